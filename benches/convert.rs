@@ -0,0 +1,162 @@
+//! Measures how long conversions take for growing inputs. The conversion is
+//! linear if the throughput stays about the same as the inputs get larger.
+//!
+//! Run with `cargo bench`. A filter can be passed to run only some of the
+//! inputs, e.g. `cargo bench -- links`.
+
+use std::hint::black_box;
+use std::time::{Duration, Instant};
+
+use nanohtml2text::{Converter, LinkStyle, Options, OutputFormat};
+
+/// Sizes of the inputs, in repetitions of their building blocks.
+const SIZES: &[usize] = &[1_000, 4_000, 16_000, 64_000];
+
+fn links(n: usize) -> String {
+    (0..n)
+        .map(|i| {
+            format!(
+                "<p>Item {0}: <a href=\"https://example.com/track?id={0}&amp;u=42\" \
+                 title=\"Item {0}\">read more about item {0}</a></p>\n",
+                i
+            )
+        })
+        .collect()
+}
+
+fn unclosed_links(n: usize) -> String {
+    (0..n)
+        .map(|i| format!("<a href=\"/{0}\">link {0} ", i))
+        .collect()
+}
+
+fn scripts(n: usize) -> String {
+    (0..n)
+        .map(|i| {
+            format!(
+                "<script>var a = {0} < 1;</script><style>p {{}}</style>text {0} ",
+                i
+            )
+        })
+        .collect()
+}
+
+fn unclosed_tables(n: usize) -> String {
+    (0..n)
+        .map(|i| format!("<table><tr><td>{0}<td>x<b>bold", i))
+        .collect()
+}
+
+fn reversed_lists(n: usize) -> String {
+    (0..n)
+        .map(|i| format!("<ol reversed><li>{0}<li>x</ol><p>{0}</p>", i))
+        .collect()
+}
+
+fn nested_reversed_lists(n: usize) -> String {
+    "<ol reversed><li>x".repeat(n)
+}
+
+fn nested_lists(n: usize) -> String {
+    "<ul><li>x".repeat(n)
+}
+
+fn base_elements(n: usize) -> String {
+    format!("<head>{}</head><a href=\"x\">x</a>", "<base ".repeat(n))
+}
+
+fn stray_brackets(n: usize) -> String {
+    (0..n).map(|i| format!("a <b {0} < c &amp ", i)).collect()
+}
+
+fn tables(n: usize) -> String {
+    let rows: String = (0..n)
+        .map(|i| format!("<tr><td>{0}</td><td>row {0}</td></tr>", i))
+        .collect();
+    format!("<table><tr><th>#</th><th>Name</th></tr>{}</table>", rows)
+}
+
+fn nested_tables(n: usize) -> String {
+    "<table><tr><td>x ".repeat(n)
+}
+
+fn markdown(n: usize) -> String {
+    (0..n)
+        .map(|i| {
+            format!(
+                "<h2>Part {0}</h2><p>Some <b>bold</b> and <i>1. odd</i> text * {0}                  with <a href=\"/{0}\">a link</a>.</p><ul><li>item [{0}]</li></ul>\n",
+                i
+            )
+        })
+        .collect()
+}
+
+fn markdown_pre(n: usize) -> String {
+    format!("<pre>{}", "<pre>```x ".repeat(n))
+}
+
+fn markdown_quotes(n: usize) -> String {
+    "<blockquote>x ".repeat(n)
+}
+
+/// A name, a function generating an input of a given size and the converter
+/// to convert it with.
+type Case<'a> = (&'a str, fn(usize) -> String, &'a Converter);
+
+fn time(mut f: impl FnMut()) -> Duration {
+    let start = Instant::now();
+    let mut runs = 0;
+    while runs < 3 || start.elapsed() < Duration::from_millis(200) {
+        f();
+        runs += 1;
+    }
+    start.elapsed() / runs
+}
+
+fn main() {
+    let filter = std::env::args().skip(1).find(|arg| !arg.starts_with('-'));
+    let default = Converter::default();
+    let footnotes = Converter::new(Options::new().link_style(LinkStyle::Footnote));
+    let data_tables = Converter::new(Options::new().detect_layout_tables(false));
+    let markdown_converter = Converter::new(Options::new().format(OutputFormat::Markdown));
+    let cases: &[Case] = &[
+        ("links", links, &default),
+        ("footnotes", links, &footnotes),
+        ("unclosed_links", unclosed_links, &default),
+        ("scripts", scripts, &default),
+        ("unclosed_tables", unclosed_tables, &default),
+        ("reversed_lists", reversed_lists, &default),
+        ("nested_reversed", nested_reversed_lists, &default),
+        ("nested_lists", nested_lists, &default),
+        ("base_elements", base_elements, &default),
+        ("stray_brackets", stray_brackets, &default),
+        ("tables", tables, &default),
+        ("nested_tables", nested_tables, &data_tables),
+        ("markdown", markdown, &markdown_converter),
+        ("markdown_pre", markdown_pre, &markdown_converter),
+        ("markdown_quotes", markdown_quotes, &markdown_converter),
+    ];
+
+    for &(name, input, converter) in cases {
+        if filter
+            .as_ref()
+            .is_some_and(|filter| !name.contains(filter.as_str()))
+        {
+            continue;
+        }
+        for &size in SIZES {
+            let html = input(size);
+            let mb = html.len() as f64 / 1_000_000.0;
+            let duration = time(|| {
+                black_box(converter.convert(black_box(&html)));
+            });
+            println!(
+                "{:<16} {:>8.2} MB {:>10.2?} {:>8.1} MB/s",
+                name,
+                mb,
+                duration,
+                mb / duration.as_secs_f64()
+            );
+        }
+    }
+}
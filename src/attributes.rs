@@ -0,0 +1,166 @@
+use crate::escape::decode_attribute;
+
+/// An attribute of an HTML tag.
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Attribute {
+    /// The name of the attribute in lowercase.
+    pub name: String,
+    /// The value of the attribute with entities decoded. Attributes without a
+    /// value, like `reversed` in `<ol reversed>`, have an empty value.
+    pub value: String,
+}
+
+/// An iterator over the attributes of a tag, created by [`attributes`].
+#[derive(Debug, Clone)]
+pub struct Attributes<'a> {
+    rest: &'a str,
+}
+
+/// Parses the attributes of a tag, given the part of the tag after its name,
+/// e.g. `href="https://example.com" title='Example' target=_blank download`.
+///
+/// Attributes are tokenized like browsers do: values may be double quoted,
+/// single quoted or unquoted, and attributes may have no value at all. Names
+/// are converted to lowercase and entities in values are decoded.
+///
+/// ```
+/// use nanohtml2text::attributes;
+///
+/// let attributes: Vec<_> = attributes(r#"HREF="/a?b=1&amp;c=2" data-x = 'y' hidden"#)
+///     .map(|attribute| (attribute.name, attribute.value))
+///     .collect();
+/// assert_eq!(
+///     attributes,
+///     [
+///         ("href".to_string(), "/a?b=1&c=2".to_string()),
+///         ("data-x".to_string(), "y".to_string()),
+///         ("hidden".to_string(), "".to_string()),
+///     ]
+/// );
+/// ```
+pub fn attributes(tag: &str) -> Attributes<'_> {
+    Attributes { rest: tag }
+}
+
+impl Iterator for Attributes<'_> {
+    type Item = Attribute;
+
+    fn next(&mut self) -> Option<Attribute> {
+        let rest = self
+            .rest
+            .trim_start_matches(|c: char| is_html_whitespace(c) || c == '/');
+        if rest.is_empty() || rest.starts_with('>') {
+            self.rest = "";
+            return None;
+        }
+
+        // an equal sign at the start is part of the name
+        let name_end = rest
+            .char_indices()
+            .skip(1)
+            .find(|&(_, c)| is_html_whitespace(c) || matches!(c, '/' | '>' | '='))
+            .map_or(rest.len(), |(i, _)| i);
+        let name = rest[..name_end].to_ascii_lowercase();
+        let rest = rest[name_end..].trim_start_matches(is_html_whitespace);
+
+        let rest = match rest.strip_prefix('=') {
+            Some(rest) => rest.trim_start_matches(is_html_whitespace),
+            None => {
+                self.rest = rest;
+                return Some(Attribute {
+                    name,
+                    value: String::new(),
+                });
+            }
+        };
+        let (value, rest) = match rest.chars().next() {
+            Some(quote @ ('"' | '\'')) => {
+                let value = &rest[1..];
+                match value.find(quote) {
+                    Some(end) => (&value[..end], &value[end + 1..]),
+                    None => (value, ""),
+                }
+            }
+            _ => {
+                let end = rest
+                    .find(|c: char| is_html_whitespace(c) || c == '>')
+                    .unwrap_or(rest.len());
+                rest.split_at(end)
+            }
+        };
+        self.rest = rest;
+        Some(Attribute {
+            name,
+            value: decode_attribute(value).into_owned(),
+        })
+    }
+}
+
+/// The value of the attribute `name`, which must be lowercase. Like in browsers,
+/// the first of several attributes with the same name counts.
+pub(crate) fn attribute_value(tag: &str, name: &str) -> Option<String> {
+    attributes(tag)
+        .find(|attribute| attribute.name == name)
+        .map(|attribute| attribute.value)
+}
+
+pub(crate) fn has_attribute(tag: &str, name: &str) -> bool {
+    attributes(tag).any(|attribute| attribute.name == name)
+}
+
+pub(crate) fn is_html_whitespace(c: char) -> bool {
+    matches!(c, ' ' | '\t' | '\n' | '\x0C' | '\r')
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(tag: &str) -> Vec<(String, String)> {
+        attributes(tag)
+            .map(|attribute| (attribute.name, attribute.value))
+            .collect()
+    }
+
+    fn pairs(pairs: &[(&str, &str)]) -> Vec<(String, String)> {
+        pairs
+            .iter()
+            .map(|&(name, value)| (name.to_string(), value.to_string()))
+            .collect()
+    }
+
+    #[test]
+    fn value_kinds() {
+        assert_eq!(
+            parse("a=\"1 2\" b='3 \"4\"' c=5 d e = 6 F=7/"),
+            pairs(&[
+                ("a", "1 2"),
+                ("b", "3 \"4\""),
+                ("c", "5"),
+                ("d", ""),
+                ("e", "6"),
+                ("f", "7/"),
+            ])
+        );
+    }
+
+    #[test]
+    fn odd_syntax() {
+        assert_eq!(parse(""), pairs(&[]));
+        assert_eq!(parse(" / "), pairs(&[]));
+        assert_eq!(parse("a/b"), pairs(&[("a", ""), ("b", "")]));
+        assert_eq!(parse("=a=b"), pairs(&[("=a", "b")]));
+        assert_eq!(parse("a=\"unterminated"), pairs(&[("a", "unterminated")]));
+        assert_eq!(parse("a=\"1\"b=2"), pairs(&[("a", "1"), ("b", "2")]));
+        assert_eq!(parse("a= b"), pairs(&[("a", "b")]));
+    }
+
+    #[test]
+    fn lookup() {
+        let tag = "data-href=\"x\" hreflang=en HREF=\"y&amp;z\" href=ignored";
+        assert_eq!(attribute_value(tag, "href").as_deref(), Some("y&z"));
+        assert_eq!(attribute_value(tag, "title"), None);
+        assert!(has_attribute("reversed start=3", "reversed"));
+        assert!(!has_attribute("data-reversed", "reversed"));
+    }
+}
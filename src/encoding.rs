@@ -0,0 +1,317 @@
+use std::borrow::Cow;
+
+use crate::attributes::{attributes, is_html_whitespace};
+use crate::escape::WINDOWS_1252;
+use crate::render::tag_end;
+
+/// How many bytes at the start of a document are searched for a `meta`
+/// element declaring the encoding, like browsers do.
+const PRESCAN_LIMIT: usize = 1024;
+
+/// A character encoding that HTML can be decoded from, see
+/// [`Converter::convert_bytes`](crate::Converter::convert_bytes).
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum Encoding {
+    /// UTF-8, which is assumed if nothing else is declared.
+    Utf8,
+    /// UTF-16 in little-endian byte order, also used for the label `utf-16`.
+    Utf16Le,
+    /// UTF-16 in big-endian byte order.
+    Utf16Be,
+    /// Windows-1252, which is also used for ISO-8859-1 and ASCII, as in
+    /// browsers. It only differs from ISO-8859-1 in characters that are
+    /// control characters there.
+    Windows1252,
+    /// ISO-8859-15, also known as Latin-9, which replaces a few characters of
+    /// ISO-8859-1 with others like `€`.
+    Iso8859_15,
+}
+
+impl Encoding {
+    /// Looks up an encoding by one of its names, like `utf-8`, `latin1` or
+    /// `ISO-8859-15`. Upper and lower case and surrounding whitespace don't
+    /// matter.
+    ///
+    /// ```
+    /// use nanohtml2text::Encoding;
+    ///
+    /// assert_eq!(Encoding::for_label(" ISO-8859-1"), Some(Encoding::Windows1252));
+    /// assert_eq!(Encoding::for_label("shift_jis"), None);
+    /// ```
+    pub fn for_label(label: &str) -> Option<Encoding> {
+        let label = label.trim_matches(is_html_whitespace).to_ascii_lowercase();
+        // the labels from https://encoding.spec.whatwg.org/#names-and-labels
+        let encoding =
+            match label.as_str() {
+                "unicode-1-1-utf-8" | "unicode11utf8" | "unicode20utf8" | "utf-8" | "utf8"
+                | "x-unicode20utf8" => Encoding::Utf8,
+                "csunicode" | "iso-10646-ucs-2" | "ucs-2" | "unicode" | "unicodefeff"
+                | "utf-16" | "utf-16le" => Encoding::Utf16Le,
+                "unicodefffe" | "utf-16be" => Encoding::Utf16Be,
+                "ansi_x3.4-1968" | "ascii" | "cp1252" | "cp819" | "csisolatin1" | "ibm819"
+                | "iso-8859-1" | "iso-ir-100" | "iso8859-1" | "iso88591" | "iso_8859-1"
+                | "iso_8859-1:1987" | "l1" | "latin1" | "us-ascii" | "windows-1252"
+                | "x-cp1252" => Encoding::Windows1252,
+                "csisolatin9" | "iso-8859-15" | "iso8859-15" | "iso885915" | "iso_8859-15"
+                | "l9" => Encoding::Iso8859_15,
+                _ => return None,
+            };
+        Some(encoding)
+    }
+
+    /// The name of the encoding.
+    pub fn name(self) -> &'static str {
+        match self {
+            Encoding::Utf8 => "UTF-8",
+            Encoding::Utf16Le => "UTF-16LE",
+            Encoding::Utf16Be => "UTF-16BE",
+            Encoding::Windows1252 => "windows-1252",
+            Encoding::Iso8859_15 => "ISO-8859-15",
+        }
+    }
+
+    /// Finds out how `html` is encoded. A byte order mark counts the most,
+    /// followed by a `meta` element with a `charset`, or with
+    /// `http-equiv="Content-Type"` and a `content` naming a charset, within
+    /// the first 1024 bytes. Otherwise, the HTML is taken to be UTF-8.
+    ///
+    /// ```
+    /// use nanohtml2text::Encoding;
+    ///
+    /// let html = b"<meta http-equiv=Content-Type content='text/html; charset=latin1'>";
+    /// assert_eq!(Encoding::detect(html), Encoding::Windows1252);
+    /// assert_eq!(Encoding::detect(b"\xFF\xFEa\0"), Encoding::Utf16Le);
+    /// ```
+    pub fn detect(html: &[u8]) -> Encoding {
+        bom_encoding(html)
+            .map(|(encoding, _)| encoding)
+            .or_else(|| prescan(html))
+            .unwrap_or(Encoding::Utf8)
+    }
+
+    /// Decodes `bytes`, replacing invalid sequences with U+FFFD. A byte order
+    /// mark of this encoding at the start is removed.
+    ///
+    /// ```
+    /// use nanohtml2text::Encoding;
+    ///
+    /// assert_eq!(Encoding::Windows1252.decode(b"caf\xE9 \x93ok\x94"), "café “ok”");
+    /// assert_eq!(Encoding::Iso8859_15.decode(b"\xA4"), "€");
+    /// ```
+    pub fn decode(self, bytes: &[u8]) -> Cow<'_, str> {
+        let bytes = match bom_encoding(bytes) {
+            Some((encoding, len)) if encoding == self => &bytes[len..],
+            _ => bytes,
+        };
+        match self {
+            Encoding::Utf8 => String::from_utf8_lossy(bytes),
+            Encoding::Utf16Le => decode_utf16(bytes, u16::from_le_bytes),
+            Encoding::Utf16Be => decode_utf16(bytes, u16::from_be_bytes),
+            Encoding::Windows1252 | Encoding::Iso8859_15 => {
+                if bytes.is_ascii() {
+                    // can't fail, ASCII is valid UTF-8
+                    return String::from_utf8_lossy(bytes);
+                }
+                let decode_byte = if self == Encoding::Windows1252 {
+                    windows_1252_char
+                } else {
+                    iso_8859_15_char
+                };
+                Cow::Owned(bytes.iter().map(|&b| decode_byte(b)).collect())
+            }
+        }
+    }
+}
+
+/// The encoding given by the byte order mark at the start of `bytes`, if there
+/// is one, and the length of the mark.
+fn bom_encoding(bytes: &[u8]) -> Option<(Encoding, usize)> {
+    match bytes {
+        [0xEF, 0xBB, 0xBF, ..] => Some((Encoding::Utf8, 3)),
+        [0xFF, 0xFE, ..] => Some((Encoding::Utf16Le, 2)),
+        [0xFE, 0xFF, ..] => Some((Encoding::Utf16Be, 2)),
+        _ => None,
+    }
+}
+
+fn decode_utf16(bytes: &[u8], decode_unit: fn([u8; 2]) -> u16) -> Cow<'static, str> {
+    let units = bytes
+        .chunks_exact(2)
+        .map(|unit| decode_unit([unit[0], unit[1]]));
+    let mut text: String = char::decode_utf16(units)
+        .map(|c| c.unwrap_or(char::REPLACEMENT_CHARACTER))
+        .collect();
+    if bytes.len() % 2 == 1 {
+        text.push(char::REPLACEMENT_CHARACTER);
+    }
+    Cow::Owned(text)
+}
+
+fn windows_1252_char(byte: u8) -> char {
+    match byte {
+        0x80..=0x9F => WINDOWS_1252[usize::from(byte) - 0x80],
+        _ => char::from(byte),
+    }
+}
+
+/// ISO-8859-15 is ISO-8859-1 with eight characters replaced, most notably by
+/// the euro sign.
+fn iso_8859_15_char(byte: u8) -> char {
+    match byte {
+        0xA4 => '€',
+        0xA6 => 'Š',
+        0xA8 => 'š',
+        0xB4 => 'Ž',
+        0xB8 => 'ž',
+        0xBC => 'Œ',
+        0xBD => 'œ',
+        0xBE => 'Ÿ',
+        _ => char::from(byte),
+    }
+}
+
+/// Looks for a `meta` element that declares the encoding at the start of
+/// `html`, following
+/// <https://html.spec.whatwg.org/#prescan-a-byte-stream-to-determine-its-encoding>
+/// in a simplified way.
+fn prescan(html: &[u8]) -> Option<Encoding> {
+    let head = String::from_utf8_lossy(&html[..html.len().min(PRESCAN_LIMIT)]);
+    let mut rest = head.as_ref();
+    while let Some(start) = rest.find('<') {
+        rest = &rest[start + 1..];
+        if let Some(comment) = rest.strip_prefix("!--") {
+            rest = comment.find("-->").map_or("", |end| &comment[end + 3..]);
+            continue;
+        }
+        let end = tag_end(rest, true).unwrap_or(rest.len());
+        let tag = &rest[..end];
+        rest = &rest[end..];
+        let name_end = tag
+            .find(|c: char| is_html_whitespace(c) || c == '/')
+            .unwrap_or(tag.len());
+        if !tag[..name_end].eq_ignore_ascii_case("meta") {
+            continue;
+        }
+        if let Some(encoding) = meta_encoding(&tag[name_end..]) {
+            // a document that can be scanned like this can't be UTF-16
+            return Some(match encoding {
+                Encoding::Utf16Le | Encoding::Utf16Be => Encoding::Utf8,
+                encoding => encoding,
+            });
+        }
+    }
+    None
+}
+
+/// The encoding declared by a `meta` element with the attributes `attribs`.
+fn meta_encoding(attribs: &str) -> Option<Encoding> {
+    let mut charset = None;
+    let mut content = None;
+    let mut http_equiv = false;
+    // like in browsers, the first of several attributes with the same name
+    // counts
+    for attribute in attributes(attribs) {
+        match attribute.name.as_str() {
+            "charset" if charset.is_none() => charset = Some(attribute.value),
+            "content" if content.is_none() => content = Some(attribute.value),
+            "http-equiv" => {
+                http_equiv |= attribute.value.trim().eq_ignore_ascii_case("content-type")
+            }
+            _ => {}
+        }
+    }
+    match (charset, content) {
+        (Some(charset), _) => Encoding::for_label(&charset),
+        (None, Some(content)) if http_equiv => Encoding::for_label(charset_from_content(&content)?),
+        _ => None,
+    }
+}
+
+/// Extracts the charset from a `Content-Type` like `text/html; charset=utf-8`.
+fn charset_from_content(content: &str) -> Option<&str> {
+    let lowercase = content.to_ascii_lowercase();
+    let mut from = 0;
+    loop {
+        from += lowercase[from..].find("charset")? + "charset".len();
+        let value = match content[from..]
+            .trim_start_matches(is_html_whitespace)
+            .strip_prefix('=')
+        {
+            Some(value) => value.trim_start_matches(is_html_whitespace),
+            None => continue,
+        };
+        return match value.chars().next()? {
+            quote @ ('"' | '\'') => value[1..].find(quote).map(|end| &value[1..=end]),
+            _ => {
+                let end = value
+                    .find(|c: char| is_html_whitespace(c) || c == ';')
+                    .unwrap_or(value.len());
+                Some(&value[..end])
+            }
+        };
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn detection() {
+        for &(html, encoding) in &[
+            (&b"\xEF\xBB\xBF<meta charset=latin1>"[..], Encoding::Utf8),
+            (b"\xFE\xFF\0<", Encoding::Utf16Be),
+            (b"<meta charset=\"ISO-8859-15\">", Encoding::Iso8859_15),
+            (b"<META CHARSET='Windows-1252'>", Encoding::Windows1252),
+            (b"<meta charset=utf-16>", Encoding::Utf8),
+            (
+                b"<meta charset=unknown><meta charset=l9>",
+                Encoding::Iso8859_15,
+            ),
+            (b"<!-- <meta charset=latin1> --><p>", Encoding::Utf8),
+            (b"<meta title='>' charset=l1>", Encoding::Windows1252),
+            (b"<meta content='text/html; charset=l1'>", Encoding::Utf8),
+            (
+                b"<meta http-equiv=\"content-type\" content=\"text/html;charset = 'latin1'\">",
+                Encoding::Windows1252,
+            ),
+            (b"<p>caf\xE9</p>", Encoding::Utf8),
+        ] {
+            assert_eq!(Encoding::detect(html), encoding, "{:?}", html);
+        }
+
+        let mut late = vec![b' '; PRESCAN_LIMIT];
+        late.extend_from_slice(b"<meta charset=latin1>");
+        assert_eq!(Encoding::detect(&late), Encoding::Utf8);
+    }
+
+    #[test]
+    fn content_charset() {
+        assert_eq!(
+            charset_from_content("text/html; charset=UTF-8"),
+            Some("UTF-8")
+        );
+        assert_eq!(
+            charset_from_content("charset; charset=\"a b\""),
+            Some("a b")
+        );
+        assert_eq!(charset_from_content("charset='unterminated"), None);
+        assert_eq!(charset_from_content("text/html"), None);
+    }
+
+    #[test]
+    fn decoding() {
+        assert_eq!(Encoding::Utf8.decode(b"\xEF\xBB\xBFa\xFFb"), "a\u{FFFD}b");
+        assert_eq!(
+            Encoding::Utf16Le.decode(b"\xFF\xFEh\0i\0=\xD8\x00"),
+            "hi\u{FFFD}\u{FFFD}"
+        );
+        assert_eq!(Encoding::Utf16Be.decode(b"\xD8\x3D\xDE\x00"), "😀");
+        assert_eq!(Encoding::Windows1252.decode(b"\x80\x81\xFF"), "€\u{81}ÿ");
+        assert!(matches!(
+            Encoding::Windows1252.decode(b"ascii"),
+            Cow::Borrowed(_)
+        ));
+        assert_eq!(Encoding::Iso8859_15.decode(b"\x80\xA4\xE9"), "\u{80}€é");
+    }
+}
@@ -1,2059 +1,2245 @@
-/*
-The below piece of code was taken from:
-https://github.com/veddan/rust-htmlescape/blob/master/src/entities.rs
+// The HTML5 named character references, generated from
+// https://html.spec.whatwg.org/entities.json.
+//
+// The data is part of the HTML Living Standard:
+// Copyright © WHATWG (Apple, Google, Mozilla, Microsoft). This work is
+// licensed under a Creative Commons Attribution 4.0 International License:
+// https://creativecommons.org/licenses/by/4.0/
+//
+// Names include the trailing semicolon. The legacy references that may also
+// appear without one are listed a second time without it. The list is sorted
+// by name so that it can be searched with a binary search.
 
-Copyright 2016 Viktor Dahl <pazaconyoman@gmail.com>
-
-Permission is hereby granted, free of charge, to any person obtaining a copy of
-this software and associated documentation files (the "Software"), to deal in
-the Software without restriction, including without limitation the rights to
-use, copy, modify, merge, publish, distribute, sublicense, and/or sell copies
-of the Software, and to permit persons to whom the Software is furnished to do
-so, subject to the following conditions:
-
-The above copyright notice and this permission notice shall be included in all
-copies or substantial portions of the Software.
-
-THE SOFTWARE IS PROVIDED "AS IS", WITHOUT WARRANTY OF ANY KIND, EXPRESS OR
-IMPLIED, INCLUDING BUT NOT LIMITED TO THE WARRANTIES OF MERCHANTABILITY,
-FITNESS FOR A PARTICULAR PURPOSE AND NONINFRINGEMENT. IN NO EVENT SHALL THE
-AUTHORS OR COPYRIGHT HOLDERS BE LIABLE FOR ANY CLAIM, DAMAGES OR OTHER
-LIABILITY, WHETHER IN AN ACTION OF CONTRACT, TORT OR OTHERWISE, ARISING FROM,
-OUT OF OR IN CONNECTION WITH THE SOFTWARE OR THE USE OR OTHER DEALINGS IN THE
-SOFTWARE.
-*/
-
-pub static ENTITIES: &'static [(&'static str, char)] = &[
-    ("AElig", '\u{00C6}'),
-    ("AMP", '\u{000026}'),
-    ("Aacute", '\u{0000C1}'),
-    ("Abreve", '\u{000102}'),
-    ("Acirc", '\u{0000C2}'),
-    ("Acy", '\u{000410}'),
-    ("Afr", '\u{01D504}'),
-    ("Agrave", '\u{0000C0}'),
-    ("Alpha", '\u{000391}'),
-    ("Amacr", '\u{000100}'),
-    ("And", '\u{002A53}'),
-    ("Aogon", '\u{000104}'),
-    ("Aopf", '\u{01D538}'),
-    ("ApplyFunction", '\u{002061}'),
-    ("Aring", '\u{0000C5}'),
-    ("Ascr", '\u{01D49C}'),
-    ("Assign", '\u{002254}'),
-    ("Atilde", '\u{0000C3}'),
-    ("Auml", '\u{0000C4}'),
-    ("Backslash", '\u{002216}'),
-    ("Barv", '\u{002AE7}'),
-    ("Barwed", '\u{002306}'),
-    ("Bcy", '\u{000411}'),
-    ("Because", '\u{002235}'),
-    ("Bernoullis", '\u{00212C}'),
-    ("Beta", '\u{000392}'),
-    ("Bfr", '\u{01D505}'),
-    ("Bopf", '\u{01D539}'),
-    ("Breve", '\u{0002D8}'),
-    ("Bscr", '\u{00212C}'),
-    ("Bumpeq", '\u{00224E}'),
-    ("CHcy", '\u{000427}'),
-    ("COPY", '\u{0000A9}'),
-    ("Cacute", '\u{000106}'),
-    ("Cap", '\u{0022D2}'),
-    ("CapitalDifferentialD", '\u{002145}'),
-    ("Cayleys", '\u{00212D}'),
-    ("Ccaron", '\u{00010C}'),
-    ("Ccedil", '\u{0000C7}'),
-    ("Ccirc", '\u{000108}'),
-    ("Cconint", '\u{002230}'),
-    ("Cdot", '\u{00010A}'),
-    ("Cedilla", '\u{0000B8}'),
-    ("CenterDot", '\u{0000B7}'),
-    ("Cfr", '\u{00212D}'),
-    ("Chi", '\u{0003A7}'),
-    ("CircleDot", '\u{002299}'),
-    ("CircleMinus", '\u{002296}'),
-    ("CirclePlus", '\u{002295}'),
-    ("CircleTimes", '\u{002297}'),
-    ("ClockwiseContourIntegral", '\u{002232}'),
-    ("CloseCurlyDoubleQuote", '\u{00201D}'),
-    ("CloseCurlyQuote", '\u{002019}'),
-    ("Colon", '\u{002237}'),
-    ("Colone", '\u{002A74}'),
-    ("Congruent", '\u{002261}'),
-    ("Conint", '\u{00222F}'),
-    ("ContourIntegral", '\u{00222E}'),
-    ("Copf", '\u{002102}'),
-    ("Coproduct", '\u{002210}'),
-    ("CounterClockwiseContourIntegral", '\u{002233}'),
-    ("Cross", '\u{002A2F}'),
-    ("Cscr", '\u{01D49E}'),
-    ("Cup", '\u{0022D3}'),
-    ("CupCap", '\u{00224D}'),
-    ("DD", '\u{002145}'),
-    ("DDotrahd", '\u{002911}'),
-    ("DJcy", '\u{000402}'),
-    ("DScy", '\u{000405}'),
-    ("DZcy", '\u{00040F}'),
-    ("Dagger", '\u{002021}'),
-    ("Darr", '\u{0021A1}'),
-    ("Dashv", '\u{002AE4}'),
-    ("Dcaron", '\u{00010E}'),
-    ("Dcy", '\u{000414}'),
-    ("Del", '\u{002207}'),
-    ("Delta", '\u{000394}'),
-    ("Dfr", '\u{01D507}'),
-    ("DiacriticalAcute", '\u{0000B4}'),
-    ("DiacriticalDot", '\u{0002D9}'),
-    ("DiacriticalDoubleAcute", '\u{0002DD}'),
-    ("DiacriticalGrave", '\u{000060}'),
-    ("DiacriticalTilde", '\u{0002DC}'),
-    ("Diamond", '\u{0022C4}'),
-    ("DifferentialD", '\u{002146}'),
-    ("Dopf", '\u{01D53B}'),
-    ("Dot", '\u{0000A8}'),
-    ("DotDot", '\u{0020DC}'),
-    ("DotEqual", '\u{002250}'),
-    ("DoubleContourIntegral", '\u{00222F}'),
-    ("DoubleDot", '\u{0000A8}'),
-    ("DoubleDownArrow", '\u{0021D3}'),
-    ("DoubleLeftArrow", '\u{0021D0}'),
-    ("DoubleLeftRightArrow", '\u{0021D4}'),
-    ("DoubleLeftTee", '\u{002AE4}'),
-    ("DoubleLongLeftArrow", '\u{0027F8}'),
-    ("DoubleLongLeftRightArrow", '\u{0027FA}'),
-    ("DoubleLongRightArrow", '\u{0027F9}'),
-    ("DoubleRightArrow", '\u{0021D2}'),
-    ("DoubleRightTee", '\u{0022A8}'),
-    ("DoubleUpArrow", '\u{0021D1}'),
-    ("DoubleUpDownArrow", '\u{0021D5}'),
-    ("DoubleVerticalBar", '\u{002225}'),
-    ("DownArrow", '\u{002193}'),
-    ("DownArrowBar", '\u{002913}'),
-    ("DownArrowUpArrow", '\u{0021F5}'),
-    ("DownBreve", '\u{000311}'),
-    ("DownLeftRightVector", '\u{002950}'),
-    ("DownLeftTeeVector", '\u{00295E}'),
-    ("DownLeftVector", '\u{0021BD}'),
-    ("DownLeftVectorBar", '\u{002956}'),
-    ("DownRightTeeVector", '\u{00295F}'),
-    ("DownRightVector", '\u{0021C1}'),
-    ("DownRightVectorBar", '\u{002957}'),
-    ("DownTee", '\u{0022A4}'),
-    ("DownTeeArrow", '\u{0021A7}'),
-    ("Downarrow", '\u{0021D3}'),
-    ("Dscr", '\u{01D49F}'),
-    ("Dstrok", '\u{000110}'),
-    ("ENG", '\u{00014A}'),
-    ("ETH", '\u{0000D0}'),
-    ("Eacute", '\u{0000C9}'),
-    ("Ecaron", '\u{00011A}'),
-    ("Ecirc", '\u{0000CA}'),
-    ("Ecy", '\u{00042D}'),
-    ("Edot", '\u{000116}'),
-    ("Efr", '\u{01D508}'),
-    ("Egrave", '\u{0000C8}'),
-    ("Element", '\u{002208}'),
-    ("Emacr", '\u{000112}'),
-    ("EmptySmallSquare", '\u{0025FB}'),
-    ("EmptyVerySmallSquare", '\u{0025AB}'),
-    ("Eogon", '\u{000118}'),
-    ("Eopf", '\u{01D53C}'),
-    ("Epsilon", '\u{000395}'),
-    ("Equal", '\u{002A75}'),
-    ("EqualTilde", '\u{002242}'),
-    ("Equilibrium", '\u{0021CC}'),
-    ("Escr", '\u{002130}'),
-    ("Esim", '\u{002A73}'),
-    ("Eta", '\u{000397}'),
-    ("Euml", '\u{0000CB}'),
-    ("Exists", '\u{002203}'),
-    ("ExponentialE", '\u{002147}'),
-    ("Fcy", '\u{000424}'),
-    ("Ffr", '\u{01D509}'),
-    ("FilledSmallSquare", '\u{0025FC}'),
-    ("FilledVerySmallSquare", '\u{0025AA}'),
-    ("Fopf", '\u{01D53D}'),
-    ("ForAll", '\u{002200}'),
-    ("Fouriertrf", '\u{002131}'),
-    ("Fscr", '\u{002131}'),
-    ("GJcy", '\u{000403}'),
-    ("GT", '\u{00003E}'),
-    ("Gamma", '\u{000393}'),
-    ("Gammad", '\u{0003DC}'),
-    ("Gbreve", '\u{00011E}'),
-    ("Gcedil", '\u{000122}'),
-    ("Gcirc", '\u{00011C}'),
-    ("Gcy", '\u{000413}'),
-    ("Gdot", '\u{000120}'),
-    ("Gfr", '\u{01D50A}'),
-    ("Gg", '\u{0022D9}'),
-    ("Gopf", '\u{01D53E}'),
-    ("GreaterEqual", '\u{002265}'),
-    ("GreaterEqualLess", '\u{0022DB}'),
-    ("GreaterFullEqual", '\u{002267}'),
-    ("GreaterGreater", '\u{002AA2}'),
-    ("GreaterLess", '\u{002277}'),
-    ("GreaterSlantEqual", '\u{002A7E}'),
-    ("GreaterTilde", '\u{002273}'),
-    ("Gscr", '\u{01D4A2}'),
-    ("Gt", '\u{00226B}'),
-    ("HARDcy", '\u{00042A}'),
-    ("Hacek", '\u{0002C7}'),
-    ("Hat", '\u{00005E}'),
-    ("Hcirc", '\u{000124}'),
-    ("Hfr", '\u{00210C}'),
-    ("HilbertSpace", '\u{00210B}'),
-    ("Hopf", '\u{00210D}'),
-    ("HorizontalLine", '\u{002500}'),
-    ("Hscr", '\u{00210B}'),
-    ("Hstrok", '\u{000126}'),
-    ("HumpDownHump", '\u{00224E}'),
-    ("HumpEqual", '\u{00224F}'),
-    ("IEcy", '\u{000415}'),
-    ("IJlig", '\u{000132}'),
-    ("IOcy", '\u{000401}'),
-    ("Iacute", '\u{0000CD}'),
-    ("Icirc", '\u{0000CE}'),
-    ("Icy", '\u{000418}'),
-    ("Idot", '\u{000130}'),
-    ("Ifr", '\u{002111}'),
-    ("Igrave", '\u{0000CC}'),
-    ("Im", '\u{002111}'),
-    ("Imacr", '\u{00012A}'),
-    ("ImaginaryI", '\u{002148}'),
-    ("Implies", '\u{0021D2}'),
-    ("Int", '\u{00222C}'),
-    ("Integral", '\u{00222B}'),
-    ("Intersection", '\u{0022C2}'),
-    ("InvisibleComma", '\u{002063}'),
-    ("InvisibleTimes", '\u{002062}'),
-    ("Iogon", '\u{00012E}'),
-    ("Iopf", '\u{01D540}'),
-    ("Iota", '\u{000399}'),
-    ("Iscr", '\u{002110}'),
-    ("Itilde", '\u{000128}'),
-    ("Iukcy", '\u{000406}'),
-    ("Iuml", '\u{0000CF}'),
-    ("Jcirc", '\u{000134}'),
-    ("Jcy", '\u{000419}'),
-    ("Jfr", '\u{01D50D}'),
-    ("Jopf", '\u{01D541}'),
-    ("Jscr", '\u{01D4A5}'),
-    ("Jsercy", '\u{000408}'),
-    ("Jukcy", '\u{000404}'),
-    ("KHcy", '\u{000425}'),
-    ("KJcy", '\u{00040C}'),
-    ("Kappa", '\u{00039A}'),
-    ("Kcedil", '\u{000136}'),
-    ("Kcy", '\u{00041A}'),
-    ("Kfr", '\u{01D50E}'),
-    ("Kopf", '\u{01D542}'),
-    ("Kscr", '\u{01D4A6}'),
-    ("LJcy", '\u{000409}'),
-    ("LT", '\u{00003C}'),
-    ("Lacute", '\u{000139}'),
-    ("Lambda", '\u{00039B}'),
-    ("Lang", '\u{0027EA}'),
-    ("Laplacetrf", '\u{002112}'),
-    ("Larr", '\u{00219E}'),
-    ("Lcaron", '\u{00013D}'),
-    ("Lcedil", '\u{00013B}'),
-    ("Lcy", '\u{00041B}'),
-    ("LeftAngleBracket", '\u{0027E8}'),
-    ("LeftArrow", '\u{002190}'),
-    ("LeftArrowBar", '\u{0021E4}'),
-    ("LeftArrowRightArrow", '\u{0021C6}'),
-    ("LeftCeiling", '\u{002308}'),
-    ("LeftDoubleBracket", '\u{0027E6}'),
-    ("LeftDownTeeVector", '\u{002961}'),
-    ("LeftDownVector", '\u{0021C3}'),
-    ("LeftDownVectorBar", '\u{002959}'),
-    ("LeftFloor", '\u{00230A}'),
-    ("LeftRightArrow", '\u{002194}'),
-    ("LeftRightVector", '\u{00294E}'),
-    ("LeftTee", '\u{0022A3}'),
-    ("LeftTeeArrow", '\u{0021A4}'),
-    ("LeftTeeVector", '\u{00295A}'),
-    ("LeftTriangle", '\u{0022B2}'),
-    ("LeftTriangleBar", '\u{0029CF}'),
-    ("LeftTriangleEqual", '\u{0022B4}'),
-    ("LeftUpDownVector", '\u{002951}'),
-    ("LeftUpTeeVector", '\u{002960}'),
-    ("LeftUpVector", '\u{0021BF}'),
-    ("LeftUpVectorBar", '\u{002958}'),
-    ("LeftVector", '\u{0021BC}'),
-    ("LeftVectorBar", '\u{002952}'),
-    ("Leftarrow", '\u{0021D0}'),
-    ("Leftrightarrow", '\u{0021D4}'),
-    ("LessEqualGreater", '\u{0022DA}'),
-    ("LessFullEqual", '\u{002266}'),
-    ("LessGreater", '\u{002276}'),
-    ("LessLess", '\u{002AA1}'),
-    ("LessSlantEqual", '\u{002A7D}'),
-    ("LessTilde", '\u{002272}'),
-    ("Lfr", '\u{01D50F}'),
-    ("Ll", '\u{0022D8}'),
-    ("Lleftarrow", '\u{0021DA}'),
-    ("Lmidot", '\u{00013F}'),
-    ("LongLeftArrow", '\u{0027F5}'),
-    ("LongLeftRightArrow", '\u{0027F7}'),
-    ("LongRightArrow", '\u{0027F6}'),
-    ("Longleftarrow", '\u{0027F8}'),
-    ("Longleftrightarrow", '\u{0027FA}'),
-    ("Longrightarrow", '\u{0027F9}'),
-    ("Lopf", '\u{01D543}'),
-    ("LowerLeftArrow", '\u{002199}'),
-    ("LowerRightArrow", '\u{002198}'),
-    ("Lscr", '\u{002112}'),
-    ("Lsh", '\u{0021B0}'),
-    ("Lstrok", '\u{000141}'),
-    ("Lt", '\u{00226A}'),
-    ("Map", '\u{002905}'),
-    ("Mcy", '\u{00041C}'),
-    ("MediumSpace", '\u{00205F}'),
-    ("Mellintrf", '\u{002133}'),
-    ("Mfr", '\u{01D510}'),
-    ("MinusPlus", '\u{002213}'),
-    ("Mopf", '\u{01D544}'),
-    ("Mscr", '\u{002133}'),
-    ("Mu", '\u{00039C}'),
-    ("NJcy", '\u{00040A}'),
-    ("Nacute", '\u{000143}'),
-    ("Ncaron", '\u{000147}'),
-    ("Ncedil", '\u{000145}'),
-    ("Ncy", '\u{00041D}'),
-    ("NegativeMediumSpace", '\u{00200B}'),
-    ("NegativeThickSpace", '\u{00200B}'),
-    ("NegativeThinSpace", '\u{00200B}'),
-    ("NegativeVeryThinSpace", '\u{00200B}'),
-    ("NestedGreaterGreater", '\u{00226B}'),
-    ("NestedLessLess", '\u{00226A}'),
-    ("NewLine", '\u{00000A}'),
-    ("Nfr", '\u{01D511}'),
-    ("NoBreak", '\u{002060}'),
-    ("NonBreakingSpace", '\u{0000A0}'),
-    ("Nopf", '\u{002115}'),
-    ("Not", '\u{002AEC}'),
-    ("NotCongruent", '\u{002262}'),
-    ("NotCupCap", '\u{00226D}'),
-    ("NotDoubleVerticalBar", '\u{002226}'),
-    ("NotElement", '\u{002209}'),
-    ("NotEqual", '\u{002260}'),
-    ("NotExists", '\u{002204}'),
-    ("NotGreater", '\u{00226F}'),
-    ("NotGreaterEqual", '\u{002271}'),
-    ("NotGreaterLess", '\u{002279}'),
-    ("NotGreaterTilde", '\u{002275}'),
-    ("NotLeftTriangle", '\u{0022EA}'),
-    ("NotLeftTriangleEqual", '\u{0022EC}'),
-    ("NotLess", '\u{00226E}'),
-    ("NotLessEqual", '\u{002270}'),
-    ("NotLessGreater", '\u{002278}'),
-    ("NotLessTilde", '\u{002274}'),
-    ("NotPrecedes", '\u{002280}'),
-    ("NotPrecedesSlantEqual", '\u{0022E0}'),
-    ("NotReverseElement", '\u{00220C}'),
-    ("NotRightTriangle", '\u{0022EB}'),
-    ("NotRightTriangleEqual", '\u{0022ED}'),
-    ("NotSquareSubsetEqual", '\u{0022E2}'),
-    ("NotSquareSupersetEqual", '\u{0022E3}'),
-    ("NotSubsetEqual", '\u{002288}'),
-    ("NotSucceeds", '\u{002281}'),
-    ("NotSucceedsSlantEqual", '\u{0022E1}'),
-    ("NotSupersetEqual", '\u{002289}'),
-    ("NotTilde", '\u{002241}'),
-    ("NotTildeEqual", '\u{002244}'),
-    ("NotTildeFullEqual", '\u{002247}'),
-    ("NotTildeTilde", '\u{002249}'),
-    ("NotVerticalBar", '\u{002224}'),
-    ("Nscr", '\u{01D4A9}'),
-    ("Ntilde", '\u{0000D1}'),
-    ("Nu", '\u{00039D}'),
-    ("OElig", '\u{000152}'),
-    ("Oacute", '\u{0000D3}'),
-    ("Ocirc", '\u{0000D4}'),
-    ("Ocy", '\u{00041E}'),
-    ("Odblac", '\u{000150}'),
-    ("Ofr", '\u{01D512}'),
-    ("Ograve", '\u{0000D2}'),
-    ("Omacr", '\u{00014C}'),
-    ("Omega", '\u{0003A9}'),
-    ("Omicron", '\u{00039F}'),
-    ("Oopf", '\u{01D546}'),
-    ("OpenCurlyDoubleQuote", '\u{00201C}'),
-    ("OpenCurlyQuote", '\u{002018}'),
-    ("Or", '\u{002A54}'),
-    ("Oscr", '\u{01D4AA}'),
-    ("Oslash", '\u{0000D8}'),
-    ("Otilde", '\u{0000D5}'),
-    ("Otimes", '\u{002A37}'),
-    ("Ouml", '\u{0000D6}'),
-    ("OverBar", '\u{00203E}'),
-    ("OverBrace", '\u{0023DE}'),
-    ("OverBracket", '\u{0023B4}'),
-    ("OverParenthesis", '\u{0023DC}'),
-    ("PartialD", '\u{002202}'),
-    ("Pcy", '\u{00041F}'),
-    ("Pfr", '\u{01D513}'),
-    ("Phi", '\u{0003A6}'),
-    ("Pi", '\u{0003A0}'),
-    ("PlusMinus", '\u{0000B1}'),
-    ("Poincareplane", '\u{00210C}'),
-    ("Popf", '\u{002119}'),
-    ("Pr", '\u{002ABB}'),
-    ("Precedes", '\u{00227A}'),
-    ("PrecedesEqual", '\u{002AAF}'),
-    ("PrecedesSlantEqual", '\u{00227C}'),
-    ("PrecedesTilde", '\u{00227E}'),
-    ("Prime", '\u{002033}'),
-    ("Product", '\u{00220F}'),
-    ("Proportion", '\u{002237}'),
-    ("Proportional", '\u{00221D}'),
-    ("Pscr", '\u{01D4AB}'),
-    ("Psi", '\u{0003A8}'),
-    ("QUOT", '\u{000022}'),
-    ("Qfr", '\u{01D514}'),
-    ("Qopf", '\u{00211A}'),
-    ("Qscr", '\u{01D4AC}'),
-    ("RBarr", '\u{002910}'),
-    ("REG", '\u{0000AE}'),
-    ("Racute", '\u{000154}'),
-    ("Rang", '\u{0027EB}'),
-    ("Rarr", '\u{0021A0}'),
-    ("Rarrtl", '\u{002916}'),
-    ("Rcaron", '\u{000158}'),
-    ("Rcedil", '\u{000156}'),
-    ("Rcy", '\u{000420}'),
-    ("Re", '\u{00211C}'),
-    ("ReverseElement", '\u{00220B}'),
-    ("ReverseEquilibrium", '\u{0021CB}'),
-    ("ReverseUpEquilibrium", '\u{00296F}'),
-    ("Rfr", '\u{00211C}'),
-    ("Rho", '\u{0003A1}'),
-    ("RightAngleBracket", '\u{0027E9}'),
-    ("RightArrow", '\u{002192}'),
-    ("RightArrowBar", '\u{0021E5}'),
-    ("RightArrowLeftArrow", '\u{0021C4}'),
-    ("RightCeiling", '\u{002309}'),
-    ("RightDoubleBracket", '\u{0027E7}'),
-    ("RightDownTeeVector", '\u{00295D}'),
-    ("RightDownVector", '\u{0021C2}'),
-    ("RightDownVectorBar", '\u{002955}'),
-    ("RightFloor", '\u{00230B}'),
-    ("RightTee", '\u{0022A2}'),
-    ("RightTeeArrow", '\u{0021A6}'),
-    ("RightTeeVector", '\u{00295B}'),
-    ("RightTriangle", '\u{0022B3}'),
-    ("RightTriangleBar", '\u{0029D0}'),
-    ("RightTriangleEqual", '\u{0022B5}'),
-    ("RightUpDownVector", '\u{00294F}'),
-    ("RightUpTeeVector", '\u{00295C}'),
-    ("RightUpVector", '\u{0021BE}'),
-    ("RightUpVectorBar", '\u{002954}'),
-    ("RightVector", '\u{0021C0}'),
-    ("RightVectorBar", '\u{002953}'),
-    ("Rightarrow", '\u{0021D2}'),
-    ("Ropf", '\u{00211D}'),
-    ("RoundImplies", '\u{002970}'),
-    ("Rrightarrow", '\u{0021DB}'),
-    ("Rscr", '\u{00211B}'),
-    ("Rsh", '\u{0021B1}'),
-    ("RuleDelayed", '\u{0029F4}'),
-    ("SHCHcy", '\u{000429}'),
-    ("SHcy", '\u{000428}'),
-    ("SOFTcy", '\u{00042C}'),
-    ("Sacute", '\u{00015A}'),
-    ("Sc", '\u{002ABC}'),
-    ("Scaron", '\u{000160}'),
-    ("Scedil", '\u{00015E}'),
-    ("Scirc", '\u{00015C}'),
-    ("Scy", '\u{000421}'),
-    ("Sfr", '\u{01D516}'),
-    ("ShortDownArrow", '\u{002193}'),
-    ("ShortLeftArrow", '\u{002190}'),
-    ("ShortRightArrow", '\u{002192}'),
-    ("ShortUpArrow", '\u{002191}'),
-    ("Sigma", '\u{0003A3}'),
-    ("SmallCircle", '\u{002218}'),
-    ("Sopf", '\u{01D54A}'),
-    ("Sqrt", '\u{00221A}'),
-    ("Square", '\u{0025A1}'),
-    ("SquareIntersection", '\u{002293}'),
-    ("SquareSubset", '\u{00228F}'),
-    ("SquareSubsetEqual", '\u{002291}'),
-    ("SquareSuperset", '\u{002290}'),
-    ("SquareSupersetEqual", '\u{002292}'),
-    ("SquareUnion", '\u{002294}'),
-    ("Sscr", '\u{01D4AE}'),
-    ("Star", '\u{0022C6}'),
-    ("Sub", '\u{0022D0}'),
-    ("Subset", '\u{0022D0}'),
-    ("SubsetEqual", '\u{002286}'),
-    ("Succeeds", '\u{00227B}'),
-    ("SucceedsEqual", '\u{002AB0}'),
-    ("SucceedsSlantEqual", '\u{00227D}'),
-    ("SucceedsTilde", '\u{00227F}'),
-    ("SuchThat", '\u{00220B}'),
-    ("Sum", '\u{002211}'),
-    ("Sup", '\u{0022D1}'),
-    ("Superset", '\u{002283}'),
-    ("SupersetEqual", '\u{002287}'),
-    ("Supset", '\u{0022D1}'),
-    ("THORN", '\u{0000DE}'),
-    ("TRADE", '\u{002122}'),
-    ("TSHcy", '\u{00040B}'),
-    ("TScy", '\u{000426}'),
-    ("Tab", '\u{000009}'),
-    ("Tau", '\u{0003A4}'),
-    ("Tcaron", '\u{000164}'),
-    ("Tcedil", '\u{000162}'),
-    ("Tcy", '\u{000422}'),
-    ("Tfr", '\u{01D517}'),
-    ("Therefore", '\u{002234}'),
-    ("Theta", '\u{000398}'),
-    ("ThinSpace", '\u{002009}'),
-    ("Tilde", '\u{00223C}'),
-    ("TildeEqual", '\u{002243}'),
-    ("TildeFullEqual", '\u{002245}'),
-    ("TildeTilde", '\u{002248}'),
-    ("Topf", '\u{01D54B}'),
-    ("TripleDot", '\u{0020DB}'),
-    ("Tscr", '\u{01D4AF}'),
-    ("Tstrok", '\u{000166}'),
-    ("Uacute", '\u{0000DA}'),
-    ("Uarr", '\u{00219F}'),
-    ("Uarrocir", '\u{002949}'),
-    ("Ubrcy", '\u{00040E}'),
-    ("Ubreve", '\u{00016C}'),
-    ("Ucirc", '\u{0000DB}'),
-    ("Ucy", '\u{000423}'),
-    ("Udblac", '\u{000170}'),
-    ("Ufr", '\u{01D518}'),
-    ("Ugrave", '\u{0000D9}'),
-    ("Umacr", '\u{00016A}'),
-    ("UnderBar", '\u{00005F}'),
-    ("UnderBrace", '\u{0023DF}'),
-    ("UnderBracket", '\u{0023B5}'),
-    ("UnderParenthesis", '\u{0023DD}'),
-    ("Union", '\u{0022C3}'),
-    ("UnionPlus", '\u{00228E}'),
-    ("Uogon", '\u{000172}'),
-    ("Uopf", '\u{01D54C}'),
-    ("UpArrow", '\u{002191}'),
-    ("UpArrowBar", '\u{002912}'),
-    ("UpArrowDownArrow", '\u{0021C5}'),
-    ("UpDownArrow", '\u{002195}'),
-    ("UpEquilibrium", '\u{00296E}'),
-    ("UpTee", '\u{0022A5}'),
-    ("UpTeeArrow", '\u{0021A5}'),
-    ("Uparrow", '\u{0021D1}'),
-    ("Updownarrow", '\u{0021D5}'),
-    ("UpperLeftArrow", '\u{002196}'),
-    ("UpperRightArrow", '\u{002197}'),
-    ("Upsi", '\u{0003D2}'),
-    ("Upsilon", '\u{0003A5}'),
-    ("Uring", '\u{00016E}'),
-    ("Uscr", '\u{01D4B0}'),
-    ("Utilde", '\u{000168}'),
-    ("Uuml", '\u{0000DC}'),
-    ("VDash", '\u{0022AB}'),
-    ("Vbar", '\u{002AEB}'),
-    ("Vcy", '\u{000412}'),
-    ("Vdash", '\u{0022A9}'),
-    ("Vdashl", '\u{002AE6}'),
-    ("Vee", '\u{0022C1}'),
-    ("Verbar", '\u{002016}'),
-    ("Vert", '\u{002016}'),
-    ("VerticalBar", '\u{002223}'),
-    ("VerticalLine", '\u{00007C}'),
-    ("VerticalSeparator", '\u{002758}'),
-    ("VerticalTilde", '\u{002240}'),
-    ("VeryThinSpace", '\u{00200A}'),
-    ("Vfr", '\u{01D519}'),
-    ("Vopf", '\u{01D54D}'),
-    ("Vscr", '\u{01D4B1}'),
-    ("Vvdash", '\u{0022AA}'),
-    ("Wcirc", '\u{000174}'),
-    ("Wedge", '\u{0022C0}'),
-    ("Wfr", '\u{01D51A}'),
-    ("Wopf", '\u{01D54E}'),
-    ("Wscr", '\u{01D4B2}'),
-    ("Xfr", '\u{01D51B}'),
-    ("Xi", '\u{00039E}'),
-    ("Xopf", '\u{01D54F}'),
-    ("Xscr", '\u{01D4B3}'),
-    ("YAcy", '\u{00042F}'),
-    ("YIcy", '\u{000407}'),
-    ("YUcy", '\u{00042E}'),
-    ("Yacute", '\u{0000DD}'),
-    ("Ycirc", '\u{000176}'),
-    ("Ycy", '\u{00042B}'),
-    ("Yfr", '\u{01D51C}'),
-    ("Yopf", '\u{01D550}'),
-    ("Yscr", '\u{01D4B4}'),
-    ("Yuml", '\u{000178}'),
-    ("ZHcy", '\u{000416}'),
-    ("Zacute", '\u{000179}'),
-    ("Zcaron", '\u{00017D}'),
-    ("Zcy", '\u{000417}'),
-    ("Zdot", '\u{00017B}'),
-    ("ZeroWidthSpace", '\u{00200B}'),
-    ("Zeta", '\u{000396}'),
-    ("Zfr", '\u{002128}'),
-    ("Zopf", '\u{002124}'),
-    ("Zscr", '\u{01D4B5}'),
-    ("aacute", '\u{0000E1}'),
-    ("abreve", '\u{000103}'),
-    ("ac", '\u{00223E}'),
-    ("acd", '\u{00223F}'),
-    ("acirc", '\u{0000E2}'),
-    ("acute", '\u{0000B4}'),
-    ("acy", '\u{000430}'),
-    ("aelig", '\u{0000E6}'),
-    ("af", '\u{002061}'),
-    ("afr", '\u{01D51E}'),
-    ("agrave", '\u{0000E0}'),
-    ("alefsym", '\u{002135}'),
-    ("aleph", '\u{002135}'),
-    ("alpha", '\u{0003B1}'),
-    ("amacr", '\u{000101}'),
-    ("amalg", '\u{002A3F}'),
-    ("amp", '\u{000026}'),
-    ("and", '\u{002227}'),
-    ("andand", '\u{002A55}'),
-    ("andd", '\u{002A5C}'),
-    ("andslope", '\u{002A58}'),
-    ("andv", '\u{002A5A}'),
-    ("ang", '\u{002220}'),
-    ("ange", '\u{0029A4}'),
-    ("angle", '\u{002220}'),
-    ("angmsd", '\u{002221}'),
-    ("angmsdaa", '\u{0029A8}'),
-    ("angmsdab", '\u{0029A9}'),
-    ("angmsdac", '\u{0029AA}'),
-    ("angmsdad", '\u{0029AB}'),
-    ("angmsdae", '\u{0029AC}'),
-    ("angmsdaf", '\u{0029AD}'),
-    ("angmsdag", '\u{0029AE}'),
-    ("angmsdah", '\u{0029AF}'),
-    ("angrt", '\u{00221F}'),
-    ("angrtvb", '\u{0022BE}'),
-    ("angrtvbd", '\u{00299D}'),
-    ("angsph", '\u{002222}'),
-    ("angst", '\u{0000C5}'),
-    ("angzarr", '\u{00237C}'),
-    ("aogon", '\u{000105}'),
-    ("aopf", '\u{01D552}'),
-    ("ap", '\u{002248}'),
-    ("apE", '\u{002A70}'),
-    ("apacir", '\u{002A6F}'),
-    ("ape", '\u{00224A}'),
-    ("apid", '\u{00224B}'),
-    ("apos", '\u{000027}'),
-    ("approx", '\u{002248}'),
-    ("approxeq", '\u{00224A}'),
-    ("aring", '\u{0000E5}'),
-    ("ascr", '\u{01D4B6}'),
-    ("ast", '\u{00002A}'),
-    ("asymp", '\u{002248}'),
-    ("asympeq", '\u{00224D}'),
-    ("atilde", '\u{0000E3}'),
-    ("auml", '\u{0000E4}'),
-    ("awconint", '\u{002233}'),
-    ("awint", '\u{002A11}'),
-    ("bNot", '\u{002AED}'),
-    ("backcong", '\u{00224C}'),
-    ("backepsilon", '\u{0003F6}'),
-    ("backprime", '\u{002035}'),
-    ("backsim", '\u{00223D}'),
-    ("backsimeq", '\u{0022CD}'),
-    ("barvee", '\u{0022BD}'),
-    ("barwed", '\u{002305}'),
-    ("barwedge", '\u{002305}'),
-    ("bbrk", '\u{0023B5}'),
-    ("bbrktbrk", '\u{0023B6}'),
-    ("bcong", '\u{00224C}'),
-    ("bcy", '\u{000431}'),
-    ("bdquo", '\u{00201E}'),
-    ("becaus", '\u{002235}'),
-    ("because", '\u{002235}'),
-    ("bemptyv", '\u{0029B0}'),
-    ("bepsi", '\u{0003F6}'),
-    ("bernou", '\u{00212C}'),
-    ("beta", '\u{0003B2}'),
-    ("beth", '\u{002136}'),
-    ("between", '\u{00226C}'),
-    ("bfr", '\u{01D51F}'),
-    ("bigcap", '\u{0022C2}'),
-    ("bigcirc", '\u{0025EF}'),
-    ("bigcup", '\u{0022C3}'),
-    ("bigodot", '\u{002A00}'),
-    ("bigoplus", '\u{002A01}'),
-    ("bigotimes", '\u{002A02}'),
-    ("bigsqcup", '\u{002A06}'),
-    ("bigstar", '\u{002605}'),
-    ("bigtriangledown", '\u{0025BD}'),
-    ("bigtriangleup", '\u{0025B3}'),
-    ("biguplus", '\u{002A04}'),
-    ("bigvee", '\u{0022C1}'),
-    ("bigwedge", '\u{0022C0}'),
-    ("bkarow", '\u{00290D}'),
-    ("blacklozenge", '\u{0029EB}'),
-    ("blacksquare", '\u{0025AA}'),
-    ("blacktriangle", '\u{0025B4}'),
-    ("blacktriangledown", '\u{0025BE}'),
-    ("blacktriangleleft", '\u{0025C2}'),
-    ("blacktriangleright", '\u{0025B8}'),
-    ("blank", '\u{002423}'),
-    ("blk12", '\u{002592}'),
-    ("blk14", '\u{002591}'),
-    ("blk34", '\u{002593}'),
-    ("block", '\u{002588}'),
-    ("bnot", '\u{002310}'),
-    ("bopf", '\u{01D553}'),
-    ("bot", '\u{0022A5}'),
-    ("bottom", '\u{0022A5}'),
-    ("bowtie", '\u{0022C8}'),
-    ("boxDL", '\u{002557}'),
-    ("boxDR", '\u{002554}'),
-    ("boxDl", '\u{002556}'),
-    ("boxDr", '\u{002553}'),
-    ("boxH", '\u{002550}'),
-    ("boxHD", '\u{002566}'),
-    ("boxHU", '\u{002569}'),
-    ("boxHd", '\u{002564}'),
-    ("boxHu", '\u{002567}'),
-    ("boxUL", '\u{00255D}'),
-    ("boxUR", '\u{00255A}'),
-    ("boxUl", '\u{00255C}'),
-    ("boxUr", '\u{002559}'),
-    ("boxV", '\u{002551}'),
-    ("boxVH", '\u{00256C}'),
-    ("boxVL", '\u{002563}'),
-    ("boxVR", '\u{002560}'),
-    ("boxVh", '\u{00256B}'),
-    ("boxVl", '\u{002562}'),
-    ("boxVr", '\u{00255F}'),
-    ("boxbox", '\u{0029C9}'),
-    ("boxdL", '\u{002555}'),
-    ("boxdR", '\u{002552}'),
-    ("boxdl", '\u{002510}'),
-    ("boxdr", '\u{00250C}'),
-    ("boxh", '\u{002500}'),
-    ("boxhD", '\u{002565}'),
-    ("boxhU", '\u{002568}'),
-    ("boxhd", '\u{00252C}'),
-    ("boxhu", '\u{002534}'),
-    ("boxminus", '\u{00229F}'),
-    ("boxplus", '\u{00229E}'),
-    ("boxtimes", '\u{0022A0}'),
-    ("boxuL", '\u{00255B}'),
-    ("boxuR", '\u{002558}'),
-    ("boxul", '\u{002518}'),
-    ("boxur", '\u{002514}'),
-    ("boxv", '\u{002502}'),
-    ("boxvH", '\u{00256A}'),
-    ("boxvL", '\u{002561}'),
-    ("boxvR", '\u{00255E}'),
-    ("boxvh", '\u{00253C}'),
-    ("boxvl", '\u{002524}'),
-    ("boxvr", '\u{00251C}'),
-    ("bprime", '\u{002035}'),
-    ("breve", '\u{0002D8}'),
-    ("brvbar", '\u{0000A6}'),
-    ("bscr", '\u{01D4B7}'),
-    ("bsemi", '\u{00204F}'),
-    ("bsim", '\u{00223D}'),
-    ("bsime", '\u{0022CD}'),
-    ("bsol", '\u{00005C}'),
-    ("bsolb", '\u{0029C5}'),
-    ("bsolhsub", '\u{0027C8}'),
-    ("bull", '\u{002022}'),
-    ("bullet", '\u{002022}'),
-    ("bump", '\u{00224E}'),
-    ("bumpE", '\u{002AAE}'),
-    ("bumpe", '\u{00224F}'),
-    ("bumpeq", '\u{00224F}'),
-    ("cacute", '\u{000107}'),
-    ("cap", '\u{002229}'),
-    ("capand", '\u{002A44}'),
-    ("capbrcup", '\u{002A49}'),
-    ("capcap", '\u{002A4B}'),
-    ("capcup", '\u{002A47}'),
-    ("capdot", '\u{002A40}'),
-    ("caret", '\u{002041}'),
-    ("caron", '\u{0002C7}'),
-    ("ccaps", '\u{002A4D}'),
-    ("ccaron", '\u{00010D}'),
-    ("ccedil", '\u{0000E7}'),
-    ("ccirc", '\u{000109}'),
-    ("ccups", '\u{002A4C}'),
-    ("ccupssm", '\u{002A50}'),
-    ("cdot", '\u{00010B}'),
-    ("cedil", '\u{0000B8}'),
-    ("cemptyv", '\u{0029B2}'),
-    ("cent", '\u{0000A2}'),
-    ("centerdot", '\u{0000B7}'),
-    ("cfr", '\u{01D520}'),
-    ("chcy", '\u{000447}'),
-    ("check", '\u{002713}'),
-    ("checkmark", '\u{002713}'),
-    ("chi", '\u{0003C7}'),
-    ("cir", '\u{0025CB}'),
-    ("cirE", '\u{0029C3}'),
-    ("circ", '\u{0002C6}'),
-    ("circeq", '\u{002257}'),
-    ("circlearrowleft", '\u{0021BA}'),
-    ("circlearrowright", '\u{0021BB}'),
-    ("circledR", '\u{0000AE}'),
-    ("circledS", '\u{0024C8}'),
-    ("circledast", '\u{00229B}'),
-    ("circledcirc", '\u{00229A}'),
-    ("circleddash", '\u{00229D}'),
-    ("cire", '\u{002257}'),
-    ("cirfnint", '\u{002A10}'),
-    ("cirmid", '\u{002AEF}'),
-    ("cirscir", '\u{0029C2}'),
-    ("clubs", '\u{002663}'),
-    ("clubsuit", '\u{002663}'),
-    ("colon", '\u{00003A}'),
-    ("colone", '\u{002254}'),
-    ("coloneq", '\u{002254}'),
-    ("comma", '\u{00002C}'),
-    ("commat", '\u{000040}'),
-    ("comp", '\u{002201}'),
-    ("compfn", '\u{002218}'),
-    ("complement", '\u{002201}'),
-    ("complexes", '\u{002102}'),
-    ("cong", '\u{002245}'),
-    ("congdot", '\u{002A6D}'),
-    ("conint", '\u{00222E}'),
-    ("copf", '\u{01D554}'),
-    ("coprod", '\u{002210}'),
-    ("copy", '\u{0000A9}'),
-    ("copysr", '\u{002117}'),
-    ("crarr", '\u{0021B5}'),
-    ("cross", '\u{002717}'),
-    ("cscr", '\u{01D4B8}'),
-    ("csub", '\u{002ACF}'),
-    ("csube", '\u{002AD1}'),
-    ("csup", '\u{002AD0}'),
-    ("csupe", '\u{002AD2}'),
-    ("ctdot", '\u{0022EF}'),
-    ("cudarrl", '\u{002938}'),
-    ("cudarrr", '\u{002935}'),
-    ("cuepr", '\u{0022DE}'),
-    ("cuesc", '\u{0022DF}'),
-    ("cularr", '\u{0021B6}'),
-    ("cularrp", '\u{00293D}'),
-    ("cup", '\u{00222A}'),
-    ("cupbrcap", '\u{002A48}'),
-    ("cupcap", '\u{002A46}'),
-    ("cupcup", '\u{002A4A}'),
-    ("cupdot", '\u{00228D}'),
-    ("cupor", '\u{002A45}'),
-    ("curarr", '\u{0021B7}'),
-    ("curarrm", '\u{00293C}'),
-    ("curlyeqprec", '\u{0022DE}'),
-    ("curlyeqsucc", '\u{0022DF}'),
-    ("curlyvee", '\u{0022CE}'),
-    ("curlywedge", '\u{0022CF}'),
-    ("curren", '\u{0000A4}'),
-    ("curvearrowleft", '\u{0021B6}'),
-    ("curvearrowright", '\u{0021B7}'),
-    ("cuvee", '\u{0022CE}'),
-    ("cuwed", '\u{0022CF}'),
-    ("cwconint", '\u{002232}'),
-    ("cwint", '\u{002231}'),
-    ("cylcty", '\u{00232D}'),
-    ("dArr", '\u{0021D3}'),
-    ("dHar", '\u{002965}'),
-    ("dagger", '\u{002020}'),
-    ("daleth", '\u{002138}'),
-    ("darr", '\u{002193}'),
-    ("dash", '\u{002010}'),
-    ("dashv", '\u{0022A3}'),
-    ("dbkarow", '\u{00290F}'),
-    ("dblac", '\u{0002DD}'),
-    ("dcaron", '\u{00010F}'),
-    ("dcy", '\u{000434}'),
-    ("dd", '\u{002146}'),
-    ("ddagger", '\u{002021}'),
-    ("ddarr", '\u{0021CA}'),
-    ("ddotseq", '\u{002A77}'),
-    ("deg", '\u{0000B0}'),
-    ("delta", '\u{0003B4}'),
-    ("demptyv", '\u{0029B1}'),
-    ("dfisht", '\u{00297F}'),
-    ("dfr", '\u{01D521}'),
-    ("dharl", '\u{0021C3}'),
-    ("dharr", '\u{0021C2}'),
-    ("diam", '\u{0022C4}'),
-    ("diamond", '\u{0022C4}'),
-    ("diamondsuit", '\u{002666}'),
-    ("diams", '\u{002666}'),
-    ("die", '\u{0000A8}'),
-    ("digamma", '\u{0003DD}'),
-    ("disin", '\u{0022F2}'),
-    ("div", '\u{0000F7}'),
-    ("divide", '\u{0000F7}'),
-    ("divideontimes", '\u{0022C7}'),
-    ("divonx", '\u{0022C7}'),
-    ("djcy", '\u{000452}'),
-    ("dlcorn", '\u{00231E}'),
-    ("dlcrop", '\u{00230D}'),
-    ("dollar", '\u{000024}'),
-    ("dopf", '\u{01D555}'),
-    ("dot", '\u{0002D9}'),
-    ("doteq", '\u{002250}'),
-    ("doteqdot", '\u{002251}'),
-    ("dotminus", '\u{002238}'),
-    ("dotplus", '\u{002214}'),
-    ("dotsquare", '\u{0022A1}'),
-    ("doublebarwedge", '\u{002306}'),
-    ("downarrow", '\u{002193}'),
-    ("downdownarrows", '\u{0021CA}'),
-    ("downharpoonleft", '\u{0021C3}'),
-    ("downharpoonright", '\u{0021C2}'),
-    ("drbkarow", '\u{002910}'),
-    ("drcorn", '\u{00231F}'),
-    ("drcrop", '\u{00230C}'),
-    ("dscr", '\u{01D4B9}'),
-    ("dscy", '\u{000455}'),
-    ("dsol", '\u{0029F6}'),
-    ("dstrok", '\u{000111}'),
-    ("dtdot", '\u{0022F1}'),
-    ("dtri", '\u{0025BF}'),
-    ("dtrif", '\u{0025BE}'),
-    ("duarr", '\u{0021F5}'),
-    ("duhar", '\u{00296F}'),
-    ("dwangle", '\u{0029A6}'),
-    ("dzcy", '\u{00045F}'),
-    ("dzigrarr", '\u{0027FF}'),
-    ("eDDot", '\u{002A77}'),
-    ("eDot", '\u{002251}'),
-    ("eacute", '\u{0000E9}'),
-    ("easter", '\u{002A6E}'),
-    ("ecaron", '\u{00011B}'),
-    ("ecir", '\u{002256}'),
-    ("ecirc", '\u{0000EA}'),
-    ("ecolon", '\u{002255}'),
-    ("ecy", '\u{00044D}'),
-    ("edot", '\u{000117}'),
-    ("ee", '\u{002147}'),
-    ("efDot", '\u{002252}'),
-    ("efr", '\u{01D522}'),
-    ("eg", '\u{002A9A}'),
-    ("egrave", '\u{0000E8}'),
-    ("egs", '\u{002A96}'),
-    ("egsdot", '\u{002A98}'),
-    ("el", '\u{002A99}'),
-    ("elinters", '\u{0023E7}'),
-    ("ell", '\u{002113}'),
-    ("els", '\u{002A95}'),
-    ("elsdot", '\u{002A97}'),
-    ("emacr", '\u{000113}'),
-    ("empty", '\u{002205}'),
-    ("emptyset", '\u{002205}'),
-    ("emptyv", '\u{002205}'),
-    ("emsp", '\u{002003}'),
-    ("emsp13", '\u{002004}'),
-    ("emsp14", '\u{002005}'),
-    ("eng", '\u{00014B}'),
-    ("ensp", '\u{002002}'),
-    ("eogon", '\u{000119}'),
-    ("eopf", '\u{01D556}'),
-    ("epar", '\u{0022D5}'),
-    ("eparsl", '\u{0029E3}'),
-    ("eplus", '\u{002A71}'),
-    ("epsi", '\u{0003B5}'),
-    ("epsilon", '\u{0003B5}'),
-    ("epsiv", '\u{0003F5}'),
-    ("eqcirc", '\u{002256}'),
-    ("eqcolon", '\u{002255}'),
-    ("eqsim", '\u{002242}'),
-    ("eqslantgtr", '\u{002A96}'),
-    ("eqslantless", '\u{002A95}'),
-    ("equals", '\u{00003D}'),
-    ("equest", '\u{00225F}'),
-    ("equiv", '\u{002261}'),
-    ("equivDD", '\u{002A78}'),
-    ("eqvparsl", '\u{0029E5}'),
-    ("erDot", '\u{002253}'),
-    ("erarr", '\u{002971}'),
-    ("escr", '\u{00212F}'),
-    ("esdot", '\u{002250}'),
-    ("esim", '\u{002242}'),
-    ("eta", '\u{0003B7}'),
-    ("eth", '\u{0000F0}'),
-    ("euml", '\u{0000EB}'),
-    ("euro", '\u{0020AC}'),
-    ("excl", '\u{000021}'),
-    ("exist", '\u{002203}'),
-    ("expectation", '\u{002130}'),
-    ("exponentiale", '\u{002147}'),
-    ("fallingdotseq", '\u{002252}'),
-    ("fcy", '\u{000444}'),
-    ("female", '\u{002640}'),
-    ("ffilig", '\u{00FB03}'),
-    ("fflig", '\u{00FB00}'),
-    ("ffllig", '\u{00FB04}'),
-    ("ffr", '\u{01D523}'),
-    ("filig", '\u{00FB01}'),
-    ("flat", '\u{00266D}'),
-    ("fllig", '\u{00FB02}'),
-    ("fltns", '\u{0025B1}'),
-    ("fnof", '\u{000192}'),
-    ("fopf", '\u{01D557}'),
-    ("forall", '\u{002200}'),
-    ("fork", '\u{0022D4}'),
-    ("forkv", '\u{002AD9}'),
-    ("fpartint", '\u{002A0D}'),
-    ("frac12", '\u{0000BD}'),
-    ("frac13", '\u{002153}'),
-    ("frac14", '\u{0000BC}'),
-    ("frac15", '\u{002155}'),
-    ("frac16", '\u{002159}'),
-    ("frac18", '\u{00215B}'),
-    ("frac23", '\u{002154}'),
-    ("frac25", '\u{002156}'),
-    ("frac34", '\u{0000BE}'),
-    ("frac35", '\u{002157}'),
-    ("frac38", '\u{00215C}'),
-    ("frac45", '\u{002158}'),
-    ("frac56", '\u{00215A}'),
-    ("frac58", '\u{00215D}'),
-    ("frac78", '\u{00215E}'),
-    ("frasl", '\u{002044}'),
-    ("frown", '\u{002322}'),
-    ("fscr", '\u{01D4BB}'),
-    ("gE", '\u{002267}'),
-    ("gEl", '\u{002A8C}'),
-    ("gacute", '\u{0001F5}'),
-    ("gamma", '\u{0003B3}'),
-    ("gammad", '\u{0003DD}'),
-    ("gap", '\u{002A86}'),
-    ("gbreve", '\u{00011F}'),
-    ("gcirc", '\u{00011D}'),
-    ("gcy", '\u{000433}'),
-    ("gdot", '\u{000121}'),
-    ("ge", '\u{002265}'),
-    ("gel", '\u{0022DB}'),
-    ("geq", '\u{002265}'),
-    ("geqq", '\u{002267}'),
-    ("geqslant", '\u{002A7E}'),
-    ("ges", '\u{002A7E}'),
-    ("gescc", '\u{002AA9}'),
-    ("gesdot", '\u{002A80}'),
-    ("gesdoto", '\u{002A82}'),
-    ("gesdotol", '\u{002A84}'),
-    ("gesles", '\u{002A94}'),
-    ("gfr", '\u{01D524}'),
-    ("gg", '\u{00226B}'),
-    ("ggg", '\u{0022D9}'),
-    ("gimel", '\u{002137}'),
-    ("gjcy", '\u{000453}'),
-    ("gl", '\u{002277}'),
-    ("glE", '\u{002A92}'),
-    ("gla", '\u{002AA5}'),
-    ("glj", '\u{002AA4}'),
-    ("gnE", '\u{002269}'),
-    ("gnap", '\u{002A8A}'),
-    ("gnapprox", '\u{002A8A}'),
-    ("gne", '\u{002A88}'),
-    ("gneq", '\u{002A88}'),
-    ("gneqq", '\u{002269}'),
-    ("gnsim", '\u{0022E7}'),
-    ("gopf", '\u{01D558}'),
-    ("grave", '\u{000060}'),
-    ("gscr", '\u{00210A}'),
-    ("gsim", '\u{002273}'),
-    ("gsime", '\u{002A8E}'),
-    ("gsiml", '\u{002A90}'),
-    ("gt", '\u{00003E}'),
-    ("gtcc", '\u{002AA7}'),
-    ("gtcir", '\u{002A7A}'),
-    ("gtdot", '\u{0022D7}'),
-    ("gtlPar", '\u{002995}'),
-    ("gtquest", '\u{002A7C}'),
-    ("gtrapprox", '\u{002A86}'),
-    ("gtrarr", '\u{002978}'),
-    ("gtrdot", '\u{0022D7}'),
-    ("gtreqless", '\u{0022DB}'),
-    ("gtreqqless", '\u{002A8C}'),
-    ("gtrless", '\u{002277}'),
-    ("gtrsim", '\u{002273}'),
-    ("hArr", '\u{0021D4}'),
-    ("hairsp", '\u{00200A}'),
-    ("half", '\u{0000BD}'),
-    ("hamilt", '\u{00210B}'),
-    ("hardcy", '\u{00044A}'),
-    ("harr", '\u{002194}'),
-    ("harrcir", '\u{002948}'),
-    ("harrw", '\u{0021AD}'),
-    ("hbar", '\u{00210F}'),
-    ("hcirc", '\u{000125}'),
-    ("hearts", '\u{002665}'),
-    ("heartsuit", '\u{002665}'),
-    ("hellip", '\u{002026}'),
-    ("hercon", '\u{0022B9}'),
-    ("hfr", '\u{01D525}'),
-    ("hksearow", '\u{002925}'),
-    ("hkswarow", '\u{002926}'),
-    ("hoarr", '\u{0021FF}'),
-    ("homtht", '\u{00223B}'),
-    ("hookleftarrow", '\u{0021A9}'),
-    ("hookrightarrow", '\u{0021AA}'),
-    ("hopf", '\u{01D559}'),
-    ("horbar", '\u{002015}'),
-    ("hscr", '\u{01D4BD}'),
-    ("hslash", '\u{00210F}'),
-    ("hstrok", '\u{000127}'),
-    ("hybull", '\u{002043}'),
-    ("hyphen", '\u{002010}'),
-    ("iacute", '\u{0000ED}'),
-    ("ic", '\u{002063}'),
-    ("icirc", '\u{0000EE}'),
-    ("icy", '\u{000438}'),
-    ("iecy", '\u{000435}'),
-    ("iexcl", '\u{0000A1}'),
-    ("iff", '\u{0021D4}'),
-    ("ifr", '\u{01D526}'),
-    ("igrave", '\u{0000EC}'),
-    ("ii", '\u{002148}'),
-    ("iiiint", '\u{002A0C}'),
-    ("iiint", '\u{00222D}'),
-    ("iinfin", '\u{0029DC}'),
-    ("iiota", '\u{002129}'),
-    ("ijlig", '\u{000133}'),
-    ("imacr", '\u{00012B}'),
-    ("image", '\u{002111}'),
-    ("imagline", '\u{002110}'),
-    ("imagpart", '\u{002111}'),
-    ("imath", '\u{000131}'),
-    ("imof", '\u{0022B7}'),
-    ("imped", '\u{0001B5}'),
-    ("in", '\u{002208}'),
-    ("incare", '\u{002105}'),
-    ("infin", '\u{00221E}'),
-    ("infintie", '\u{0029DD}'),
-    ("inodot", '\u{000131}'),
-    ("int", '\u{00222B}'),
-    ("intcal", '\u{0022BA}'),
-    ("integers", '\u{002124}'),
-    ("intercal", '\u{0022BA}'),
-    ("intlarhk", '\u{002A17}'),
-    ("intprod", '\u{002A3C}'),
-    ("iocy", '\u{000451}'),
-    ("iogon", '\u{00012F}'),
-    ("iopf", '\u{01D55A}'),
-    ("iota", '\u{0003B9}'),
-    ("iprod", '\u{002A3C}'),
-    ("iquest", '\u{0000BF}'),
-    ("iscr", '\u{01D4BE}'),
-    ("isin", '\u{002208}'),
-    ("isinE", '\u{0022F9}'),
-    ("isindot", '\u{0022F5}'),
-    ("isins", '\u{0022F4}'),
-    ("isinsv", '\u{0022F3}'),
-    ("isinv", '\u{002208}'),
-    ("it", '\u{002062}'),
-    ("itilde", '\u{000129}'),
-    ("iukcy", '\u{000456}'),
-    ("iuml", '\u{0000EF}'),
-    ("jcirc", '\u{000135}'),
-    ("jcy", '\u{000439}'),
-    ("jfr", '\u{01D527}'),
-    ("jmath", '\u{000237}'),
-    ("jopf", '\u{01D55B}'),
-    ("jscr", '\u{01D4BF}'),
-    ("jsercy", '\u{000458}'),
-    ("jukcy", '\u{000454}'),
-    ("kappa", '\u{0003BA}'),
-    ("kappav", '\u{0003F0}'),
-    ("kcedil", '\u{000137}'),
-    ("kcy", '\u{00043A}'),
-    ("kfr", '\u{01D528}'),
-    ("kgreen", '\u{000138}'),
-    ("khcy", '\u{000445}'),
-    ("kjcy", '\u{00045C}'),
-    ("kopf", '\u{01D55C}'),
-    ("kscr", '\u{01D4C0}'),
-    ("lAarr", '\u{0021DA}'),
-    ("lArr", '\u{0021D0}'),
-    ("lAtail", '\u{00291B}'),
-    ("lBarr", '\u{00290E}'),
-    ("lE", '\u{002266}'),
-    ("lEg", '\u{002A8B}'),
-    ("lHar", '\u{002962}'),
-    ("lacute", '\u{00013A}'),
-    ("laemptyv", '\u{0029B4}'),
-    ("lagran", '\u{002112}'),
-    ("lambda", '\u{0003BB}'),
-    ("lang", '\u{0027E8}'),
-    ("langd", '\u{002991}'),
-    ("langle", '\u{0027E8}'),
-    ("lap", '\u{002A85}'),
-    ("laquo", '\u{0000AB}'),
-    ("larr", '\u{002190}'),
-    ("larrb", '\u{0021E4}'),
-    ("larrbfs", '\u{00291F}'),
-    ("larrfs", '\u{00291D}'),
-    ("larrhk", '\u{0021A9}'),
-    ("larrlp", '\u{0021AB}'),
-    ("larrpl", '\u{002939}'),
-    ("larrsim", '\u{002973}'),
-    ("larrtl", '\u{0021A2}'),
-    ("lat", '\u{002AAB}'),
-    ("latail", '\u{002919}'),
-    ("late", '\u{002AAD}'),
-    ("lbarr", '\u{00290C}'),
-    ("lbbrk", '\u{002772}'),
-    ("lbrace", '\u{00007B}'),
-    ("lbrack", '\u{00005B}'),
-    ("lbrke", '\u{00298B}'),
-    ("lbrksld", '\u{00298F}'),
-    ("lbrkslu", '\u{00298D}'),
-    ("lcaron", '\u{00013E}'),
-    ("lcedil", '\u{00013C}'),
-    ("lceil", '\u{002308}'),
-    ("lcub", '\u{00007B}'),
-    ("lcy", '\u{00043B}'),
-    ("ldca", '\u{002936}'),
-    ("ldquo", '\u{00201C}'),
-    ("ldquor", '\u{00201E}'),
-    ("ldrdhar", '\u{002967}'),
-    ("ldrushar", '\u{00294B}'),
-    ("ldsh", '\u{0021B2}'),
-    ("le", '\u{002264}'),
-    ("leftarrow", '\u{002190}'),
-    ("leftarrowtail", '\u{0021A2}'),
-    ("leftharpoondown", '\u{0021BD}'),
-    ("leftharpoonup", '\u{0021BC}'),
-    ("leftleftarrows", '\u{0021C7}'),
-    ("leftrightarrow", '\u{002194}'),
-    ("leftrightarrows", '\u{0021C6}'),
-    ("leftrightharpoons", '\u{0021CB}'),
-    ("leftrightsquigarrow", '\u{0021AD}'),
-    ("leftthreetimes", '\u{0022CB}'),
-    ("leg", '\u{0022DA}'),
-    ("leq", '\u{002264}'),
-    ("leqq", '\u{002266}'),
-    ("leqslant", '\u{002A7D}'),
-    ("les", '\u{002A7D}'),
-    ("lescc", '\u{002AA8}'),
-    ("lesdot", '\u{002A7F}'),
-    ("lesdoto", '\u{002A81}'),
-    ("lesdotor", '\u{002A83}'),
-    ("lesges", '\u{002A93}'),
-    ("lessapprox", '\u{002A85}'),
-    ("lessdot", '\u{0022D6}'),
-    ("lesseqgtr", '\u{0022DA}'),
-    ("lesseqqgtr", '\u{002A8B}'),
-    ("lessgtr", '\u{002276}'),
-    ("lesssim", '\u{002272}'),
-    ("lfisht", '\u{00297C}'),
-    ("lfloor", '\u{00230A}'),
-    ("lfr", '\u{01D529}'),
-    ("lg", '\u{002276}'),
-    ("lgE", '\u{002A91}'),
-    ("lhard", '\u{0021BD}'),
-    ("lharu", '\u{0021BC}'),
-    ("lharul", '\u{00296A}'),
-    ("lhblk", '\u{002584}'),
-    ("ljcy", '\u{000459}'),
-    ("ll", '\u{00226A}'),
-    ("llarr", '\u{0021C7}'),
-    ("llcorner", '\u{00231E}'),
-    ("llhard", '\u{00296B}'),
-    ("lltri", '\u{0025FA}'),
-    ("lmidot", '\u{000140}'),
-    ("lmoust", '\u{0023B0}'),
-    ("lmoustache", '\u{0023B0}'),
-    ("lnE", '\u{002268}'),
-    ("lnap", '\u{002A89}'),
-    ("lnapprox", '\u{002A89}'),
-    ("lne", '\u{002A87}'),
-    ("lneq", '\u{002A87}'),
-    ("lneqq", '\u{002268}'),
-    ("lnsim", '\u{0022E6}'),
-    ("loang", '\u{0027EC}'),
-    ("loarr", '\u{0021FD}'),
-    ("lobrk", '\u{0027E6}'),
-    ("longleftarrow", '\u{0027F5}'),
-    ("longleftrightarrow", '\u{0027F7}'),
-    ("longmapsto", '\u{0027FC}'),
-    ("longrightarrow", '\u{0027F6}'),
-    ("looparrowleft", '\u{0021AB}'),
-    ("looparrowright", '\u{0021AC}'),
-    ("lopar", '\u{002985}'),
-    ("lopf", '\u{01D55D}'),
-    ("loplus", '\u{002A2D}'),
-    ("lotimes", '\u{002A34}'),
-    ("lowast", '\u{002217}'),
-    ("lowbar", '\u{00005F}'),
-    ("loz", '\u{0025CA}'),
-    ("lozenge", '\u{0025CA}'),
-    ("lozf", '\u{0029EB}'),
-    ("lpar", '\u{000028}'),
-    ("lparlt", '\u{002993}'),
-    ("lrarr", '\u{0021C6}'),
-    ("lrcorner", '\u{00231F}'),
-    ("lrhar", '\u{0021CB}'),
-    ("lrhard", '\u{00296D}'),
-    ("lrm", '\u{00200E}'),
-    ("lrtri", '\u{0022BF}'),
-    ("lsaquo", '\u{002039}'),
-    ("lscr", '\u{01D4C1}'),
-    ("lsh", '\u{0021B0}'),
-    ("lsim", '\u{002272}'),
-    ("lsime", '\u{002A8D}'),
-    ("lsimg", '\u{002A8F}'),
-    ("lsqb", '\u{00005B}'),
-    ("lsquo", '\u{002018}'),
-    ("lsquor", '\u{00201A}'),
-    ("lstrok", '\u{000142}'),
-    ("lt", '\u{00003C}'),
-    ("ltcc", '\u{002AA6}'),
-    ("ltcir", '\u{002A79}'),
-    ("ltdot", '\u{0022D6}'),
-    ("lthree", '\u{0022CB}'),
-    ("ltimes", '\u{0022C9}'),
-    ("ltlarr", '\u{002976}'),
-    ("ltquest", '\u{002A7B}'),
-    ("ltrPar", '\u{002996}'),
-    ("ltri", '\u{0025C3}'),
-    ("ltrie", '\u{0022B4}'),
-    ("ltrif", '\u{0025C2}'),
-    ("lurdshar", '\u{00294A}'),
-    ("luruhar", '\u{002966}'),
-    ("mDDot", '\u{00223A}'),
-    ("macr", '\u{0000AF}'),
-    ("male", '\u{002642}'),
-    ("malt", '\u{002720}'),
-    ("maltese", '\u{002720}'),
-    ("map", '\u{0021A6}'),
-    ("mapsto", '\u{0021A6}'),
-    ("mapstodown", '\u{0021A7}'),
-    ("mapstoleft", '\u{0021A4}'),
-    ("mapstoup", '\u{0021A5}'),
-    ("marker", '\u{0025AE}'),
-    ("mcomma", '\u{002A29}'),
-    ("mcy", '\u{00043C}'),
-    ("mdash", '\u{002014}'),
-    ("measuredangle", '\u{002221}'),
-    ("mfr", '\u{01D52A}'),
-    ("mho", '\u{002127}'),
-    ("micro", '\u{0000B5}'),
-    ("mid", '\u{002223}'),
-    ("midast", '\u{00002A}'),
-    ("midcir", '\u{002AF0}'),
-    ("middot", '\u{0000B7}'),
-    ("minus", '\u{002212}'),
-    ("minusb", '\u{00229F}'),
-    ("minusd", '\u{002238}'),
-    ("minusdu", '\u{002A2A}'),
-    ("mlcp", '\u{002ADB}'),
-    ("mldr", '\u{002026}'),
-    ("mnplus", '\u{002213}'),
-    ("models", '\u{0022A7}'),
-    ("mopf", '\u{01D55E}'),
-    ("mp", '\u{002213}'),
-    ("mscr", '\u{01D4C2}'),
-    ("mstpos", '\u{00223E}'),
-    ("mu", '\u{0003BC}'),
-    ("multimap", '\u{0022B8}'),
-    ("mumap", '\u{0022B8}'),
-    ("nLeftarrow", '\u{0021CD}'),
-    ("nLeftrightarrow", '\u{0021CE}'),
-    ("nRightarrow", '\u{0021CF}'),
-    ("nVDash", '\u{0022AF}'),
-    ("nVdash", '\u{0022AE}'),
-    ("nabla", '\u{002207}'),
-    ("nacute", '\u{000144}'),
-    ("nap", '\u{002249}'),
-    ("napos", '\u{000149}'),
-    ("napprox", '\u{002249}'),
-    ("natur", '\u{00266E}'),
-    ("natural", '\u{00266E}'),
-    ("naturals", '\u{002115}'),
-    ("nbsp", '\u{0000A0}'),
-    ("ncap", '\u{002A43}'),
-    ("ncaron", '\u{000148}'),
-    ("ncedil", '\u{000146}'),
-    ("ncong", '\u{002247}'),
-    ("ncup", '\u{002A42}'),
-    ("ncy", '\u{00043D}'),
-    ("ndash", '\u{002013}'),
-    ("ne", '\u{002260}'),
-    ("neArr", '\u{0021D7}'),
-    ("nearhk", '\u{002924}'),
-    ("nearr", '\u{002197}'),
-    ("nearrow", '\u{002197}'),
-    ("nequiv", '\u{002262}'),
-    ("nesear", '\u{002928}'),
-    ("nexist", '\u{002204}'),
-    ("nexists", '\u{002204}'),
-    ("nfr", '\u{01D52B}'),
-    ("nge", '\u{002271}'),
-    ("ngeq", '\u{002271}'),
-    ("ngsim", '\u{002275}'),
-    ("ngt", '\u{00226F}'),
-    ("ngtr", '\u{00226F}'),
-    ("nhArr", '\u{0021CE}'),
-    ("nharr", '\u{0021AE}'),
-    ("nhpar", '\u{002AF2}'),
-    ("ni", '\u{00220B}'),
-    ("nis", '\u{0022FC}'),
-    ("nisd", '\u{0022FA}'),
-    ("niv", '\u{00220B}'),
-    ("njcy", '\u{00045A}'),
-    ("nlArr", '\u{0021CD}'),
-    ("nlarr", '\u{00219A}'),
-    ("nldr", '\u{002025}'),
-    ("nle", '\u{002270}'),
-    ("nleftarrow", '\u{00219A}'),
-    ("nleftrightarrow", '\u{0021AE}'),
-    ("nleq", '\u{002270}'),
-    ("nless", '\u{00226E}'),
-    ("nlsim", '\u{002274}'),
-    ("nlt", '\u{00226E}'),
-    ("nltri", '\u{0022EA}'),
-    ("nltrie", '\u{0022EC}'),
-    ("nmid", '\u{002224}'),
-    ("nopf", '\u{01D55F}'),
-    ("not", '\u{0000AC}'),
-    ("notin", '\u{002209}'),
-    ("notinva", '\u{002209}'),
-    ("notinvb", '\u{0022F7}'),
-    ("notinvc", '\u{0022F6}'),
-    ("notni", '\u{00220C}'),
-    ("notniva", '\u{00220C}'),
-    ("notnivb", '\u{0022FE}'),
-    ("notnivc", '\u{0022FD}'),
-    ("npar", '\u{002226}'),
-    ("nparallel", '\u{002226}'),
-    ("npolint", '\u{002A14}'),
-    ("npr", '\u{002280}'),
-    ("nprcue", '\u{0022E0}'),
-    ("nprec", '\u{002280}'),
-    ("nrArr", '\u{0021CF}'),
-    ("nrarr", '\u{00219B}'),
-    ("nrightarrow", '\u{00219B}'),
-    ("nrtri", '\u{0022EB}'),
-    ("nrtrie", '\u{0022ED}'),
-    ("nsc", '\u{002281}'),
-    ("nsccue", '\u{0022E1}'),
-    ("nscr", '\u{01D4C3}'),
-    ("nshortmid", '\u{002224}'),
-    ("nshortparallel", '\u{002226}'),
-    ("nsim", '\u{002241}'),
-    ("nsime", '\u{002244}'),
-    ("nsimeq", '\u{002244}'),
-    ("nsmid", '\u{002224}'),
-    ("nspar", '\u{002226}'),
-    ("nsqsube", '\u{0022E2}'),
-    ("nsqsupe", '\u{0022E3}'),
-    ("nsub", '\u{002284}'),
-    ("nsube", '\u{002288}'),
-    ("nsubseteq", '\u{002288}'),
-    ("nsucc", '\u{002281}'),
-    ("nsup", '\u{002285}'),
-    ("nsupe", '\u{002289}'),
-    ("nsupseteq", '\u{002289}'),
-    ("ntgl", '\u{002279}'),
-    ("ntilde", '\u{0000F1}'),
-    ("ntlg", '\u{002278}'),
-    ("ntriangleleft", '\u{0022EA}'),
-    ("ntrianglelefteq", '\u{0022EC}'),
-    ("ntriangleright", '\u{0022EB}'),
-    ("ntrianglerighteq", '\u{0022ED}'),
-    ("nu", '\u{0003BD}'),
-    ("num", '\u{000023}'),
-    ("numero", '\u{002116}'),
-    ("numsp", '\u{002007}'),
-    ("nvDash", '\u{0022AD}'),
-    ("nvHarr", '\u{002904}'),
-    ("nvdash", '\u{0022AC}'),
-    ("nvinfin", '\u{0029DE}'),
-    ("nvlArr", '\u{002902}'),
-    ("nvrArr", '\u{002903}'),
-    ("nwArr", '\u{0021D6}'),
-    ("nwarhk", '\u{002923}'),
-    ("nwarr", '\u{002196}'),
-    ("nwarrow", '\u{002196}'),
-    ("nwnear", '\u{002927}'),
-    ("oS", '\u{0024C8}'),
-    ("oacute", '\u{0000F3}'),
-    ("oast", '\u{00229B}'),
-    ("ocir", '\u{00229A}'),
-    ("ocirc", '\u{0000F4}'),
-    ("ocy", '\u{00043E}'),
-    ("odash", '\u{00229D}'),
-    ("odblac", '\u{000151}'),
-    ("odiv", '\u{002A38}'),
-    ("odot", '\u{002299}'),
-    ("odsold", '\u{0029BC}'),
-    ("oelig", '\u{000153}'),
-    ("ofcir", '\u{0029BF}'),
-    ("ofr", '\u{01D52C}'),
-    ("ogon", '\u{0002DB}'),
-    ("ograve", '\u{0000F2}'),
-    ("ogt", '\u{0029C1}'),
-    ("ohbar", '\u{0029B5}'),
-    ("ohm", '\u{0003A9}'),
-    ("oint", '\u{00222E}'),
-    ("olarr", '\u{0021BA}'),
-    ("olcir", '\u{0029BE}'),
-    ("olcross", '\u{0029BB}'),
-    ("oline", '\u{00203E}'),
-    ("olt", '\u{0029C0}'),
-    ("omacr", '\u{00014D}'),
-    ("omega", '\u{0003C9}'),
-    ("omicron", '\u{0003BF}'),
-    ("omid", '\u{0029B6}'),
-    ("ominus", '\u{002296}'),
-    ("oopf", '\u{01D560}'),
-    ("opar", '\u{0029B7}'),
-    ("operp", '\u{0029B9}'),
-    ("oplus", '\u{002295}'),
-    ("or", '\u{002228}'),
-    ("orarr", '\u{0021BB}'),
-    ("ord", '\u{002A5D}'),
-    ("order", '\u{002134}'),
-    ("orderof", '\u{002134}'),
-    ("ordf", '\u{0000AA}'),
-    ("ordm", '\u{0000BA}'),
-    ("origof", '\u{0022B6}'),
-    ("oror", '\u{002A56}'),
-    ("orslope", '\u{002A57}'),
-    ("orv", '\u{002A5B}'),
-    ("oscr", '\u{002134}'),
-    ("oslash", '\u{0000F8}'),
-    ("osol", '\u{002298}'),
-    ("otilde", '\u{0000F5}'),
-    ("otimes", '\u{002297}'),
-    ("otimesas", '\u{002A36}'),
-    ("ouml", '\u{0000F6}'),
-    ("ovbar", '\u{00233D}'),
-    ("par", '\u{002225}'),
-    ("para", '\u{0000B6}'),
-    ("parallel", '\u{002225}'),
-    ("parsim", '\u{002AF3}'),
-    ("parsl", '\u{002AFD}'),
-    ("part", '\u{002202}'),
-    ("pcy", '\u{00043F}'),
-    ("percnt", '\u{000025}'),
-    ("period", '\u{00002E}'),
-    ("permil", '\u{002030}'),
-    ("perp", '\u{0022A5}'),
-    ("pertenk", '\u{002031}'),
-    ("pfr", '\u{01D52D}'),
-    ("phi", '\u{0003C6}'),
-    ("phiv", '\u{0003D5}'),
-    ("phmmat", '\u{002133}'),
-    ("phone", '\u{00260E}'),
-    ("pi", '\u{0003C0}'),
-    ("pitchfork", '\u{0022D4}'),
-    ("piv", '\u{0003D6}'),
-    ("planck", '\u{00210F}'),
-    ("planckh", '\u{00210E}'),
-    ("plankv", '\u{00210F}'),
-    ("plus", '\u{00002B}'),
-    ("plusacir", '\u{002A23}'),
-    ("plusb", '\u{00229E}'),
-    ("pluscir", '\u{002A22}'),
-    ("plusdo", '\u{002214}'),
-    ("plusdu", '\u{002A25}'),
-    ("pluse", '\u{002A72}'),
-    ("plusmn", '\u{0000B1}'),
-    ("plussim", '\u{002A26}'),
-    ("plustwo", '\u{002A27}'),
-    ("pm", '\u{0000B1}'),
-    ("pointint", '\u{002A15}'),
-    ("popf", '\u{01D561}'),
-    ("pound", '\u{0000A3}'),
-    ("pr", '\u{00227A}'),
-    ("prE", '\u{002AB3}'),
-    ("prap", '\u{002AB7}'),
-    ("prcue", '\u{00227C}'),
-    ("pre", '\u{002AAF}'),
-    ("prec", '\u{00227A}'),
-    ("precapprox", '\u{002AB7}'),
-    ("preccurlyeq", '\u{00227C}'),
-    ("preceq", '\u{002AAF}'),
-    ("precnapprox", '\u{002AB9}'),
-    ("precneqq", '\u{002AB5}'),
-    ("precnsim", '\u{0022E8}'),
-    ("precsim", '\u{00227E}'),
-    ("prime", '\u{002032}'),
-    ("primes", '\u{002119}'),
-    ("prnE", '\u{002AB5}'),
-    ("prnap", '\u{002AB9}'),
-    ("prnsim", '\u{0022E8}'),
-    ("prod", '\u{00220F}'),
-    ("profalar", '\u{00232E}'),
-    ("profline", '\u{002312}'),
-    ("profsurf", '\u{002313}'),
-    ("prop", '\u{00221D}'),
-    ("propto", '\u{00221D}'),
-    ("prsim", '\u{00227E}'),
-    ("prurel", '\u{0022B0}'),
-    ("pscr", '\u{01D4C5}'),
-    ("psi", '\u{0003C8}'),
-    ("puncsp", '\u{002008}'),
-    ("qfr", '\u{01D52E}'),
-    ("qint", '\u{002A0C}'),
-    ("qopf", '\u{01D562}'),
-    ("qprime", '\u{002057}'),
-    ("qscr", '\u{01D4C6}'),
-    ("quaternions", '\u{00210D}'),
-    ("quatint", '\u{002A16}'),
-    ("quest", '\u{00003F}'),
-    ("questeq", '\u{00225F}'),
-    ("quot", '\u{000022}'),
-    ("rAarr", '\u{0021DB}'),
-    ("rArr", '\u{0021D2}'),
-    ("rAtail", '\u{00291C}'),
-    ("rBarr", '\u{00290F}'),
-    ("rHar", '\u{002964}'),
-    ("racute", '\u{000155}'),
-    ("radic", '\u{00221A}'),
-    ("raemptyv", '\u{0029B3}'),
-    ("rang", '\u{0027E9}'),
-    ("rangd", '\u{002992}'),
-    ("range", '\u{0029A5}'),
-    ("rangle", '\u{0027E9}'),
-    ("raquo", '\u{0000BB}'),
-    ("rarr", '\u{002192}'),
-    ("rarrap", '\u{002975}'),
-    ("rarrb", '\u{0021E5}'),
-    ("rarrbfs", '\u{002920}'),
-    ("rarrc", '\u{002933}'),
-    ("rarrfs", '\u{00291E}'),
-    ("rarrhk", '\u{0021AA}'),
-    ("rarrlp", '\u{0021AC}'),
-    ("rarrpl", '\u{002945}'),
-    ("rarrsim", '\u{002974}'),
-    ("rarrtl", '\u{0021A3}'),
-    ("rarrw", '\u{00219D}'),
-    ("ratail", '\u{00291A}'),
-    ("ratio", '\u{002236}'),
-    ("rationals", '\u{00211A}'),
-    ("rbarr", '\u{00290D}'),
-    ("rbbrk", '\u{002773}'),
-    ("rbrace", '\u{00007D}'),
-    ("rbrack", '\u{00005D}'),
-    ("rbrke", '\u{00298C}'),
-    ("rbrksld", '\u{00298E}'),
-    ("rbrkslu", '\u{002990}'),
-    ("rcaron", '\u{000159}'),
-    ("rcedil", '\u{000157}'),
-    ("rceil", '\u{002309}'),
-    ("rcub", '\u{00007D}'),
-    ("rcy", '\u{000440}'),
-    ("rdca", '\u{002937}'),
-    ("rdldhar", '\u{002969}'),
-    ("rdquo", '\u{00201D}'),
-    ("rdquor", '\u{00201D}'),
-    ("rdsh", '\u{0021B3}'),
-    ("real", '\u{00211C}'),
-    ("realine", '\u{00211B}'),
-    ("realpart", '\u{00211C}'),
-    ("reals", '\u{00211D}'),
-    ("rect", '\u{0025AD}'),
-    ("reg", '\u{0000AE}'),
-    ("rfisht", '\u{00297D}'),
-    ("rfloor", '\u{00230B}'),
-    ("rfr", '\u{01D52F}'),
-    ("rhard", '\u{0021C1}'),
-    ("rharu", '\u{0021C0}'),
-    ("rharul", '\u{00296C}'),
-    ("rho", '\u{0003C1}'),
-    ("rhov", '\u{0003F1}'),
-    ("rightarrow", '\u{002192}'),
-    ("rightarrowtail", '\u{0021A3}'),
-    ("rightharpoondown", '\u{0021C1}'),
-    ("rightharpoonup", '\u{0021C0}'),
-    ("rightleftarrows", '\u{0021C4}'),
-    ("rightleftharpoons", '\u{0021CC}'),
-    ("rightrightarrows", '\u{0021C9}'),
-    ("rightsquigarrow", '\u{00219D}'),
-    ("rightthreetimes", '\u{0022CC}'),
-    ("ring", '\u{0002DA}'),
-    ("risingdotseq", '\u{002253}'),
-    ("rlarr", '\u{0021C4}'),
-    ("rlhar", '\u{0021CC}'),
-    ("rlm", '\u{00200F}'),
-    ("rmoust", '\u{0023B1}'),
-    ("rmoustache", '\u{0023B1}'),
-    ("rnmid", '\u{002AEE}'),
-    ("roang", '\u{0027ED}'),
-    ("roarr", '\u{0021FE}'),
-    ("robrk", '\u{0027E7}'),
-    ("ropar", '\u{002986}'),
-    ("ropf", '\u{01D563}'),
-    ("roplus", '\u{002A2E}'),
-    ("rotimes", '\u{002A35}'),
-    ("rpar", '\u{000029}'),
-    ("rpargt", '\u{002994}'),
-    ("rppolint", '\u{002A12}'),
-    ("rrarr", '\u{0021C9}'),
-    ("rsaquo", '\u{00203A}'),
-    ("rscr", '\u{01D4C7}'),
-    ("rsh", '\u{0021B1}'),
-    ("rsqb", '\u{00005D}'),
-    ("rsquo", '\u{002019}'),
-    ("rsquor", '\u{002019}'),
-    ("rthree", '\u{0022CC}'),
-    ("rtimes", '\u{0022CA}'),
-    ("rtri", '\u{0025B9}'),
-    ("rtrie", '\u{0022B5}'),
-    ("rtrif", '\u{0025B8}'),
-    ("rtriltri", '\u{0029CE}'),
-    ("ruluhar", '\u{002968}'),
-    ("rx", '\u{00211E}'),
-    ("sacute", '\u{00015B}'),
-    ("sbquo", '\u{00201A}'),
-    ("sc", '\u{00227B}'),
-    ("scE", '\u{002AB4}'),
-    ("scap", '\u{002AB8}'),
-    ("scaron", '\u{000161}'),
-    ("sccue", '\u{00227D}'),
-    ("sce", '\u{002AB0}'),
-    ("scedil", '\u{00015F}'),
-    ("scirc", '\u{00015D}'),
-    ("scnE", '\u{002AB6}'),
-    ("scnap", '\u{002ABA}'),
-    ("scnsim", '\u{0022E9}'),
-    ("scpolint", '\u{002A13}'),
-    ("scsim", '\u{00227F}'),
-    ("scy", '\u{000441}'),
-    ("sdot", '\u{0022C5}'),
-    ("sdotb", '\u{0022A1}'),
-    ("sdote", '\u{002A66}'),
-    ("seArr", '\u{0021D8}'),
-    ("searhk", '\u{002925}'),
-    ("searr", '\u{002198}'),
-    ("searrow", '\u{002198}'),
-    ("sect", '\u{0000A7}'),
-    ("semi", '\u{00003B}'),
-    ("seswar", '\u{002929}'),
-    ("setminus", '\u{002216}'),
-    ("setmn", '\u{002216}'),
-    ("sext", '\u{002736}'),
-    ("sfr", '\u{01D530}'),
-    ("sfrown", '\u{002322}'),
-    ("sharp", '\u{00266F}'),
-    ("shchcy", '\u{000449}'),
-    ("shcy", '\u{000448}'),
-    ("shortmid", '\u{002223}'),
-    ("shortparallel", '\u{002225}'),
-    ("shy", '\u{0000AD}'),
-    ("sigma", '\u{0003C3}'),
-    ("sigmaf", '\u{0003C2}'),
-    ("sigmav", '\u{0003C2}'),
-    ("sim", '\u{00223C}'),
-    ("simdot", '\u{002A6A}'),
-    ("sime", '\u{002243}'),
-    ("simeq", '\u{002243}'),
-    ("simg", '\u{002A9E}'),
-    ("simgE", '\u{002AA0}'),
-    ("siml", '\u{002A9D}'),
-    ("simlE", '\u{002A9F}'),
-    ("simne", '\u{002246}'),
-    ("simplus", '\u{002A24}'),
-    ("simrarr", '\u{002972}'),
-    ("slarr", '\u{002190}'),
-    ("smallsetminus", '\u{002216}'),
-    ("smashp", '\u{002A33}'),
-    ("smeparsl", '\u{0029E4}'),
-    ("smid", '\u{002223}'),
-    ("smile", '\u{002323}'),
-    ("smt", '\u{002AAA}'),
-    ("smte", '\u{002AAC}'),
-    ("softcy", '\u{00044C}'),
-    ("sol", '\u{00002F}'),
-    ("solb", '\u{0029C4}'),
-    ("solbar", '\u{00233F}'),
-    ("sopf", '\u{01D564}'),
-    ("spades", '\u{002660}'),
-    ("spadesuit", '\u{002660}'),
-    ("spar", '\u{002225}'),
-    ("sqcap", '\u{002293}'),
-    ("sqcup", '\u{002294}'),
-    ("sqsub", '\u{00228F}'),
-    ("sqsube", '\u{002291}'),
-    ("sqsubset", '\u{00228F}'),
-    ("sqsubseteq", '\u{002291}'),
-    ("sqsup", '\u{002290}'),
-    ("sqsupe", '\u{002292}'),
-    ("sqsupset", '\u{002290}'),
-    ("sqsupseteq", '\u{002292}'),
-    ("squ", '\u{0025A1}'),
-    ("square", '\u{0025A1}'),
-    ("squarf", '\u{0025AA}'),
-    ("squf", '\u{0025AA}'),
-    ("srarr", '\u{002192}'),
-    ("sscr", '\u{01D4C8}'),
-    ("ssetmn", '\u{002216}'),
-    ("ssmile", '\u{002323}'),
-    ("sstarf", '\u{0022C6}'),
-    ("star", '\u{002606}'),
-    ("starf", '\u{002605}'),
-    ("straightepsilon", '\u{0003F5}'),
-    ("straightphi", '\u{0003D5}'),
-    ("strns", '\u{0000AF}'),
-    ("sub", '\u{002282}'),
-    ("subE", '\u{002AC5}'),
-    ("subdot", '\u{002ABD}'),
-    ("sube", '\u{002286}'),
-    ("subedot", '\u{002AC3}'),
-    ("submult", '\u{002AC1}'),
-    ("subnE", '\u{002ACB}'),
-    ("subne", '\u{00228A}'),
-    ("subplus", '\u{002ABF}'),
-    ("subrarr", '\u{002979}'),
-    ("subset", '\u{002282}'),
-    ("subseteq", '\u{002286}'),
-    ("subseteqq", '\u{002AC5}'),
-    ("subsetneq", '\u{00228A}'),
-    ("subsetneqq", '\u{002ACB}'),
-    ("subsim", '\u{002AC7}'),
-    ("subsub", '\u{002AD5}'),
-    ("subsup", '\u{002AD3}'),
-    ("succ", '\u{00227B}'),
-    ("succapprox", '\u{002AB8}'),
-    ("succcurlyeq", '\u{00227D}'),
-    ("succeq", '\u{002AB0}'),
-    ("succnapprox", '\u{002ABA}'),
-    ("succneqq", '\u{002AB6}'),
-    ("succnsim", '\u{0022E9}'),
-    ("succsim", '\u{00227F}'),
-    ("sum", '\u{002211}'),
-    ("sung", '\u{00266A}'),
-    ("sup", '\u{002283}'),
-    ("sup1", '\u{0000B9}'),
-    ("sup2", '\u{0000B2}'),
-    ("sup3", '\u{0000B3}'),
-    ("supE", '\u{002AC6}'),
-    ("supdot", '\u{002ABE}'),
-    ("supdsub", '\u{002AD8}'),
-    ("supe", '\u{002287}'),
-    ("supedot", '\u{002AC4}'),
-    ("suphsol", '\u{0027C9}'),
-    ("suphsub", '\u{002AD7}'),
-    ("suplarr", '\u{00297B}'),
-    ("supmult", '\u{002AC2}'),
-    ("supnE", '\u{002ACC}'),
-    ("supne", '\u{00228B}'),
-    ("supplus", '\u{002AC0}'),
-    ("supset", '\u{002283}'),
-    ("supseteq", '\u{002287}'),
-    ("supseteqq", '\u{002AC6}'),
-    ("supsetneq", '\u{00228B}'),
-    ("supsetneqq", '\u{002ACC}'),
-    ("supsim", '\u{002AC8}'),
-    ("supsub", '\u{002AD4}'),
-    ("supsup", '\u{002AD6}'),
-    ("swArr", '\u{0021D9}'),
-    ("swarhk", '\u{002926}'),
-    ("swarr", '\u{002199}'),
-    ("swarrow", '\u{002199}'),
-    ("swnwar", '\u{00292A}'),
-    ("szlig", '\u{0000DF}'),
-    ("target", '\u{002316}'),
-    ("tau", '\u{0003C4}'),
-    ("tbrk", '\u{0023B4}'),
-    ("tcaron", '\u{000165}'),
-    ("tcedil", '\u{000163}'),
-    ("tcy", '\u{000442}'),
-    ("tdot", '\u{0020DB}'),
-    ("telrec", '\u{002315}'),
-    ("tfr", '\u{01D531}'),
-    ("there4", '\u{002234}'),
-    ("therefore", '\u{002234}'),
-    ("theta", '\u{0003B8}'),
-    ("thetasym", '\u{0003D1}'),
-    ("thetav", '\u{0003D1}'),
-    ("thickapprox", '\u{002248}'),
-    ("thicksim", '\u{00223C}'),
-    ("thinsp", '\u{002009}'),
-    ("thkap", '\u{002248}'),
-    ("thksim", '\u{00223C}'),
-    ("thorn", '\u{0000FE}'),
-    ("tilde", '\u{0002DC}'),
-    ("times", '\u{0000D7}'),
-    ("timesb", '\u{0022A0}'),
-    ("timesbar", '\u{002A31}'),
-    ("timesd", '\u{002A30}'),
-    ("tint", '\u{00222D}'),
-    ("toea", '\u{002928}'),
-    ("top", '\u{0022A4}'),
-    ("topbot", '\u{002336}'),
-    ("topcir", '\u{002AF1}'),
-    ("topf", '\u{01D565}'),
-    ("topfork", '\u{002ADA}'),
-    ("tosa", '\u{002929}'),
-    ("tprime", '\u{002034}'),
-    ("trade", '\u{002122}'),
-    ("triangle", '\u{0025B5}'),
-    ("triangledown", '\u{0025BF}'),
-    ("triangleleft", '\u{0025C3}'),
-    ("trianglelefteq", '\u{0022B4}'),
-    ("triangleq", '\u{00225C}'),
-    ("triangleright", '\u{0025B9}'),
-    ("trianglerighteq", '\u{0022B5}'),
-    ("tridot", '\u{0025EC}'),
-    ("trie", '\u{00225C}'),
-    ("triminus", '\u{002A3A}'),
-    ("triplus", '\u{002A39}'),
-    ("trisb", '\u{0029CD}'),
-    ("tritime", '\u{002A3B}'),
-    ("trpezium", '\u{0023E2}'),
-    ("tscr", '\u{01D4C9}'),
-    ("tscy", '\u{000446}'),
-    ("tshcy", '\u{00045B}'),
-    ("tstrok", '\u{000167}'),
-    ("twixt", '\u{00226C}'),
-    ("twoheadleftarrow", '\u{00219E}'),
-    ("twoheadrightarrow", '\u{0021A0}'),
-    ("uArr", '\u{0021D1}'),
-    ("uHar", '\u{002963}'),
-    ("uacute", '\u{0000FA}'),
-    ("uarr", '\u{002191}'),
-    ("ubrcy", '\u{00045E}'),
-    ("ubreve", '\u{00016D}'),
-    ("ucirc", '\u{0000FB}'),
-    ("ucy", '\u{000443}'),
-    ("udarr", '\u{0021C5}'),
-    ("udblac", '\u{000171}'),
-    ("udhar", '\u{00296E}'),
-    ("ufisht", '\u{00297E}'),
-    ("ufr", '\u{01D532}'),
-    ("ugrave", '\u{0000F9}'),
-    ("uharl", '\u{0021BF}'),
-    ("uharr", '\u{0021BE}'),
-    ("uhblk", '\u{002580}'),
-    ("ulcorn", '\u{00231C}'),
-    ("ulcorner", '\u{00231C}'),
-    ("ulcrop", '\u{00230F}'),
-    ("ultri", '\u{0025F8}'),
-    ("umacr", '\u{00016B}'),
-    ("uml", '\u{0000A8}'),
-    ("uogon", '\u{000173}'),
-    ("uopf", '\u{01D566}'),
-    ("uparrow", '\u{002191}'),
-    ("updownarrow", '\u{002195}'),
-    ("upharpoonleft", '\u{0021BF}'),
-    ("upharpoonright", '\u{0021BE}'),
-    ("uplus", '\u{00228E}'),
-    ("upsi", '\u{0003C5}'),
-    ("upsih", '\u{0003D2}'),
-    ("upsilon", '\u{0003C5}'),
-    ("upuparrows", '\u{0021C8}'),
-    ("urcorn", '\u{00231D}'),
-    ("urcorner", '\u{00231D}'),
-    ("urcrop", '\u{00230E}'),
-    ("uring", '\u{00016F}'),
-    ("urtri", '\u{0025F9}'),
-    ("uscr", '\u{01D4CA}'),
-    ("utdot", '\u{0022F0}'),
-    ("utilde", '\u{000169}'),
-    ("utri", '\u{0025B5}'),
-    ("utrif", '\u{0025B4}'),
-    ("uuarr", '\u{0021C8}'),
-    ("uuml", '\u{0000FC}'),
-    ("uwangle", '\u{0029A7}'),
-    ("vArr", '\u{0021D5}'),
-    ("vBar", '\u{002AE8}'),
-    ("vBarv", '\u{002AE9}'),
-    ("vDash", '\u{0022A8}'),
-    ("vangrt", '\u{00299C}'),
-    ("varepsilon", '\u{0003F5}'),
-    ("varkappa", '\u{0003F0}'),
-    ("varnothing", '\u{002205}'),
-    ("varphi", '\u{0003D5}'),
-    ("varpi", '\u{0003D6}'),
-    ("varpropto", '\u{00221D}'),
-    ("varr", '\u{002195}'),
-    ("varrho", '\u{0003F1}'),
-    ("varsigma", '\u{0003C2}'),
-    ("vartheta", '\u{0003D1}'),
-    ("vartriangleleft", '\u{0022B2}'),
-    ("vartriangleright", '\u{0022B3}'),
-    ("vcy", '\u{000432}'),
-    ("vdash", '\u{0022A2}'),
-    ("vee", '\u{002228}'),
-    ("veebar", '\u{0022BB}'),
-    ("veeeq", '\u{00225A}'),
-    ("vellip", '\u{0022EE}'),
-    ("verbar", '\u{00007C}'),
-    ("vert", '\u{00007C}'),
-    ("vfr", '\u{01D533}'),
-    ("vltri", '\u{0022B2}'),
-    ("vopf", '\u{01D567}'),
-    ("vprop", '\u{00221D}'),
-    ("vrtri", '\u{0022B3}'),
-    ("vscr", '\u{01D4CB}'),
-    ("vzigzag", '\u{00299A}'),
-    ("wcirc", '\u{000175}'),
-    ("wedbar", '\u{002A5F}'),
-    ("wedge", '\u{002227}'),
-    ("wedgeq", '\u{002259}'),
-    ("weierp", '\u{002118}'),
-    ("wfr", '\u{01D534}'),
-    ("wopf", '\u{01D568}'),
-    ("wp", '\u{002118}'),
-    ("wr", '\u{002240}'),
-    ("wreath", '\u{002240}'),
-    ("wscr", '\u{01D4CC}'),
-    ("xcap", '\u{0022C2}'),
-    ("xcirc", '\u{0025EF}'),
-    ("xcup", '\u{0022C3}'),
-    ("xdtri", '\u{0025BD}'),
-    ("xfr", '\u{01D535}'),
-    ("xhArr", '\u{0027FA}'),
-    ("xharr", '\u{0027F7}'),
-    ("xi", '\u{0003BE}'),
-    ("xlArr", '\u{0027F8}'),
-    ("xlarr", '\u{0027F5}'),
-    ("xmap", '\u{0027FC}'),
-    ("xnis", '\u{0022FB}'),
-    ("xodot", '\u{002A00}'),
-    ("xopf", '\u{01D569}'),
-    ("xoplus", '\u{002A01}'),
-    ("xotime", '\u{002A02}'),
-    ("xrArr", '\u{0027F9}'),
-    ("xrarr", '\u{0027F6}'),
-    ("xscr", '\u{01D4CD}'),
-    ("xsqcup", '\u{002A06}'),
-    ("xuplus", '\u{002A04}'),
-    ("xutri", '\u{0025B3}'),
-    ("xvee", '\u{0022C1}'),
-    ("xwedge", '\u{0022C0}'),
-    ("yacute", '\u{0000FD}'),
-    ("yacy", '\u{00044F}'),
-    ("ycirc", '\u{000177}'),
-    ("ycy", '\u{00044B}'),
-    ("yen", '\u{0000A5}'),
-    ("yfr", '\u{01D536}'),
-    ("yicy", '\u{000457}'),
-    ("yopf", '\u{01D56A}'),
-    ("yscr", '\u{01D4CE}'),
-    ("yucy", '\u{00044E}'),
-    ("yuml", '\u{0000FF}'),
-    ("zacute", '\u{00017A}'),
-    ("zcaron", '\u{00017E}'),
-    ("zcy", '\u{000437}'),
-    ("zdot", '\u{00017C}'),
-    ("zeetrf", '\u{002128}'),
-    ("zeta", '\u{0003B6}'),
-    ("zfr", '\u{01D537}'),
-    ("zhcy", '\u{000436}'),
-    ("zigrarr", '\u{0021DD}'),
-    ("zopf", '\u{01D56B}'),
-    ("zscr", '\u{01D4CF}'),
-    ("zwj", '\u{00200D}'),
-    ("zwnj", '\u{00200C}'),
+pub static ENTITIES: &[(&str, &str)] = &[
+    ("AElig", "\u{00C6}"),
+    ("AElig;", "\u{00C6}"),
+    ("AMP", "\u{0026}"),
+    ("AMP;", "\u{0026}"),
+    ("Aacute", "\u{00C1}"),
+    ("Aacute;", "\u{00C1}"),
+    ("Abreve;", "\u{0102}"),
+    ("Acirc", "\u{00C2}"),
+    ("Acirc;", "\u{00C2}"),
+    ("Acy;", "\u{0410}"),
+    ("Afr;", "\u{1D504}"),
+    ("Agrave", "\u{00C0}"),
+    ("Agrave;", "\u{00C0}"),
+    ("Alpha;", "\u{0391}"),
+    ("Amacr;", "\u{0100}"),
+    ("And;", "\u{2A53}"),
+    ("Aogon;", "\u{0104}"),
+    ("Aopf;", "\u{1D538}"),
+    ("ApplyFunction;", "\u{2061}"),
+    ("Aring", "\u{00C5}"),
+    ("Aring;", "\u{00C5}"),
+    ("Ascr;", "\u{1D49C}"),
+    ("Assign;", "\u{2254}"),
+    ("Atilde", "\u{00C3}"),
+    ("Atilde;", "\u{00C3}"),
+    ("Auml", "\u{00C4}"),
+    ("Auml;", "\u{00C4}"),
+    ("Backslash;", "\u{2216}"),
+    ("Barv;", "\u{2AE7}"),
+    ("Barwed;", "\u{2306}"),
+    ("Bcy;", "\u{0411}"),
+    ("Because;", "\u{2235}"),
+    ("Bernoullis;", "\u{212C}"),
+    ("Beta;", "\u{0392}"),
+    ("Bfr;", "\u{1D505}"),
+    ("Bopf;", "\u{1D539}"),
+    ("Breve;", "\u{02D8}"),
+    ("Bscr;", "\u{212C}"),
+    ("Bumpeq;", "\u{224E}"),
+    ("CHcy;", "\u{0427}"),
+    ("COPY", "\u{00A9}"),
+    ("COPY;", "\u{00A9}"),
+    ("Cacute;", "\u{0106}"),
+    ("Cap;", "\u{22D2}"),
+    ("CapitalDifferentialD;", "\u{2145}"),
+    ("Cayleys;", "\u{212D}"),
+    ("Ccaron;", "\u{010C}"),
+    ("Ccedil", "\u{00C7}"),
+    ("Ccedil;", "\u{00C7}"),
+    ("Ccirc;", "\u{0108}"),
+    ("Cconint;", "\u{2230}"),
+    ("Cdot;", "\u{010A}"),
+    ("Cedilla;", "\u{00B8}"),
+    ("CenterDot;", "\u{00B7}"),
+    ("Cfr;", "\u{212D}"),
+    ("Chi;", "\u{03A7}"),
+    ("CircleDot;", "\u{2299}"),
+    ("CircleMinus;", "\u{2296}"),
+    ("CirclePlus;", "\u{2295}"),
+    ("CircleTimes;", "\u{2297}"),
+    ("ClockwiseContourIntegral;", "\u{2232}"),
+    ("CloseCurlyDoubleQuote;", "\u{201D}"),
+    ("CloseCurlyQuote;", "\u{2019}"),
+    ("Colon;", "\u{2237}"),
+    ("Colone;", "\u{2A74}"),
+    ("Congruent;", "\u{2261}"),
+    ("Conint;", "\u{222F}"),
+    ("ContourIntegral;", "\u{222E}"),
+    ("Copf;", "\u{2102}"),
+    ("Coproduct;", "\u{2210}"),
+    ("CounterClockwiseContourIntegral;", "\u{2233}"),
+    ("Cross;", "\u{2A2F}"),
+    ("Cscr;", "\u{1D49E}"),
+    ("Cup;", "\u{22D3}"),
+    ("CupCap;", "\u{224D}"),
+    ("DD;", "\u{2145}"),
+    ("DDotrahd;", "\u{2911}"),
+    ("DJcy;", "\u{0402}"),
+    ("DScy;", "\u{0405}"),
+    ("DZcy;", "\u{040F}"),
+    ("Dagger;", "\u{2021}"),
+    ("Darr;", "\u{21A1}"),
+    ("Dashv;", "\u{2AE4}"),
+    ("Dcaron;", "\u{010E}"),
+    ("Dcy;", "\u{0414}"),
+    ("Del;", "\u{2207}"),
+    ("Delta;", "\u{0394}"),
+    ("Dfr;", "\u{1D507}"),
+    ("DiacriticalAcute;", "\u{00B4}"),
+    ("DiacriticalDot;", "\u{02D9}"),
+    ("DiacriticalDoubleAcute;", "\u{02DD}"),
+    ("DiacriticalGrave;", "\u{0060}"),
+    ("DiacriticalTilde;", "\u{02DC}"),
+    ("Diamond;", "\u{22C4}"),
+    ("DifferentialD;", "\u{2146}"),
+    ("Dopf;", "\u{1D53B}"),
+    ("Dot;", "\u{00A8}"),
+    ("DotDot;", "\u{20DC}"),
+    ("DotEqual;", "\u{2250}"),
+    ("DoubleContourIntegral;", "\u{222F}"),
+    ("DoubleDot;", "\u{00A8}"),
+    ("DoubleDownArrow;", "\u{21D3}"),
+    ("DoubleLeftArrow;", "\u{21D0}"),
+    ("DoubleLeftRightArrow;", "\u{21D4}"),
+    ("DoubleLeftTee;", "\u{2AE4}"),
+    ("DoubleLongLeftArrow;", "\u{27F8}"),
+    ("DoubleLongLeftRightArrow;", "\u{27FA}"),
+    ("DoubleLongRightArrow;", "\u{27F9}"),
+    ("DoubleRightArrow;", "\u{21D2}"),
+    ("DoubleRightTee;", "\u{22A8}"),
+    ("DoubleUpArrow;", "\u{21D1}"),
+    ("DoubleUpDownArrow;", "\u{21D5}"),
+    ("DoubleVerticalBar;", "\u{2225}"),
+    ("DownArrow;", "\u{2193}"),
+    ("DownArrowBar;", "\u{2913}"),
+    ("DownArrowUpArrow;", "\u{21F5}"),
+    ("DownBreve;", "\u{0311}"),
+    ("DownLeftRightVector;", "\u{2950}"),
+    ("DownLeftTeeVector;", "\u{295E}"),
+    ("DownLeftVector;", "\u{21BD}"),
+    ("DownLeftVectorBar;", "\u{2956}"),
+    ("DownRightTeeVector;", "\u{295F}"),
+    ("DownRightVector;", "\u{21C1}"),
+    ("DownRightVectorBar;", "\u{2957}"),
+    ("DownTee;", "\u{22A4}"),
+    ("DownTeeArrow;", "\u{21A7}"),
+    ("Downarrow;", "\u{21D3}"),
+    ("Dscr;", "\u{1D49F}"),
+    ("Dstrok;", "\u{0110}"),
+    ("ENG;", "\u{014A}"),
+    ("ETH", "\u{00D0}"),
+    ("ETH;", "\u{00D0}"),
+    ("Eacute", "\u{00C9}"),
+    ("Eacute;", "\u{00C9}"),
+    ("Ecaron;", "\u{011A}"),
+    ("Ecirc", "\u{00CA}"),
+    ("Ecirc;", "\u{00CA}"),
+    ("Ecy;", "\u{042D}"),
+    ("Edot;", "\u{0116}"),
+    ("Efr;", "\u{1D508}"),
+    ("Egrave", "\u{00C8}"),
+    ("Egrave;", "\u{00C8}"),
+    ("Element;", "\u{2208}"),
+    ("Emacr;", "\u{0112}"),
+    ("EmptySmallSquare;", "\u{25FB}"),
+    ("EmptyVerySmallSquare;", "\u{25AB}"),
+    ("Eogon;", "\u{0118}"),
+    ("Eopf;", "\u{1D53C}"),
+    ("Epsilon;", "\u{0395}"),
+    ("Equal;", "\u{2A75}"),
+    ("EqualTilde;", "\u{2242}"),
+    ("Equilibrium;", "\u{21CC}"),
+    ("Escr;", "\u{2130}"),
+    ("Esim;", "\u{2A73}"),
+    ("Eta;", "\u{0397}"),
+    ("Euml", "\u{00CB}"),
+    ("Euml;", "\u{00CB}"),
+    ("Exists;", "\u{2203}"),
+    ("ExponentialE;", "\u{2147}"),
+    ("Fcy;", "\u{0424}"),
+    ("Ffr;", "\u{1D509}"),
+    ("FilledSmallSquare;", "\u{25FC}"),
+    ("FilledVerySmallSquare;", "\u{25AA}"),
+    ("Fopf;", "\u{1D53D}"),
+    ("ForAll;", "\u{2200}"),
+    ("Fouriertrf;", "\u{2131}"),
+    ("Fscr;", "\u{2131}"),
+    ("GJcy;", "\u{0403}"),
+    ("GT", "\u{003E}"),
+    ("GT;", "\u{003E}"),
+    ("Gamma;", "\u{0393}"),
+    ("Gammad;", "\u{03DC}"),
+    ("Gbreve;", "\u{011E}"),
+    ("Gcedil;", "\u{0122}"),
+    ("Gcirc;", "\u{011C}"),
+    ("Gcy;", "\u{0413}"),
+    ("Gdot;", "\u{0120}"),
+    ("Gfr;", "\u{1D50A}"),
+    ("Gg;", "\u{22D9}"),
+    ("Gopf;", "\u{1D53E}"),
+    ("GreaterEqual;", "\u{2265}"),
+    ("GreaterEqualLess;", "\u{22DB}"),
+    ("GreaterFullEqual;", "\u{2267}"),
+    ("GreaterGreater;", "\u{2AA2}"),
+    ("GreaterLess;", "\u{2277}"),
+    ("GreaterSlantEqual;", "\u{2A7E}"),
+    ("GreaterTilde;", "\u{2273}"),
+    ("Gscr;", "\u{1D4A2}"),
+    ("Gt;", "\u{226B}"),
+    ("HARDcy;", "\u{042A}"),
+    ("Hacek;", "\u{02C7}"),
+    ("Hat;", "\u{005E}"),
+    ("Hcirc;", "\u{0124}"),
+    ("Hfr;", "\u{210C}"),
+    ("HilbertSpace;", "\u{210B}"),
+    ("Hopf;", "\u{210D}"),
+    ("HorizontalLine;", "\u{2500}"),
+    ("Hscr;", "\u{210B}"),
+    ("Hstrok;", "\u{0126}"),
+    ("HumpDownHump;", "\u{224E}"),
+    ("HumpEqual;", "\u{224F}"),
+    ("IEcy;", "\u{0415}"),
+    ("IJlig;", "\u{0132}"),
+    ("IOcy;", "\u{0401}"),
+    ("Iacute", "\u{00CD}"),
+    ("Iacute;", "\u{00CD}"),
+    ("Icirc", "\u{00CE}"),
+    ("Icirc;", "\u{00CE}"),
+    ("Icy;", "\u{0418}"),
+    ("Idot;", "\u{0130}"),
+    ("Ifr;", "\u{2111}"),
+    ("Igrave", "\u{00CC}"),
+    ("Igrave;", "\u{00CC}"),
+    ("Im;", "\u{2111}"),
+    ("Imacr;", "\u{012A}"),
+    ("ImaginaryI;", "\u{2148}"),
+    ("Implies;", "\u{21D2}"),
+    ("Int;", "\u{222C}"),
+    ("Integral;", "\u{222B}"),
+    ("Intersection;", "\u{22C2}"),
+    ("InvisibleComma;", "\u{2063}"),
+    ("InvisibleTimes;", "\u{2062}"),
+    ("Iogon;", "\u{012E}"),
+    ("Iopf;", "\u{1D540}"),
+    ("Iota;", "\u{0399}"),
+    ("Iscr;", "\u{2110}"),
+    ("Itilde;", "\u{0128}"),
+    ("Iukcy;", "\u{0406}"),
+    ("Iuml", "\u{00CF}"),
+    ("Iuml;", "\u{00CF}"),
+    ("Jcirc;", "\u{0134}"),
+    ("Jcy;", "\u{0419}"),
+    ("Jfr;", "\u{1D50D}"),
+    ("Jopf;", "\u{1D541}"),
+    ("Jscr;", "\u{1D4A5}"),
+    ("Jsercy;", "\u{0408}"),
+    ("Jukcy;", "\u{0404}"),
+    ("KHcy;", "\u{0425}"),
+    ("KJcy;", "\u{040C}"),
+    ("Kappa;", "\u{039A}"),
+    ("Kcedil;", "\u{0136}"),
+    ("Kcy;", "\u{041A}"),
+    ("Kfr;", "\u{1D50E}"),
+    ("Kopf;", "\u{1D542}"),
+    ("Kscr;", "\u{1D4A6}"),
+    ("LJcy;", "\u{0409}"),
+    ("LT", "\u{003C}"),
+    ("LT;", "\u{003C}"),
+    ("Lacute;", "\u{0139}"),
+    ("Lambda;", "\u{039B}"),
+    ("Lang;", "\u{27EA}"),
+    ("Laplacetrf;", "\u{2112}"),
+    ("Larr;", "\u{219E}"),
+    ("Lcaron;", "\u{013D}"),
+    ("Lcedil;", "\u{013B}"),
+    ("Lcy;", "\u{041B}"),
+    ("LeftAngleBracket;", "\u{27E8}"),
+    ("LeftArrow;", "\u{2190}"),
+    ("LeftArrowBar;", "\u{21E4}"),
+    ("LeftArrowRightArrow;", "\u{21C6}"),
+    ("LeftCeiling;", "\u{2308}"),
+    ("LeftDoubleBracket;", "\u{27E6}"),
+    ("LeftDownTeeVector;", "\u{2961}"),
+    ("LeftDownVector;", "\u{21C3}"),
+    ("LeftDownVectorBar;", "\u{2959}"),
+    ("LeftFloor;", "\u{230A}"),
+    ("LeftRightArrow;", "\u{2194}"),
+    ("LeftRightVector;", "\u{294E}"),
+    ("LeftTee;", "\u{22A3}"),
+    ("LeftTeeArrow;", "\u{21A4}"),
+    ("LeftTeeVector;", "\u{295A}"),
+    ("LeftTriangle;", "\u{22B2}"),
+    ("LeftTriangleBar;", "\u{29CF}"),
+    ("LeftTriangleEqual;", "\u{22B4}"),
+    ("LeftUpDownVector;", "\u{2951}"),
+    ("LeftUpTeeVector;", "\u{2960}"),
+    ("LeftUpVector;", "\u{21BF}"),
+    ("LeftUpVectorBar;", "\u{2958}"),
+    ("LeftVector;", "\u{21BC}"),
+    ("LeftVectorBar;", "\u{2952}"),
+    ("Leftarrow;", "\u{21D0}"),
+    ("Leftrightarrow;", "\u{21D4}"),
+    ("LessEqualGreater;", "\u{22DA}"),
+    ("LessFullEqual;", "\u{2266}"),
+    ("LessGreater;", "\u{2276}"),
+    ("LessLess;", "\u{2AA1}"),
+    ("LessSlantEqual;", "\u{2A7D}"),
+    ("LessTilde;", "\u{2272}"),
+    ("Lfr;", "\u{1D50F}"),
+    ("Ll;", "\u{22D8}"),
+    ("Lleftarrow;", "\u{21DA}"),
+    ("Lmidot;", "\u{013F}"),
+    ("LongLeftArrow;", "\u{27F5}"),
+    ("LongLeftRightArrow;", "\u{27F7}"),
+    ("LongRightArrow;", "\u{27F6}"),
+    ("Longleftarrow;", "\u{27F8}"),
+    ("Longleftrightarrow;", "\u{27FA}"),
+    ("Longrightarrow;", "\u{27F9}"),
+    ("Lopf;", "\u{1D543}"),
+    ("LowerLeftArrow;", "\u{2199}"),
+    ("LowerRightArrow;", "\u{2198}"),
+    ("Lscr;", "\u{2112}"),
+    ("Lsh;", "\u{21B0}"),
+    ("Lstrok;", "\u{0141}"),
+    ("Lt;", "\u{226A}"),
+    ("Map;", "\u{2905}"),
+    ("Mcy;", "\u{041C}"),
+    ("MediumSpace;", "\u{205F}"),
+    ("Mellintrf;", "\u{2133}"),
+    ("Mfr;", "\u{1D510}"),
+    ("MinusPlus;", "\u{2213}"),
+    ("Mopf;", "\u{1D544}"),
+    ("Mscr;", "\u{2133}"),
+    ("Mu;", "\u{039C}"),
+    ("NJcy;", "\u{040A}"),
+    ("Nacute;", "\u{0143}"),
+    ("Ncaron;", "\u{0147}"),
+    ("Ncedil;", "\u{0145}"),
+    ("Ncy;", "\u{041D}"),
+    ("NegativeMediumSpace;", "\u{200B}"),
+    ("NegativeThickSpace;", "\u{200B}"),
+    ("NegativeThinSpace;", "\u{200B}"),
+    ("NegativeVeryThinSpace;", "\u{200B}"),
+    ("NestedGreaterGreater;", "\u{226B}"),
+    ("NestedLessLess;", "\u{226A}"),
+    ("NewLine;", "\u{000A}"),
+    ("Nfr;", "\u{1D511}"),
+    ("NoBreak;", "\u{2060}"),
+    ("NonBreakingSpace;", "\u{00A0}"),
+    ("Nopf;", "\u{2115}"),
+    ("Not;", "\u{2AEC}"),
+    ("NotCongruent;", "\u{2262}"),
+    ("NotCupCap;", "\u{226D}"),
+    ("NotDoubleVerticalBar;", "\u{2226}"),
+    ("NotElement;", "\u{2209}"),
+    ("NotEqual;", "\u{2260}"),
+    ("NotEqualTilde;", "\u{2242}\u{0338}"),
+    ("NotExists;", "\u{2204}"),
+    ("NotGreater;", "\u{226F}"),
+    ("NotGreaterEqual;", "\u{2271}"),
+    ("NotGreaterFullEqual;", "\u{2267}\u{0338}"),
+    ("NotGreaterGreater;", "\u{226B}\u{0338}"),
+    ("NotGreaterLess;", "\u{2279}"),
+    ("NotGreaterSlantEqual;", "\u{2A7E}\u{0338}"),
+    ("NotGreaterTilde;", "\u{2275}"),
+    ("NotHumpDownHump;", "\u{224E}\u{0338}"),
+    ("NotHumpEqual;", "\u{224F}\u{0338}"),
+    ("NotLeftTriangle;", "\u{22EA}"),
+    ("NotLeftTriangleBar;", "\u{29CF}\u{0338}"),
+    ("NotLeftTriangleEqual;", "\u{22EC}"),
+    ("NotLess;", "\u{226E}"),
+    ("NotLessEqual;", "\u{2270}"),
+    ("NotLessGreater;", "\u{2278}"),
+    ("NotLessLess;", "\u{226A}\u{0338}"),
+    ("NotLessSlantEqual;", "\u{2A7D}\u{0338}"),
+    ("NotLessTilde;", "\u{2274}"),
+    ("NotNestedGreaterGreater;", "\u{2AA2}\u{0338}"),
+    ("NotNestedLessLess;", "\u{2AA1}\u{0338}"),
+    ("NotPrecedes;", "\u{2280}"),
+    ("NotPrecedesEqual;", "\u{2AAF}\u{0338}"),
+    ("NotPrecedesSlantEqual;", "\u{22E0}"),
+    ("NotReverseElement;", "\u{220C}"),
+    ("NotRightTriangle;", "\u{22EB}"),
+    ("NotRightTriangleBar;", "\u{29D0}\u{0338}"),
+    ("NotRightTriangleEqual;", "\u{22ED}"),
+    ("NotSquareSubset;", "\u{228F}\u{0338}"),
+    ("NotSquareSubsetEqual;", "\u{22E2}"),
+    ("NotSquareSuperset;", "\u{2290}\u{0338}"),
+    ("NotSquareSupersetEqual;", "\u{22E3}"),
+    ("NotSubset;", "\u{2282}\u{20D2}"),
+    ("NotSubsetEqual;", "\u{2288}"),
+    ("NotSucceeds;", "\u{2281}"),
+    ("NotSucceedsEqual;", "\u{2AB0}\u{0338}"),
+    ("NotSucceedsSlantEqual;", "\u{22E1}"),
+    ("NotSucceedsTilde;", "\u{227F}\u{0338}"),
+    ("NotSuperset;", "\u{2283}\u{20D2}"),
+    ("NotSupersetEqual;", "\u{2289}"),
+    ("NotTilde;", "\u{2241}"),
+    ("NotTildeEqual;", "\u{2244}"),
+    ("NotTildeFullEqual;", "\u{2247}"),
+    ("NotTildeTilde;", "\u{2249}"),
+    ("NotVerticalBar;", "\u{2224}"),
+    ("Nscr;", "\u{1D4A9}"),
+    ("Ntilde", "\u{00D1}"),
+    ("Ntilde;", "\u{00D1}"),
+    ("Nu;", "\u{039D}"),
+    ("OElig;", "\u{0152}"),
+    ("Oacute", "\u{00D3}"),
+    ("Oacute;", "\u{00D3}"),
+    ("Ocirc", "\u{00D4}"),
+    ("Ocirc;", "\u{00D4}"),
+    ("Ocy;", "\u{041E}"),
+    ("Odblac;", "\u{0150}"),
+    ("Ofr;", "\u{1D512}"),
+    ("Ograve", "\u{00D2}"),
+    ("Ograve;", "\u{00D2}"),
+    ("Omacr;", "\u{014C}"),
+    ("Omega;", "\u{03A9}"),
+    ("Omicron;", "\u{039F}"),
+    ("Oopf;", "\u{1D546}"),
+    ("OpenCurlyDoubleQuote;", "\u{201C}"),
+    ("OpenCurlyQuote;", "\u{2018}"),
+    ("Or;", "\u{2A54}"),
+    ("Oscr;", "\u{1D4AA}"),
+    ("Oslash", "\u{00D8}"),
+    ("Oslash;", "\u{00D8}"),
+    ("Otilde", "\u{00D5}"),
+    ("Otilde;", "\u{00D5}"),
+    ("Otimes;", "\u{2A37}"),
+    ("Ouml", "\u{00D6}"),
+    ("Ouml;", "\u{00D6}"),
+    ("OverBar;", "\u{203E}"),
+    ("OverBrace;", "\u{23DE}"),
+    ("OverBracket;", "\u{23B4}"),
+    ("OverParenthesis;", "\u{23DC}"),
+    ("PartialD;", "\u{2202}"),
+    ("Pcy;", "\u{041F}"),
+    ("Pfr;", "\u{1D513}"),
+    ("Phi;", "\u{03A6}"),
+    ("Pi;", "\u{03A0}"),
+    ("PlusMinus;", "\u{00B1}"),
+    ("Poincareplane;", "\u{210C}"),
+    ("Popf;", "\u{2119}"),
+    ("Pr;", "\u{2ABB}"),
+    ("Precedes;", "\u{227A}"),
+    ("PrecedesEqual;", "\u{2AAF}"),
+    ("PrecedesSlantEqual;", "\u{227C}"),
+    ("PrecedesTilde;", "\u{227E}"),
+    ("Prime;", "\u{2033}"),
+    ("Product;", "\u{220F}"),
+    ("Proportion;", "\u{2237}"),
+    ("Proportional;", "\u{221D}"),
+    ("Pscr;", "\u{1D4AB}"),
+    ("Psi;", "\u{03A8}"),
+    ("QUOT", "\u{0022}"),
+    ("QUOT;", "\u{0022}"),
+    ("Qfr;", "\u{1D514}"),
+    ("Qopf;", "\u{211A}"),
+    ("Qscr;", "\u{1D4AC}"),
+    ("RBarr;", "\u{2910}"),
+    ("REG", "\u{00AE}"),
+    ("REG;", "\u{00AE}"),
+    ("Racute;", "\u{0154}"),
+    ("Rang;", "\u{27EB}"),
+    ("Rarr;", "\u{21A0}"),
+    ("Rarrtl;", "\u{2916}"),
+    ("Rcaron;", "\u{0158}"),
+    ("Rcedil;", "\u{0156}"),
+    ("Rcy;", "\u{0420}"),
+    ("Re;", "\u{211C}"),
+    ("ReverseElement;", "\u{220B}"),
+    ("ReverseEquilibrium;", "\u{21CB}"),
+    ("ReverseUpEquilibrium;", "\u{296F}"),
+    ("Rfr;", "\u{211C}"),
+    ("Rho;", "\u{03A1}"),
+    ("RightAngleBracket;", "\u{27E9}"),
+    ("RightArrow;", "\u{2192}"),
+    ("RightArrowBar;", "\u{21E5}"),
+    ("RightArrowLeftArrow;", "\u{21C4}"),
+    ("RightCeiling;", "\u{2309}"),
+    ("RightDoubleBracket;", "\u{27E7}"),
+    ("RightDownTeeVector;", "\u{295D}"),
+    ("RightDownVector;", "\u{21C2}"),
+    ("RightDownVectorBar;", "\u{2955}"),
+    ("RightFloor;", "\u{230B}"),
+    ("RightTee;", "\u{22A2}"),
+    ("RightTeeArrow;", "\u{21A6}"),
+    ("RightTeeVector;", "\u{295B}"),
+    ("RightTriangle;", "\u{22B3}"),
+    ("RightTriangleBar;", "\u{29D0}"),
+    ("RightTriangleEqual;", "\u{22B5}"),
+    ("RightUpDownVector;", "\u{294F}"),
+    ("RightUpTeeVector;", "\u{295C}"),
+    ("RightUpVector;", "\u{21BE}"),
+    ("RightUpVectorBar;", "\u{2954}"),
+    ("RightVector;", "\u{21C0}"),
+    ("RightVectorBar;", "\u{2953}"),
+    ("Rightarrow;", "\u{21D2}"),
+    ("Ropf;", "\u{211D}"),
+    ("RoundImplies;", "\u{2970}"),
+    ("Rrightarrow;", "\u{21DB}"),
+    ("Rscr;", "\u{211B}"),
+    ("Rsh;", "\u{21B1}"),
+    ("RuleDelayed;", "\u{29F4}"),
+    ("SHCHcy;", "\u{0429}"),
+    ("SHcy;", "\u{0428}"),
+    ("SOFTcy;", "\u{042C}"),
+    ("Sacute;", "\u{015A}"),
+    ("Sc;", "\u{2ABC}"),
+    ("Scaron;", "\u{0160}"),
+    ("Scedil;", "\u{015E}"),
+    ("Scirc;", "\u{015C}"),
+    ("Scy;", "\u{0421}"),
+    ("Sfr;", "\u{1D516}"),
+    ("ShortDownArrow;", "\u{2193}"),
+    ("ShortLeftArrow;", "\u{2190}"),
+    ("ShortRightArrow;", "\u{2192}"),
+    ("ShortUpArrow;", "\u{2191}"),
+    ("Sigma;", "\u{03A3}"),
+    ("SmallCircle;", "\u{2218}"),
+    ("Sopf;", "\u{1D54A}"),
+    ("Sqrt;", "\u{221A}"),
+    ("Square;", "\u{25A1}"),
+    ("SquareIntersection;", "\u{2293}"),
+    ("SquareSubset;", "\u{228F}"),
+    ("SquareSubsetEqual;", "\u{2291}"),
+    ("SquareSuperset;", "\u{2290}"),
+    ("SquareSupersetEqual;", "\u{2292}"),
+    ("SquareUnion;", "\u{2294}"),
+    ("Sscr;", "\u{1D4AE}"),
+    ("Star;", "\u{22C6}"),
+    ("Sub;", "\u{22D0}"),
+    ("Subset;", "\u{22D0}"),
+    ("SubsetEqual;", "\u{2286}"),
+    ("Succeeds;", "\u{227B}"),
+    ("SucceedsEqual;", "\u{2AB0}"),
+    ("SucceedsSlantEqual;", "\u{227D}"),
+    ("SucceedsTilde;", "\u{227F}"),
+    ("SuchThat;", "\u{220B}"),
+    ("Sum;", "\u{2211}"),
+    ("Sup;", "\u{22D1}"),
+    ("Superset;", "\u{2283}"),
+    ("SupersetEqual;", "\u{2287}"),
+    ("Supset;", "\u{22D1}"),
+    ("THORN", "\u{00DE}"),
+    ("THORN;", "\u{00DE}"),
+    ("TRADE;", "\u{2122}"),
+    ("TSHcy;", "\u{040B}"),
+    ("TScy;", "\u{0426}"),
+    ("Tab;", "\u{0009}"),
+    ("Tau;", "\u{03A4}"),
+    ("Tcaron;", "\u{0164}"),
+    ("Tcedil;", "\u{0162}"),
+    ("Tcy;", "\u{0422}"),
+    ("Tfr;", "\u{1D517}"),
+    ("Therefore;", "\u{2234}"),
+    ("Theta;", "\u{0398}"),
+    ("ThickSpace;", "\u{205F}\u{200A}"),
+    ("ThinSpace;", "\u{2009}"),
+    ("Tilde;", "\u{223C}"),
+    ("TildeEqual;", "\u{2243}"),
+    ("TildeFullEqual;", "\u{2245}"),
+    ("TildeTilde;", "\u{2248}"),
+    ("Topf;", "\u{1D54B}"),
+    ("TripleDot;", "\u{20DB}"),
+    ("Tscr;", "\u{1D4AF}"),
+    ("Tstrok;", "\u{0166}"),
+    ("Uacute", "\u{00DA}"),
+    ("Uacute;", "\u{00DA}"),
+    ("Uarr;", "\u{219F}"),
+    ("Uarrocir;", "\u{2949}"),
+    ("Ubrcy;", "\u{040E}"),
+    ("Ubreve;", "\u{016C}"),
+    ("Ucirc", "\u{00DB}"),
+    ("Ucirc;", "\u{00DB}"),
+    ("Ucy;", "\u{0423}"),
+    ("Udblac;", "\u{0170}"),
+    ("Ufr;", "\u{1D518}"),
+    ("Ugrave", "\u{00D9}"),
+    ("Ugrave;", "\u{00D9}"),
+    ("Umacr;", "\u{016A}"),
+    ("UnderBar;", "\u{005F}"),
+    ("UnderBrace;", "\u{23DF}"),
+    ("UnderBracket;", "\u{23B5}"),
+    ("UnderParenthesis;", "\u{23DD}"),
+    ("Union;", "\u{22C3}"),
+    ("UnionPlus;", "\u{228E}"),
+    ("Uogon;", "\u{0172}"),
+    ("Uopf;", "\u{1D54C}"),
+    ("UpArrow;", "\u{2191}"),
+    ("UpArrowBar;", "\u{2912}"),
+    ("UpArrowDownArrow;", "\u{21C5}"),
+    ("UpDownArrow;", "\u{2195}"),
+    ("UpEquilibrium;", "\u{296E}"),
+    ("UpTee;", "\u{22A5}"),
+    ("UpTeeArrow;", "\u{21A5}"),
+    ("Uparrow;", "\u{21D1}"),
+    ("Updownarrow;", "\u{21D5}"),
+    ("UpperLeftArrow;", "\u{2196}"),
+    ("UpperRightArrow;", "\u{2197}"),
+    ("Upsi;", "\u{03D2}"),
+    ("Upsilon;", "\u{03A5}"),
+    ("Uring;", "\u{016E}"),
+    ("Uscr;", "\u{1D4B0}"),
+    ("Utilde;", "\u{0168}"),
+    ("Uuml", "\u{00DC}"),
+    ("Uuml;", "\u{00DC}"),
+    ("VDash;", "\u{22AB}"),
+    ("Vbar;", "\u{2AEB}"),
+    ("Vcy;", "\u{0412}"),
+    ("Vdash;", "\u{22A9}"),
+    ("Vdashl;", "\u{2AE6}"),
+    ("Vee;", "\u{22C1}"),
+    ("Verbar;", "\u{2016}"),
+    ("Vert;", "\u{2016}"),
+    ("VerticalBar;", "\u{2223}"),
+    ("VerticalLine;", "\u{007C}"),
+    ("VerticalSeparator;", "\u{2758}"),
+    ("VerticalTilde;", "\u{2240}"),
+    ("VeryThinSpace;", "\u{200A}"),
+    ("Vfr;", "\u{1D519}"),
+    ("Vopf;", "\u{1D54D}"),
+    ("Vscr;", "\u{1D4B1}"),
+    ("Vvdash;", "\u{22AA}"),
+    ("Wcirc;", "\u{0174}"),
+    ("Wedge;", "\u{22C0}"),
+    ("Wfr;", "\u{1D51A}"),
+    ("Wopf;", "\u{1D54E}"),
+    ("Wscr;", "\u{1D4B2}"),
+    ("Xfr;", "\u{1D51B}"),
+    ("Xi;", "\u{039E}"),
+    ("Xopf;", "\u{1D54F}"),
+    ("Xscr;", "\u{1D4B3}"),
+    ("YAcy;", "\u{042F}"),
+    ("YIcy;", "\u{0407}"),
+    ("YUcy;", "\u{042E}"),
+    ("Yacute", "\u{00DD}"),
+    ("Yacute;", "\u{00DD}"),
+    ("Ycirc;", "\u{0176}"),
+    ("Ycy;", "\u{042B}"),
+    ("Yfr;", "\u{1D51C}"),
+    ("Yopf;", "\u{1D550}"),
+    ("Yscr;", "\u{1D4B4}"),
+    ("Yuml;", "\u{0178}"),
+    ("ZHcy;", "\u{0416}"),
+    ("Zacute;", "\u{0179}"),
+    ("Zcaron;", "\u{017D}"),
+    ("Zcy;", "\u{0417}"),
+    ("Zdot;", "\u{017B}"),
+    ("ZeroWidthSpace;", "\u{200B}"),
+    ("Zeta;", "\u{0396}"),
+    ("Zfr;", "\u{2128}"),
+    ("Zopf;", "\u{2124}"),
+    ("Zscr;", "\u{1D4B5}"),
+    ("aacute", "\u{00E1}"),
+    ("aacute;", "\u{00E1}"),
+    ("abreve;", "\u{0103}"),
+    ("ac;", "\u{223E}"),
+    ("acE;", "\u{223E}\u{0333}"),
+    ("acd;", "\u{223F}"),
+    ("acirc", "\u{00E2}"),
+    ("acirc;", "\u{00E2}"),
+    ("acute", "\u{00B4}"),
+    ("acute;", "\u{00B4}"),
+    ("acy;", "\u{0430}"),
+    ("aelig", "\u{00E6}"),
+    ("aelig;", "\u{00E6}"),
+    ("af;", "\u{2061}"),
+    ("afr;", "\u{1D51E}"),
+    ("agrave", "\u{00E0}"),
+    ("agrave;", "\u{00E0}"),
+    ("alefsym;", "\u{2135}"),
+    ("aleph;", "\u{2135}"),
+    ("alpha;", "\u{03B1}"),
+    ("amacr;", "\u{0101}"),
+    ("amalg;", "\u{2A3F}"),
+    ("amp", "\u{0026}"),
+    ("amp;", "\u{0026}"),
+    ("and;", "\u{2227}"),
+    ("andand;", "\u{2A55}"),
+    ("andd;", "\u{2A5C}"),
+    ("andslope;", "\u{2A58}"),
+    ("andv;", "\u{2A5A}"),
+    ("ang;", "\u{2220}"),
+    ("ange;", "\u{29A4}"),
+    ("angle;", "\u{2220}"),
+    ("angmsd;", "\u{2221}"),
+    ("angmsdaa;", "\u{29A8}"),
+    ("angmsdab;", "\u{29A9}"),
+    ("angmsdac;", "\u{29AA}"),
+    ("angmsdad;", "\u{29AB}"),
+    ("angmsdae;", "\u{29AC}"),
+    ("angmsdaf;", "\u{29AD}"),
+    ("angmsdag;", "\u{29AE}"),
+    ("angmsdah;", "\u{29AF}"),
+    ("angrt;", "\u{221F}"),
+    ("angrtvb;", "\u{22BE}"),
+    ("angrtvbd;", "\u{299D}"),
+    ("angsph;", "\u{2222}"),
+    ("angst;", "\u{00C5}"),
+    ("angzarr;", "\u{237C}"),
+    ("aogon;", "\u{0105}"),
+    ("aopf;", "\u{1D552}"),
+    ("ap;", "\u{2248}"),
+    ("apE;", "\u{2A70}"),
+    ("apacir;", "\u{2A6F}"),
+    ("ape;", "\u{224A}"),
+    ("apid;", "\u{224B}"),
+    ("apos;", "\u{0027}"),
+    ("approx;", "\u{2248}"),
+    ("approxeq;", "\u{224A}"),
+    ("aring", "\u{00E5}"),
+    ("aring;", "\u{00E5}"),
+    ("ascr;", "\u{1D4B6}"),
+    ("ast;", "\u{002A}"),
+    ("asymp;", "\u{2248}"),
+    ("asympeq;", "\u{224D}"),
+    ("atilde", "\u{00E3}"),
+    ("atilde;", "\u{00E3}"),
+    ("auml", "\u{00E4}"),
+    ("auml;", "\u{00E4}"),
+    ("awconint;", "\u{2233}"),
+    ("awint;", "\u{2A11}"),
+    ("bNot;", "\u{2AED}"),
+    ("backcong;", "\u{224C}"),
+    ("backepsilon;", "\u{03F6}"),
+    ("backprime;", "\u{2035}"),
+    ("backsim;", "\u{223D}"),
+    ("backsimeq;", "\u{22CD}"),
+    ("barvee;", "\u{22BD}"),
+    ("barwed;", "\u{2305}"),
+    ("barwedge;", "\u{2305}"),
+    ("bbrk;", "\u{23B5}"),
+    ("bbrktbrk;", "\u{23B6}"),
+    ("bcong;", "\u{224C}"),
+    ("bcy;", "\u{0431}"),
+    ("bdquo;", "\u{201E}"),
+    ("becaus;", "\u{2235}"),
+    ("because;", "\u{2235}"),
+    ("bemptyv;", "\u{29B0}"),
+    ("bepsi;", "\u{03F6}"),
+    ("bernou;", "\u{212C}"),
+    ("beta;", "\u{03B2}"),
+    ("beth;", "\u{2136}"),
+    ("between;", "\u{226C}"),
+    ("bfr;", "\u{1D51F}"),
+    ("bigcap;", "\u{22C2}"),
+    ("bigcirc;", "\u{25EF}"),
+    ("bigcup;", "\u{22C3}"),
+    ("bigodot;", "\u{2A00}"),
+    ("bigoplus;", "\u{2A01}"),
+    ("bigotimes;", "\u{2A02}"),
+    ("bigsqcup;", "\u{2A06}"),
+    ("bigstar;", "\u{2605}"),
+    ("bigtriangledown;", "\u{25BD}"),
+    ("bigtriangleup;", "\u{25B3}"),
+    ("biguplus;", "\u{2A04}"),
+    ("bigvee;", "\u{22C1}"),
+    ("bigwedge;", "\u{22C0}"),
+    ("bkarow;", "\u{290D}"),
+    ("blacklozenge;", "\u{29EB}"),
+    ("blacksquare;", "\u{25AA}"),
+    ("blacktriangle;", "\u{25B4}"),
+    ("blacktriangledown;", "\u{25BE}"),
+    ("blacktriangleleft;", "\u{25C2}"),
+    ("blacktriangleright;", "\u{25B8}"),
+    ("blank;", "\u{2423}"),
+    ("blk12;", "\u{2592}"),
+    ("blk14;", "\u{2591}"),
+    ("blk34;", "\u{2593}"),
+    ("block;", "\u{2588}"),
+    ("bne;", "\u{003D}\u{20E5}"),
+    ("bnequiv;", "\u{2261}\u{20E5}"),
+    ("bnot;", "\u{2310}"),
+    ("bopf;", "\u{1D553}"),
+    ("bot;", "\u{22A5}"),
+    ("bottom;", "\u{22A5}"),
+    ("bowtie;", "\u{22C8}"),
+    ("boxDL;", "\u{2557}"),
+    ("boxDR;", "\u{2554}"),
+    ("boxDl;", "\u{2556}"),
+    ("boxDr;", "\u{2553}"),
+    ("boxH;", "\u{2550}"),
+    ("boxHD;", "\u{2566}"),
+    ("boxHU;", "\u{2569}"),
+    ("boxHd;", "\u{2564}"),
+    ("boxHu;", "\u{2567}"),
+    ("boxUL;", "\u{255D}"),
+    ("boxUR;", "\u{255A}"),
+    ("boxUl;", "\u{255C}"),
+    ("boxUr;", "\u{2559}"),
+    ("boxV;", "\u{2551}"),
+    ("boxVH;", "\u{256C}"),
+    ("boxVL;", "\u{2563}"),
+    ("boxVR;", "\u{2560}"),
+    ("boxVh;", "\u{256B}"),
+    ("boxVl;", "\u{2562}"),
+    ("boxVr;", "\u{255F}"),
+    ("boxbox;", "\u{29C9}"),
+    ("boxdL;", "\u{2555}"),
+    ("boxdR;", "\u{2552}"),
+    ("boxdl;", "\u{2510}"),
+    ("boxdr;", "\u{250C}"),
+    ("boxh;", "\u{2500}"),
+    ("boxhD;", "\u{2565}"),
+    ("boxhU;", "\u{2568}"),
+    ("boxhd;", "\u{252C}"),
+    ("boxhu;", "\u{2534}"),
+    ("boxminus;", "\u{229F}"),
+    ("boxplus;", "\u{229E}"),
+    ("boxtimes;", "\u{22A0}"),
+    ("boxuL;", "\u{255B}"),
+    ("boxuR;", "\u{2558}"),
+    ("boxul;", "\u{2518}"),
+    ("boxur;", "\u{2514}"),
+    ("boxv;", "\u{2502}"),
+    ("boxvH;", "\u{256A}"),
+    ("boxvL;", "\u{2561}"),
+    ("boxvR;", "\u{255E}"),
+    ("boxvh;", "\u{253C}"),
+    ("boxvl;", "\u{2524}"),
+    ("boxvr;", "\u{251C}"),
+    ("bprime;", "\u{2035}"),
+    ("breve;", "\u{02D8}"),
+    ("brvbar", "\u{00A6}"),
+    ("brvbar;", "\u{00A6}"),
+    ("bscr;", "\u{1D4B7}"),
+    ("bsemi;", "\u{204F}"),
+    ("bsim;", "\u{223D}"),
+    ("bsime;", "\u{22CD}"),
+    ("bsol;", "\u{005C}"),
+    ("bsolb;", "\u{29C5}"),
+    ("bsolhsub;", "\u{27C8}"),
+    ("bull;", "\u{2022}"),
+    ("bullet;", "\u{2022}"),
+    ("bump;", "\u{224E}"),
+    ("bumpE;", "\u{2AAE}"),
+    ("bumpe;", "\u{224F}"),
+    ("bumpeq;", "\u{224F}"),
+    ("cacute;", "\u{0107}"),
+    ("cap;", "\u{2229}"),
+    ("capand;", "\u{2A44}"),
+    ("capbrcup;", "\u{2A49}"),
+    ("capcap;", "\u{2A4B}"),
+    ("capcup;", "\u{2A47}"),
+    ("capdot;", "\u{2A40}"),
+    ("caps;", "\u{2229}\u{FE00}"),
+    ("caret;", "\u{2041}"),
+    ("caron;", "\u{02C7}"),
+    ("ccaps;", "\u{2A4D}"),
+    ("ccaron;", "\u{010D}"),
+    ("ccedil", "\u{00E7}"),
+    ("ccedil;", "\u{00E7}"),
+    ("ccirc;", "\u{0109}"),
+    ("ccups;", "\u{2A4C}"),
+    ("ccupssm;", "\u{2A50}"),
+    ("cdot;", "\u{010B}"),
+    ("cedil", "\u{00B8}"),
+    ("cedil;", "\u{00B8}"),
+    ("cemptyv;", "\u{29B2}"),
+    ("cent", "\u{00A2}"),
+    ("cent;", "\u{00A2}"),
+    ("centerdot;", "\u{00B7}"),
+    ("cfr;", "\u{1D520}"),
+    ("chcy;", "\u{0447}"),
+    ("check;", "\u{2713}"),
+    ("checkmark;", "\u{2713}"),
+    ("chi;", "\u{03C7}"),
+    ("cir;", "\u{25CB}"),
+    ("cirE;", "\u{29C3}"),
+    ("circ;", "\u{02C6}"),
+    ("circeq;", "\u{2257}"),
+    ("circlearrowleft;", "\u{21BA}"),
+    ("circlearrowright;", "\u{21BB}"),
+    ("circledR;", "\u{00AE}"),
+    ("circledS;", "\u{24C8}"),
+    ("circledast;", "\u{229B}"),
+    ("circledcirc;", "\u{229A}"),
+    ("circleddash;", "\u{229D}"),
+    ("cire;", "\u{2257}"),
+    ("cirfnint;", "\u{2A10}"),
+    ("cirmid;", "\u{2AEF}"),
+    ("cirscir;", "\u{29C2}"),
+    ("clubs;", "\u{2663}"),
+    ("clubsuit;", "\u{2663}"),
+    ("colon;", "\u{003A}"),
+    ("colone;", "\u{2254}"),
+    ("coloneq;", "\u{2254}"),
+    ("comma;", "\u{002C}"),
+    ("commat;", "\u{0040}"),
+    ("comp;", "\u{2201}"),
+    ("compfn;", "\u{2218}"),
+    ("complement;", "\u{2201}"),
+    ("complexes;", "\u{2102}"),
+    ("cong;", "\u{2245}"),
+    ("congdot;", "\u{2A6D}"),
+    ("conint;", "\u{222E}"),
+    ("copf;", "\u{1D554}"),
+    ("coprod;", "\u{2210}"),
+    ("copy", "\u{00A9}"),
+    ("copy;", "\u{00A9}"),
+    ("copysr;", "\u{2117}"),
+    ("crarr;", "\u{21B5}"),
+    ("cross;", "\u{2717}"),
+    ("cscr;", "\u{1D4B8}"),
+    ("csub;", "\u{2ACF}"),
+    ("csube;", "\u{2AD1}"),
+    ("csup;", "\u{2AD0}"),
+    ("csupe;", "\u{2AD2}"),
+    ("ctdot;", "\u{22EF}"),
+    ("cudarrl;", "\u{2938}"),
+    ("cudarrr;", "\u{2935}"),
+    ("cuepr;", "\u{22DE}"),
+    ("cuesc;", "\u{22DF}"),
+    ("cularr;", "\u{21B6}"),
+    ("cularrp;", "\u{293D}"),
+    ("cup;", "\u{222A}"),
+    ("cupbrcap;", "\u{2A48}"),
+    ("cupcap;", "\u{2A46}"),
+    ("cupcup;", "\u{2A4A}"),
+    ("cupdot;", "\u{228D}"),
+    ("cupor;", "\u{2A45}"),
+    ("cups;", "\u{222A}\u{FE00}"),
+    ("curarr;", "\u{21B7}"),
+    ("curarrm;", "\u{293C}"),
+    ("curlyeqprec;", "\u{22DE}"),
+    ("curlyeqsucc;", "\u{22DF}"),
+    ("curlyvee;", "\u{22CE}"),
+    ("curlywedge;", "\u{22CF}"),
+    ("curren", "\u{00A4}"),
+    ("curren;", "\u{00A4}"),
+    ("curvearrowleft;", "\u{21B6}"),
+    ("curvearrowright;", "\u{21B7}"),
+    ("cuvee;", "\u{22CE}"),
+    ("cuwed;", "\u{22CF}"),
+    ("cwconint;", "\u{2232}"),
+    ("cwint;", "\u{2231}"),
+    ("cylcty;", "\u{232D}"),
+    ("dArr;", "\u{21D3}"),
+    ("dHar;", "\u{2965}"),
+    ("dagger;", "\u{2020}"),
+    ("daleth;", "\u{2138}"),
+    ("darr;", "\u{2193}"),
+    ("dash;", "\u{2010}"),
+    ("dashv;", "\u{22A3}"),
+    ("dbkarow;", "\u{290F}"),
+    ("dblac;", "\u{02DD}"),
+    ("dcaron;", "\u{010F}"),
+    ("dcy;", "\u{0434}"),
+    ("dd;", "\u{2146}"),
+    ("ddagger;", "\u{2021}"),
+    ("ddarr;", "\u{21CA}"),
+    ("ddotseq;", "\u{2A77}"),
+    ("deg", "\u{00B0}"),
+    ("deg;", "\u{00B0}"),
+    ("delta;", "\u{03B4}"),
+    ("demptyv;", "\u{29B1}"),
+    ("dfisht;", "\u{297F}"),
+    ("dfr;", "\u{1D521}"),
+    ("dharl;", "\u{21C3}"),
+    ("dharr;", "\u{21C2}"),
+    ("diam;", "\u{22C4}"),
+    ("diamond;", "\u{22C4}"),
+    ("diamondsuit;", "\u{2666}"),
+    ("diams;", "\u{2666}"),
+    ("die;", "\u{00A8}"),
+    ("digamma;", "\u{03DD}"),
+    ("disin;", "\u{22F2}"),
+    ("div;", "\u{00F7}"),
+    ("divide", "\u{00F7}"),
+    ("divide;", "\u{00F7}"),
+    ("divideontimes;", "\u{22C7}"),
+    ("divonx;", "\u{22C7}"),
+    ("djcy;", "\u{0452}"),
+    ("dlcorn;", "\u{231E}"),
+    ("dlcrop;", "\u{230D}"),
+    ("dollar;", "\u{0024}"),
+    ("dopf;", "\u{1D555}"),
+    ("dot;", "\u{02D9}"),
+    ("doteq;", "\u{2250}"),
+    ("doteqdot;", "\u{2251}"),
+    ("dotminus;", "\u{2238}"),
+    ("dotplus;", "\u{2214}"),
+    ("dotsquare;", "\u{22A1}"),
+    ("doublebarwedge;", "\u{2306}"),
+    ("downarrow;", "\u{2193}"),
+    ("downdownarrows;", "\u{21CA}"),
+    ("downharpoonleft;", "\u{21C3}"),
+    ("downharpoonright;", "\u{21C2}"),
+    ("drbkarow;", "\u{2910}"),
+    ("drcorn;", "\u{231F}"),
+    ("drcrop;", "\u{230C}"),
+    ("dscr;", "\u{1D4B9}"),
+    ("dscy;", "\u{0455}"),
+    ("dsol;", "\u{29F6}"),
+    ("dstrok;", "\u{0111}"),
+    ("dtdot;", "\u{22F1}"),
+    ("dtri;", "\u{25BF}"),
+    ("dtrif;", "\u{25BE}"),
+    ("duarr;", "\u{21F5}"),
+    ("duhar;", "\u{296F}"),
+    ("dwangle;", "\u{29A6}"),
+    ("dzcy;", "\u{045F}"),
+    ("dzigrarr;", "\u{27FF}"),
+    ("eDDot;", "\u{2A77}"),
+    ("eDot;", "\u{2251}"),
+    ("eacute", "\u{00E9}"),
+    ("eacute;", "\u{00E9}"),
+    ("easter;", "\u{2A6E}"),
+    ("ecaron;", "\u{011B}"),
+    ("ecir;", "\u{2256}"),
+    ("ecirc", "\u{00EA}"),
+    ("ecirc;", "\u{00EA}"),
+    ("ecolon;", "\u{2255}"),
+    ("ecy;", "\u{044D}"),
+    ("edot;", "\u{0117}"),
+    ("ee;", "\u{2147}"),
+    ("efDot;", "\u{2252}"),
+    ("efr;", "\u{1D522}"),
+    ("eg;", "\u{2A9A}"),
+    ("egrave", "\u{00E8}"),
+    ("egrave;", "\u{00E8}"),
+    ("egs;", "\u{2A96}"),
+    ("egsdot;", "\u{2A98}"),
+    ("el;", "\u{2A99}"),
+    ("elinters;", "\u{23E7}"),
+    ("ell;", "\u{2113}"),
+    ("els;", "\u{2A95}"),
+    ("elsdot;", "\u{2A97}"),
+    ("emacr;", "\u{0113}"),
+    ("empty;", "\u{2205}"),
+    ("emptyset;", "\u{2205}"),
+    ("emptyv;", "\u{2205}"),
+    ("emsp13;", "\u{2004}"),
+    ("emsp14;", "\u{2005}"),
+    ("emsp;", "\u{2003}"),
+    ("eng;", "\u{014B}"),
+    ("ensp;", "\u{2002}"),
+    ("eogon;", "\u{0119}"),
+    ("eopf;", "\u{1D556}"),
+    ("epar;", "\u{22D5}"),
+    ("eparsl;", "\u{29E3}"),
+    ("eplus;", "\u{2A71}"),
+    ("epsi;", "\u{03B5}"),
+    ("epsilon;", "\u{03B5}"),
+    ("epsiv;", "\u{03F5}"),
+    ("eqcirc;", "\u{2256}"),
+    ("eqcolon;", "\u{2255}"),
+    ("eqsim;", "\u{2242}"),
+    ("eqslantgtr;", "\u{2A96}"),
+    ("eqslantless;", "\u{2A95}"),
+    ("equals;", "\u{003D}"),
+    ("equest;", "\u{225F}"),
+    ("equiv;", "\u{2261}"),
+    ("equivDD;", "\u{2A78}"),
+    ("eqvparsl;", "\u{29E5}"),
+    ("erDot;", "\u{2253}"),
+    ("erarr;", "\u{2971}"),
+    ("escr;", "\u{212F}"),
+    ("esdot;", "\u{2250}"),
+    ("esim;", "\u{2242}"),
+    ("eta;", "\u{03B7}"),
+    ("eth", "\u{00F0}"),
+    ("eth;", "\u{00F0}"),
+    ("euml", "\u{00EB}"),
+    ("euml;", "\u{00EB}"),
+    ("euro;", "\u{20AC}"),
+    ("excl;", "\u{0021}"),
+    ("exist;", "\u{2203}"),
+    ("expectation;", "\u{2130}"),
+    ("exponentiale;", "\u{2147}"),
+    ("fallingdotseq;", "\u{2252}"),
+    ("fcy;", "\u{0444}"),
+    ("female;", "\u{2640}"),
+    ("ffilig;", "\u{FB03}"),
+    ("fflig;", "\u{FB00}"),
+    ("ffllig;", "\u{FB04}"),
+    ("ffr;", "\u{1D523}"),
+    ("filig;", "\u{FB01}"),
+    ("fjlig;", "\u{0066}\u{006A}"),
+    ("flat;", "\u{266D}"),
+    ("fllig;", "\u{FB02}"),
+    ("fltns;", "\u{25B1}"),
+    ("fnof;", "\u{0192}"),
+    ("fopf;", "\u{1D557}"),
+    ("forall;", "\u{2200}"),
+    ("fork;", "\u{22D4}"),
+    ("forkv;", "\u{2AD9}"),
+    ("fpartint;", "\u{2A0D}"),
+    ("frac12", "\u{00BD}"),
+    ("frac12;", "\u{00BD}"),
+    ("frac13;", "\u{2153}"),
+    ("frac14", "\u{00BC}"),
+    ("frac14;", "\u{00BC}"),
+    ("frac15;", "\u{2155}"),
+    ("frac16;", "\u{2159}"),
+    ("frac18;", "\u{215B}"),
+    ("frac23;", "\u{2154}"),
+    ("frac25;", "\u{2156}"),
+    ("frac34", "\u{00BE}"),
+    ("frac34;", "\u{00BE}"),
+    ("frac35;", "\u{2157}"),
+    ("frac38;", "\u{215C}"),
+    ("frac45;", "\u{2158}"),
+    ("frac56;", "\u{215A}"),
+    ("frac58;", "\u{215D}"),
+    ("frac78;", "\u{215E}"),
+    ("frasl;", "\u{2044}"),
+    ("frown;", "\u{2322}"),
+    ("fscr;", "\u{1D4BB}"),
+    ("gE;", "\u{2267}"),
+    ("gEl;", "\u{2A8C}"),
+    ("gacute;", "\u{01F5}"),
+    ("gamma;", "\u{03B3}"),
+    ("gammad;", "\u{03DD}"),
+    ("gap;", "\u{2A86}"),
+    ("gbreve;", "\u{011F}"),
+    ("gcirc;", "\u{011D}"),
+    ("gcy;", "\u{0433}"),
+    ("gdot;", "\u{0121}"),
+    ("ge;", "\u{2265}"),
+    ("gel;", "\u{22DB}"),
+    ("geq;", "\u{2265}"),
+    ("geqq;", "\u{2267}"),
+    ("geqslant;", "\u{2A7E}"),
+    ("ges;", "\u{2A7E}"),
+    ("gescc;", "\u{2AA9}"),
+    ("gesdot;", "\u{2A80}"),
+    ("gesdoto;", "\u{2A82}"),
+    ("gesdotol;", "\u{2A84}"),
+    ("gesl;", "\u{22DB}\u{FE00}"),
+    ("gesles;", "\u{2A94}"),
+    ("gfr;", "\u{1D524}"),
+    ("gg;", "\u{226B}"),
+    ("ggg;", "\u{22D9}"),
+    ("gimel;", "\u{2137}"),
+    ("gjcy;", "\u{0453}"),
+    ("gl;", "\u{2277}"),
+    ("glE;", "\u{2A92}"),
+    ("gla;", "\u{2AA5}"),
+    ("glj;", "\u{2AA4}"),
+    ("gnE;", "\u{2269}"),
+    ("gnap;", "\u{2A8A}"),
+    ("gnapprox;", "\u{2A8A}"),
+    ("gne;", "\u{2A88}"),
+    ("gneq;", "\u{2A88}"),
+    ("gneqq;", "\u{2269}"),
+    ("gnsim;", "\u{22E7}"),
+    ("gopf;", "\u{1D558}"),
+    ("grave;", "\u{0060}"),
+    ("gscr;", "\u{210A}"),
+    ("gsim;", "\u{2273}"),
+    ("gsime;", "\u{2A8E}"),
+    ("gsiml;", "\u{2A90}"),
+    ("gt", "\u{003E}"),
+    ("gt;", "\u{003E}"),
+    ("gtcc;", "\u{2AA7}"),
+    ("gtcir;", "\u{2A7A}"),
+    ("gtdot;", "\u{22D7}"),
+    ("gtlPar;", "\u{2995}"),
+    ("gtquest;", "\u{2A7C}"),
+    ("gtrapprox;", "\u{2A86}"),
+    ("gtrarr;", "\u{2978}"),
+    ("gtrdot;", "\u{22D7}"),
+    ("gtreqless;", "\u{22DB}"),
+    ("gtreqqless;", "\u{2A8C}"),
+    ("gtrless;", "\u{2277}"),
+    ("gtrsim;", "\u{2273}"),
+    ("gvertneqq;", "\u{2269}\u{FE00}"),
+    ("gvnE;", "\u{2269}\u{FE00}"),
+    ("hArr;", "\u{21D4}"),
+    ("hairsp;", "\u{200A}"),
+    ("half;", "\u{00BD}"),
+    ("hamilt;", "\u{210B}"),
+    ("hardcy;", "\u{044A}"),
+    ("harr;", "\u{2194}"),
+    ("harrcir;", "\u{2948}"),
+    ("harrw;", "\u{21AD}"),
+    ("hbar;", "\u{210F}"),
+    ("hcirc;", "\u{0125}"),
+    ("hearts;", "\u{2665}"),
+    ("heartsuit;", "\u{2665}"),
+    ("hellip;", "\u{2026}"),
+    ("hercon;", "\u{22B9}"),
+    ("hfr;", "\u{1D525}"),
+    ("hksearow;", "\u{2925}"),
+    ("hkswarow;", "\u{2926}"),
+    ("hoarr;", "\u{21FF}"),
+    ("homtht;", "\u{223B}"),
+    ("hookleftarrow;", "\u{21A9}"),
+    ("hookrightarrow;", "\u{21AA}"),
+    ("hopf;", "\u{1D559}"),
+    ("horbar;", "\u{2015}"),
+    ("hscr;", "\u{1D4BD}"),
+    ("hslash;", "\u{210F}"),
+    ("hstrok;", "\u{0127}"),
+    ("hybull;", "\u{2043}"),
+    ("hyphen;", "\u{2010}"),
+    ("iacute", "\u{00ED}"),
+    ("iacute;", "\u{00ED}"),
+    ("ic;", "\u{2063}"),
+    ("icirc", "\u{00EE}"),
+    ("icirc;", "\u{00EE}"),
+    ("icy;", "\u{0438}"),
+    ("iecy;", "\u{0435}"),
+    ("iexcl", "\u{00A1}"),
+    ("iexcl;", "\u{00A1}"),
+    ("iff;", "\u{21D4}"),
+    ("ifr;", "\u{1D526}"),
+    ("igrave", "\u{00EC}"),
+    ("igrave;", "\u{00EC}"),
+    ("ii;", "\u{2148}"),
+    ("iiiint;", "\u{2A0C}"),
+    ("iiint;", "\u{222D}"),
+    ("iinfin;", "\u{29DC}"),
+    ("iiota;", "\u{2129}"),
+    ("ijlig;", "\u{0133}"),
+    ("imacr;", "\u{012B}"),
+    ("image;", "\u{2111}"),
+    ("imagline;", "\u{2110}"),
+    ("imagpart;", "\u{2111}"),
+    ("imath;", "\u{0131}"),
+    ("imof;", "\u{22B7}"),
+    ("imped;", "\u{01B5}"),
+    ("in;", "\u{2208}"),
+    ("incare;", "\u{2105}"),
+    ("infin;", "\u{221E}"),
+    ("infintie;", "\u{29DD}"),
+    ("inodot;", "\u{0131}"),
+    ("int;", "\u{222B}"),
+    ("intcal;", "\u{22BA}"),
+    ("integers;", "\u{2124}"),
+    ("intercal;", "\u{22BA}"),
+    ("intlarhk;", "\u{2A17}"),
+    ("intprod;", "\u{2A3C}"),
+    ("iocy;", "\u{0451}"),
+    ("iogon;", "\u{012F}"),
+    ("iopf;", "\u{1D55A}"),
+    ("iota;", "\u{03B9}"),
+    ("iprod;", "\u{2A3C}"),
+    ("iquest", "\u{00BF}"),
+    ("iquest;", "\u{00BF}"),
+    ("iscr;", "\u{1D4BE}"),
+    ("isin;", "\u{2208}"),
+    ("isinE;", "\u{22F9}"),
+    ("isindot;", "\u{22F5}"),
+    ("isins;", "\u{22F4}"),
+    ("isinsv;", "\u{22F3}"),
+    ("isinv;", "\u{2208}"),
+    ("it;", "\u{2062}"),
+    ("itilde;", "\u{0129}"),
+    ("iukcy;", "\u{0456}"),
+    ("iuml", "\u{00EF}"),
+    ("iuml;", "\u{00EF}"),
+    ("jcirc;", "\u{0135}"),
+    ("jcy;", "\u{0439}"),
+    ("jfr;", "\u{1D527}"),
+    ("jmath;", "\u{0237}"),
+    ("jopf;", "\u{1D55B}"),
+    ("jscr;", "\u{1D4BF}"),
+    ("jsercy;", "\u{0458}"),
+    ("jukcy;", "\u{0454}"),
+    ("kappa;", "\u{03BA}"),
+    ("kappav;", "\u{03F0}"),
+    ("kcedil;", "\u{0137}"),
+    ("kcy;", "\u{043A}"),
+    ("kfr;", "\u{1D528}"),
+    ("kgreen;", "\u{0138}"),
+    ("khcy;", "\u{0445}"),
+    ("kjcy;", "\u{045C}"),
+    ("kopf;", "\u{1D55C}"),
+    ("kscr;", "\u{1D4C0}"),
+    ("lAarr;", "\u{21DA}"),
+    ("lArr;", "\u{21D0}"),
+    ("lAtail;", "\u{291B}"),
+    ("lBarr;", "\u{290E}"),
+    ("lE;", "\u{2266}"),
+    ("lEg;", "\u{2A8B}"),
+    ("lHar;", "\u{2962}"),
+    ("lacute;", "\u{013A}"),
+    ("laemptyv;", "\u{29B4}"),
+    ("lagran;", "\u{2112}"),
+    ("lambda;", "\u{03BB}"),
+    ("lang;", "\u{27E8}"),
+    ("langd;", "\u{2991}"),
+    ("langle;", "\u{27E8}"),
+    ("lap;", "\u{2A85}"),
+    ("laquo", "\u{00AB}"),
+    ("laquo;", "\u{00AB}"),
+    ("larr;", "\u{2190}"),
+    ("larrb;", "\u{21E4}"),
+    ("larrbfs;", "\u{291F}"),
+    ("larrfs;", "\u{291D}"),
+    ("larrhk;", "\u{21A9}"),
+    ("larrlp;", "\u{21AB}"),
+    ("larrpl;", "\u{2939}"),
+    ("larrsim;", "\u{2973}"),
+    ("larrtl;", "\u{21A2}"),
+    ("lat;", "\u{2AAB}"),
+    ("latail;", "\u{2919}"),
+    ("late;", "\u{2AAD}"),
+    ("lates;", "\u{2AAD}\u{FE00}"),
+    ("lbarr;", "\u{290C}"),
+    ("lbbrk;", "\u{2772}"),
+    ("lbrace;", "\u{007B}"),
+    ("lbrack;", "\u{005B}"),
+    ("lbrke;", "\u{298B}"),
+    ("lbrksld;", "\u{298F}"),
+    ("lbrkslu;", "\u{298D}"),
+    ("lcaron;", "\u{013E}"),
+    ("lcedil;", "\u{013C}"),
+    ("lceil;", "\u{2308}"),
+    ("lcub;", "\u{007B}"),
+    ("lcy;", "\u{043B}"),
+    ("ldca;", "\u{2936}"),
+    ("ldquo;", "\u{201C}"),
+    ("ldquor;", "\u{201E}"),
+    ("ldrdhar;", "\u{2967}"),
+    ("ldrushar;", "\u{294B}"),
+    ("ldsh;", "\u{21B2}"),
+    ("le;", "\u{2264}"),
+    ("leftarrow;", "\u{2190}"),
+    ("leftarrowtail;", "\u{21A2}"),
+    ("leftharpoondown;", "\u{21BD}"),
+    ("leftharpoonup;", "\u{21BC}"),
+    ("leftleftarrows;", "\u{21C7}"),
+    ("leftrightarrow;", "\u{2194}"),
+    ("leftrightarrows;", "\u{21C6}"),
+    ("leftrightharpoons;", "\u{21CB}"),
+    ("leftrightsquigarrow;", "\u{21AD}"),
+    ("leftthreetimes;", "\u{22CB}"),
+    ("leg;", "\u{22DA}"),
+    ("leq;", "\u{2264}"),
+    ("leqq;", "\u{2266}"),
+    ("leqslant;", "\u{2A7D}"),
+    ("les;", "\u{2A7D}"),
+    ("lescc;", "\u{2AA8}"),
+    ("lesdot;", "\u{2A7F}"),
+    ("lesdoto;", "\u{2A81}"),
+    ("lesdotor;", "\u{2A83}"),
+    ("lesg;", "\u{22DA}\u{FE00}"),
+    ("lesges;", "\u{2A93}"),
+    ("lessapprox;", "\u{2A85}"),
+    ("lessdot;", "\u{22D6}"),
+    ("lesseqgtr;", "\u{22DA}"),
+    ("lesseqqgtr;", "\u{2A8B}"),
+    ("lessgtr;", "\u{2276}"),
+    ("lesssim;", "\u{2272}"),
+    ("lfisht;", "\u{297C}"),
+    ("lfloor;", "\u{230A}"),
+    ("lfr;", "\u{1D529}"),
+    ("lg;", "\u{2276}"),
+    ("lgE;", "\u{2A91}"),
+    ("lhard;", "\u{21BD}"),
+    ("lharu;", "\u{21BC}"),
+    ("lharul;", "\u{296A}"),
+    ("lhblk;", "\u{2584}"),
+    ("ljcy;", "\u{0459}"),
+    ("ll;", "\u{226A}"),
+    ("llarr;", "\u{21C7}"),
+    ("llcorner;", "\u{231E}"),
+    ("llhard;", "\u{296B}"),
+    ("lltri;", "\u{25FA}"),
+    ("lmidot;", "\u{0140}"),
+    ("lmoust;", "\u{23B0}"),
+    ("lmoustache;", "\u{23B0}"),
+    ("lnE;", "\u{2268}"),
+    ("lnap;", "\u{2A89}"),
+    ("lnapprox;", "\u{2A89}"),
+    ("lne;", "\u{2A87}"),
+    ("lneq;", "\u{2A87}"),
+    ("lneqq;", "\u{2268}"),
+    ("lnsim;", "\u{22E6}"),
+    ("loang;", "\u{27EC}"),
+    ("loarr;", "\u{21FD}"),
+    ("lobrk;", "\u{27E6}"),
+    ("longleftarrow;", "\u{27F5}"),
+    ("longleftrightarrow;", "\u{27F7}"),
+    ("longmapsto;", "\u{27FC}"),
+    ("longrightarrow;", "\u{27F6}"),
+    ("looparrowleft;", "\u{21AB}"),
+    ("looparrowright;", "\u{21AC}"),
+    ("lopar;", "\u{2985}"),
+    ("lopf;", "\u{1D55D}"),
+    ("loplus;", "\u{2A2D}"),
+    ("lotimes;", "\u{2A34}"),
+    ("lowast;", "\u{2217}"),
+    ("lowbar;", "\u{005F}"),
+    ("loz;", "\u{25CA}"),
+    ("lozenge;", "\u{25CA}"),
+    ("lozf;", "\u{29EB}"),
+    ("lpar;", "\u{0028}"),
+    ("lparlt;", "\u{2993}"),
+    ("lrarr;", "\u{21C6}"),
+    ("lrcorner;", "\u{231F}"),
+    ("lrhar;", "\u{21CB}"),
+    ("lrhard;", "\u{296D}"),
+    ("lrm;", "\u{200E}"),
+    ("lrtri;", "\u{22BF}"),
+    ("lsaquo;", "\u{2039}"),
+    ("lscr;", "\u{1D4C1}"),
+    ("lsh;", "\u{21B0}"),
+    ("lsim;", "\u{2272}"),
+    ("lsime;", "\u{2A8D}"),
+    ("lsimg;", "\u{2A8F}"),
+    ("lsqb;", "\u{005B}"),
+    ("lsquo;", "\u{2018}"),
+    ("lsquor;", "\u{201A}"),
+    ("lstrok;", "\u{0142}"),
+    ("lt", "\u{003C}"),
+    ("lt;", "\u{003C}"),
+    ("ltcc;", "\u{2AA6}"),
+    ("ltcir;", "\u{2A79}"),
+    ("ltdot;", "\u{22D6}"),
+    ("lthree;", "\u{22CB}"),
+    ("ltimes;", "\u{22C9}"),
+    ("ltlarr;", "\u{2976}"),
+    ("ltquest;", "\u{2A7B}"),
+    ("ltrPar;", "\u{2996}"),
+    ("ltri;", "\u{25C3}"),
+    ("ltrie;", "\u{22B4}"),
+    ("ltrif;", "\u{25C2}"),
+    ("lurdshar;", "\u{294A}"),
+    ("luruhar;", "\u{2966}"),
+    ("lvertneqq;", "\u{2268}\u{FE00}"),
+    ("lvnE;", "\u{2268}\u{FE00}"),
+    ("mDDot;", "\u{223A}"),
+    ("macr", "\u{00AF}"),
+    ("macr;", "\u{00AF}"),
+    ("male;", "\u{2642}"),
+    ("malt;", "\u{2720}"),
+    ("maltese;", "\u{2720}"),
+    ("map;", "\u{21A6}"),
+    ("mapsto;", "\u{21A6}"),
+    ("mapstodown;", "\u{21A7}"),
+    ("mapstoleft;", "\u{21A4}"),
+    ("mapstoup;", "\u{21A5}"),
+    ("marker;", "\u{25AE}"),
+    ("mcomma;", "\u{2A29}"),
+    ("mcy;", "\u{043C}"),
+    ("mdash;", "\u{2014}"),
+    ("measuredangle;", "\u{2221}"),
+    ("mfr;", "\u{1D52A}"),
+    ("mho;", "\u{2127}"),
+    ("micro", "\u{00B5}"),
+    ("micro;", "\u{00B5}"),
+    ("mid;", "\u{2223}"),
+    ("midast;", "\u{002A}"),
+    ("midcir;", "\u{2AF0}"),
+    ("middot", "\u{00B7}"),
+    ("middot;", "\u{00B7}"),
+    ("minus;", "\u{2212}"),
+    ("minusb;", "\u{229F}"),
+    ("minusd;", "\u{2238}"),
+    ("minusdu;", "\u{2A2A}"),
+    ("mlcp;", "\u{2ADB}"),
+    ("mldr;", "\u{2026}"),
+    ("mnplus;", "\u{2213}"),
+    ("models;", "\u{22A7}"),
+    ("mopf;", "\u{1D55E}"),
+    ("mp;", "\u{2213}"),
+    ("mscr;", "\u{1D4C2}"),
+    ("mstpos;", "\u{223E}"),
+    ("mu;", "\u{03BC}"),
+    ("multimap;", "\u{22B8}"),
+    ("mumap;", "\u{22B8}"),
+    ("nGg;", "\u{22D9}\u{0338}"),
+    ("nGt;", "\u{226B}\u{20D2}"),
+    ("nGtv;", "\u{226B}\u{0338}"),
+    ("nLeftarrow;", "\u{21CD}"),
+    ("nLeftrightarrow;", "\u{21CE}"),
+    ("nLl;", "\u{22D8}\u{0338}"),
+    ("nLt;", "\u{226A}\u{20D2}"),
+    ("nLtv;", "\u{226A}\u{0338}"),
+    ("nRightarrow;", "\u{21CF}"),
+    ("nVDash;", "\u{22AF}"),
+    ("nVdash;", "\u{22AE}"),
+    ("nabla;", "\u{2207}"),
+    ("nacute;", "\u{0144}"),
+    ("nang;", "\u{2220}\u{20D2}"),
+    ("nap;", "\u{2249}"),
+    ("napE;", "\u{2A70}\u{0338}"),
+    ("napid;", "\u{224B}\u{0338}"),
+    ("napos;", "\u{0149}"),
+    ("napprox;", "\u{2249}"),
+    ("natur;", "\u{266E}"),
+    ("natural;", "\u{266E}"),
+    ("naturals;", "\u{2115}"),
+    ("nbsp", "\u{00A0}"),
+    ("nbsp;", "\u{00A0}"),
+    ("nbump;", "\u{224E}\u{0338}"),
+    ("nbumpe;", "\u{224F}\u{0338}"),
+    ("ncap;", "\u{2A43}"),
+    ("ncaron;", "\u{0148}"),
+    ("ncedil;", "\u{0146}"),
+    ("ncong;", "\u{2247}"),
+    ("ncongdot;", "\u{2A6D}\u{0338}"),
+    ("ncup;", "\u{2A42}"),
+    ("ncy;", "\u{043D}"),
+    ("ndash;", "\u{2013}"),
+    ("ne;", "\u{2260}"),
+    ("neArr;", "\u{21D7}"),
+    ("nearhk;", "\u{2924}"),
+    ("nearr;", "\u{2197}"),
+    ("nearrow;", "\u{2197}"),
+    ("nedot;", "\u{2250}\u{0338}"),
+    ("nequiv;", "\u{2262}"),
+    ("nesear;", "\u{2928}"),
+    ("nesim;", "\u{2242}\u{0338}"),
+    ("nexist;", "\u{2204}"),
+    ("nexists;", "\u{2204}"),
+    ("nfr;", "\u{1D52B}"),
+    ("ngE;", "\u{2267}\u{0338}"),
+    ("nge;", "\u{2271}"),
+    ("ngeq;", "\u{2271}"),
+    ("ngeqq;", "\u{2267}\u{0338}"),
+    ("ngeqslant;", "\u{2A7E}\u{0338}"),
+    ("nges;", "\u{2A7E}\u{0338}"),
+    ("ngsim;", "\u{2275}"),
+    ("ngt;", "\u{226F}"),
+    ("ngtr;", "\u{226F}"),
+    ("nhArr;", "\u{21CE}"),
+    ("nharr;", "\u{21AE}"),
+    ("nhpar;", "\u{2AF2}"),
+    ("ni;", "\u{220B}"),
+    ("nis;", "\u{22FC}"),
+    ("nisd;", "\u{22FA}"),
+    ("niv;", "\u{220B}"),
+    ("njcy;", "\u{045A}"),
+    ("nlArr;", "\u{21CD}"),
+    ("nlE;", "\u{2266}\u{0338}"),
+    ("nlarr;", "\u{219A}"),
+    ("nldr;", "\u{2025}"),
+    ("nle;", "\u{2270}"),
+    ("nleftarrow;", "\u{219A}"),
+    ("nleftrightarrow;", "\u{21AE}"),
+    ("nleq;", "\u{2270}"),
+    ("nleqq;", "\u{2266}\u{0338}"),
+    ("nleqslant;", "\u{2A7D}\u{0338}"),
+    ("nles;", "\u{2A7D}\u{0338}"),
+    ("nless;", "\u{226E}"),
+    ("nlsim;", "\u{2274}"),
+    ("nlt;", "\u{226E}"),
+    ("nltri;", "\u{22EA}"),
+    ("nltrie;", "\u{22EC}"),
+    ("nmid;", "\u{2224}"),
+    ("nopf;", "\u{1D55F}"),
+    ("not", "\u{00AC}"),
+    ("not;", "\u{00AC}"),
+    ("notin;", "\u{2209}"),
+    ("notinE;", "\u{22F9}\u{0338}"),
+    ("notindot;", "\u{22F5}\u{0338}"),
+    ("notinva;", "\u{2209}"),
+    ("notinvb;", "\u{22F7}"),
+    ("notinvc;", "\u{22F6}"),
+    ("notni;", "\u{220C}"),
+    ("notniva;", "\u{220C}"),
+    ("notnivb;", "\u{22FE}"),
+    ("notnivc;", "\u{22FD}"),
+    ("npar;", "\u{2226}"),
+    ("nparallel;", "\u{2226}"),
+    ("nparsl;", "\u{2AFD}\u{20E5}"),
+    ("npart;", "\u{2202}\u{0338}"),
+    ("npolint;", "\u{2A14}"),
+    ("npr;", "\u{2280}"),
+    ("nprcue;", "\u{22E0}"),
+    ("npre;", "\u{2AAF}\u{0338}"),
+    ("nprec;", "\u{2280}"),
+    ("npreceq;", "\u{2AAF}\u{0338}"),
+    ("nrArr;", "\u{21CF}"),
+    ("nrarr;", "\u{219B}"),
+    ("nrarrc;", "\u{2933}\u{0338}"),
+    ("nrarrw;", "\u{219D}\u{0338}"),
+    ("nrightarrow;", "\u{219B}"),
+    ("nrtri;", "\u{22EB}"),
+    ("nrtrie;", "\u{22ED}"),
+    ("nsc;", "\u{2281}"),
+    ("nsccue;", "\u{22E1}"),
+    ("nsce;", "\u{2AB0}\u{0338}"),
+    ("nscr;", "\u{1D4C3}"),
+    ("nshortmid;", "\u{2224}"),
+    ("nshortparallel;", "\u{2226}"),
+    ("nsim;", "\u{2241}"),
+    ("nsime;", "\u{2244}"),
+    ("nsimeq;", "\u{2244}"),
+    ("nsmid;", "\u{2224}"),
+    ("nspar;", "\u{2226}"),
+    ("nsqsube;", "\u{22E2}"),
+    ("nsqsupe;", "\u{22E3}"),
+    ("nsub;", "\u{2284}"),
+    ("nsubE;", "\u{2AC5}\u{0338}"),
+    ("nsube;", "\u{2288}"),
+    ("nsubset;", "\u{2282}\u{20D2}"),
+    ("nsubseteq;", "\u{2288}"),
+    ("nsubseteqq;", "\u{2AC5}\u{0338}"),
+    ("nsucc;", "\u{2281}"),
+    ("nsucceq;", "\u{2AB0}\u{0338}"),
+    ("nsup;", "\u{2285}"),
+    ("nsupE;", "\u{2AC6}\u{0338}"),
+    ("nsupe;", "\u{2289}"),
+    ("nsupset;", "\u{2283}\u{20D2}"),
+    ("nsupseteq;", "\u{2289}"),
+    ("nsupseteqq;", "\u{2AC6}\u{0338}"),
+    ("ntgl;", "\u{2279}"),
+    ("ntilde", "\u{00F1}"),
+    ("ntilde;", "\u{00F1}"),
+    ("ntlg;", "\u{2278}"),
+    ("ntriangleleft;", "\u{22EA}"),
+    ("ntrianglelefteq;", "\u{22EC}"),
+    ("ntriangleright;", "\u{22EB}"),
+    ("ntrianglerighteq;", "\u{22ED}"),
+    ("nu;", "\u{03BD}"),
+    ("num;", "\u{0023}"),
+    ("numero;", "\u{2116}"),
+    ("numsp;", "\u{2007}"),
+    ("nvDash;", "\u{22AD}"),
+    ("nvHarr;", "\u{2904}"),
+    ("nvap;", "\u{224D}\u{20D2}"),
+    ("nvdash;", "\u{22AC}"),
+    ("nvge;", "\u{2265}\u{20D2}"),
+    ("nvgt;", "\u{003E}\u{20D2}"),
+    ("nvinfin;", "\u{29DE}"),
+    ("nvlArr;", "\u{2902}"),
+    ("nvle;", "\u{2264}\u{20D2}"),
+    ("nvlt;", "\u{003C}\u{20D2}"),
+    ("nvltrie;", "\u{22B4}\u{20D2}"),
+    ("nvrArr;", "\u{2903}"),
+    ("nvrtrie;", "\u{22B5}\u{20D2}"),
+    ("nvsim;", "\u{223C}\u{20D2}"),
+    ("nwArr;", "\u{21D6}"),
+    ("nwarhk;", "\u{2923}"),
+    ("nwarr;", "\u{2196}"),
+    ("nwarrow;", "\u{2196}"),
+    ("nwnear;", "\u{2927}"),
+    ("oS;", "\u{24C8}"),
+    ("oacute", "\u{00F3}"),
+    ("oacute;", "\u{00F3}"),
+    ("oast;", "\u{229B}"),
+    ("ocir;", "\u{229A}"),
+    ("ocirc", "\u{00F4}"),
+    ("ocirc;", "\u{00F4}"),
+    ("ocy;", "\u{043E}"),
+    ("odash;", "\u{229D}"),
+    ("odblac;", "\u{0151}"),
+    ("odiv;", "\u{2A38}"),
+    ("odot;", "\u{2299}"),
+    ("odsold;", "\u{29BC}"),
+    ("oelig;", "\u{0153}"),
+    ("ofcir;", "\u{29BF}"),
+    ("ofr;", "\u{1D52C}"),
+    ("ogon;", "\u{02DB}"),
+    ("ograve", "\u{00F2}"),
+    ("ograve;", "\u{00F2}"),
+    ("ogt;", "\u{29C1}"),
+    ("ohbar;", "\u{29B5}"),
+    ("ohm;", "\u{03A9}"),
+    ("oint;", "\u{222E}"),
+    ("olarr;", "\u{21BA}"),
+    ("olcir;", "\u{29BE}"),
+    ("olcross;", "\u{29BB}"),
+    ("oline;", "\u{203E}"),
+    ("olt;", "\u{29C0}"),
+    ("omacr;", "\u{014D}"),
+    ("omega;", "\u{03C9}"),
+    ("omicron;", "\u{03BF}"),
+    ("omid;", "\u{29B6}"),
+    ("ominus;", "\u{2296}"),
+    ("oopf;", "\u{1D560}"),
+    ("opar;", "\u{29B7}"),
+    ("operp;", "\u{29B9}"),
+    ("oplus;", "\u{2295}"),
+    ("or;", "\u{2228}"),
+    ("orarr;", "\u{21BB}"),
+    ("ord;", "\u{2A5D}"),
+    ("order;", "\u{2134}"),
+    ("orderof;", "\u{2134}"),
+    ("ordf", "\u{00AA}"),
+    ("ordf;", "\u{00AA}"),
+    ("ordm", "\u{00BA}"),
+    ("ordm;", "\u{00BA}"),
+    ("origof;", "\u{22B6}"),
+    ("oror;", "\u{2A56}"),
+    ("orslope;", "\u{2A57}"),
+    ("orv;", "\u{2A5B}"),
+    ("oscr;", "\u{2134}"),
+    ("oslash", "\u{00F8}"),
+    ("oslash;", "\u{00F8}"),
+    ("osol;", "\u{2298}"),
+    ("otilde", "\u{00F5}"),
+    ("otilde;", "\u{00F5}"),
+    ("otimes;", "\u{2297}"),
+    ("otimesas;", "\u{2A36}"),
+    ("ouml", "\u{00F6}"),
+    ("ouml;", "\u{00F6}"),
+    ("ovbar;", "\u{233D}"),
+    ("par;", "\u{2225}"),
+    ("para", "\u{00B6}"),
+    ("para;", "\u{00B6}"),
+    ("parallel;", "\u{2225}"),
+    ("parsim;", "\u{2AF3}"),
+    ("parsl;", "\u{2AFD}"),
+    ("part;", "\u{2202}"),
+    ("pcy;", "\u{043F}"),
+    ("percnt;", "\u{0025}"),
+    ("period;", "\u{002E}"),
+    ("permil;", "\u{2030}"),
+    ("perp;", "\u{22A5}"),
+    ("pertenk;", "\u{2031}"),
+    ("pfr;", "\u{1D52D}"),
+    ("phi;", "\u{03C6}"),
+    ("phiv;", "\u{03D5}"),
+    ("phmmat;", "\u{2133}"),
+    ("phone;", "\u{260E}"),
+    ("pi;", "\u{03C0}"),
+    ("pitchfork;", "\u{22D4}"),
+    ("piv;", "\u{03D6}"),
+    ("planck;", "\u{210F}"),
+    ("planckh;", "\u{210E}"),
+    ("plankv;", "\u{210F}"),
+    ("plus;", "\u{002B}"),
+    ("plusacir;", "\u{2A23}"),
+    ("plusb;", "\u{229E}"),
+    ("pluscir;", "\u{2A22}"),
+    ("plusdo;", "\u{2214}"),
+    ("plusdu;", "\u{2A25}"),
+    ("pluse;", "\u{2A72}"),
+    ("plusmn", "\u{00B1}"),
+    ("plusmn;", "\u{00B1}"),
+    ("plussim;", "\u{2A26}"),
+    ("plustwo;", "\u{2A27}"),
+    ("pm;", "\u{00B1}"),
+    ("pointint;", "\u{2A15}"),
+    ("popf;", "\u{1D561}"),
+    ("pound", "\u{00A3}"),
+    ("pound;", "\u{00A3}"),
+    ("pr;", "\u{227A}"),
+    ("prE;", "\u{2AB3}"),
+    ("prap;", "\u{2AB7}"),
+    ("prcue;", "\u{227C}"),
+    ("pre;", "\u{2AAF}"),
+    ("prec;", "\u{227A}"),
+    ("precapprox;", "\u{2AB7}"),
+    ("preccurlyeq;", "\u{227C}"),
+    ("preceq;", "\u{2AAF}"),
+    ("precnapprox;", "\u{2AB9}"),
+    ("precneqq;", "\u{2AB5}"),
+    ("precnsim;", "\u{22E8}"),
+    ("precsim;", "\u{227E}"),
+    ("prime;", "\u{2032}"),
+    ("primes;", "\u{2119}"),
+    ("prnE;", "\u{2AB5}"),
+    ("prnap;", "\u{2AB9}"),
+    ("prnsim;", "\u{22E8}"),
+    ("prod;", "\u{220F}"),
+    ("profalar;", "\u{232E}"),
+    ("profline;", "\u{2312}"),
+    ("profsurf;", "\u{2313}"),
+    ("prop;", "\u{221D}"),
+    ("propto;", "\u{221D}"),
+    ("prsim;", "\u{227E}"),
+    ("prurel;", "\u{22B0}"),
+    ("pscr;", "\u{1D4C5}"),
+    ("psi;", "\u{03C8}"),
+    ("puncsp;", "\u{2008}"),
+    ("qfr;", "\u{1D52E}"),
+    ("qint;", "\u{2A0C}"),
+    ("qopf;", "\u{1D562}"),
+    ("qprime;", "\u{2057}"),
+    ("qscr;", "\u{1D4C6}"),
+    ("quaternions;", "\u{210D}"),
+    ("quatint;", "\u{2A16}"),
+    ("quest;", "\u{003F}"),
+    ("questeq;", "\u{225F}"),
+    ("quot", "\u{0022}"),
+    ("quot;", "\u{0022}"),
+    ("rAarr;", "\u{21DB}"),
+    ("rArr;", "\u{21D2}"),
+    ("rAtail;", "\u{291C}"),
+    ("rBarr;", "\u{290F}"),
+    ("rHar;", "\u{2964}"),
+    ("race;", "\u{223D}\u{0331}"),
+    ("racute;", "\u{0155}"),
+    ("radic;", "\u{221A}"),
+    ("raemptyv;", "\u{29B3}"),
+    ("rang;", "\u{27E9}"),
+    ("rangd;", "\u{2992}"),
+    ("range;", "\u{29A5}"),
+    ("rangle;", "\u{27E9}"),
+    ("raquo", "\u{00BB}"),
+    ("raquo;", "\u{00BB}"),
+    ("rarr;", "\u{2192}"),
+    ("rarrap;", "\u{2975}"),
+    ("rarrb;", "\u{21E5}"),
+    ("rarrbfs;", "\u{2920}"),
+    ("rarrc;", "\u{2933}"),
+    ("rarrfs;", "\u{291E}"),
+    ("rarrhk;", "\u{21AA}"),
+    ("rarrlp;", "\u{21AC}"),
+    ("rarrpl;", "\u{2945}"),
+    ("rarrsim;", "\u{2974}"),
+    ("rarrtl;", "\u{21A3}"),
+    ("rarrw;", "\u{219D}"),
+    ("ratail;", "\u{291A}"),
+    ("ratio;", "\u{2236}"),
+    ("rationals;", "\u{211A}"),
+    ("rbarr;", "\u{290D}"),
+    ("rbbrk;", "\u{2773}"),
+    ("rbrace;", "\u{007D}"),
+    ("rbrack;", "\u{005D}"),
+    ("rbrke;", "\u{298C}"),
+    ("rbrksld;", "\u{298E}"),
+    ("rbrkslu;", "\u{2990}"),
+    ("rcaron;", "\u{0159}"),
+    ("rcedil;", "\u{0157}"),
+    ("rceil;", "\u{2309}"),
+    ("rcub;", "\u{007D}"),
+    ("rcy;", "\u{0440}"),
+    ("rdca;", "\u{2937}"),
+    ("rdldhar;", "\u{2969}"),
+    ("rdquo;", "\u{201D}"),
+    ("rdquor;", "\u{201D}"),
+    ("rdsh;", "\u{21B3}"),
+    ("real;", "\u{211C}"),
+    ("realine;", "\u{211B}"),
+    ("realpart;", "\u{211C}"),
+    ("reals;", "\u{211D}"),
+    ("rect;", "\u{25AD}"),
+    ("reg", "\u{00AE}"),
+    ("reg;", "\u{00AE}"),
+    ("rfisht;", "\u{297D}"),
+    ("rfloor;", "\u{230B}"),
+    ("rfr;", "\u{1D52F}"),
+    ("rhard;", "\u{21C1}"),
+    ("rharu;", "\u{21C0}"),
+    ("rharul;", "\u{296C}"),
+    ("rho;", "\u{03C1}"),
+    ("rhov;", "\u{03F1}"),
+    ("rightarrow;", "\u{2192}"),
+    ("rightarrowtail;", "\u{21A3}"),
+    ("rightharpoondown;", "\u{21C1}"),
+    ("rightharpoonup;", "\u{21C0}"),
+    ("rightleftarrows;", "\u{21C4}"),
+    ("rightleftharpoons;", "\u{21CC}"),
+    ("rightrightarrows;", "\u{21C9}"),
+    ("rightsquigarrow;", "\u{219D}"),
+    ("rightthreetimes;", "\u{22CC}"),
+    ("ring;", "\u{02DA}"),
+    ("risingdotseq;", "\u{2253}"),
+    ("rlarr;", "\u{21C4}"),
+    ("rlhar;", "\u{21CC}"),
+    ("rlm;", "\u{200F}"),
+    ("rmoust;", "\u{23B1}"),
+    ("rmoustache;", "\u{23B1}"),
+    ("rnmid;", "\u{2AEE}"),
+    ("roang;", "\u{27ED}"),
+    ("roarr;", "\u{21FE}"),
+    ("robrk;", "\u{27E7}"),
+    ("ropar;", "\u{2986}"),
+    ("ropf;", "\u{1D563}"),
+    ("roplus;", "\u{2A2E}"),
+    ("rotimes;", "\u{2A35}"),
+    ("rpar;", "\u{0029}"),
+    ("rpargt;", "\u{2994}"),
+    ("rppolint;", "\u{2A12}"),
+    ("rrarr;", "\u{21C9}"),
+    ("rsaquo;", "\u{203A}"),
+    ("rscr;", "\u{1D4C7}"),
+    ("rsh;", "\u{21B1}"),
+    ("rsqb;", "\u{005D}"),
+    ("rsquo;", "\u{2019}"),
+    ("rsquor;", "\u{2019}"),
+    ("rthree;", "\u{22CC}"),
+    ("rtimes;", "\u{22CA}"),
+    ("rtri;", "\u{25B9}"),
+    ("rtrie;", "\u{22B5}"),
+    ("rtrif;", "\u{25B8}"),
+    ("rtriltri;", "\u{29CE}"),
+    ("ruluhar;", "\u{2968}"),
+    ("rx;", "\u{211E}"),
+    ("sacute;", "\u{015B}"),
+    ("sbquo;", "\u{201A}"),
+    ("sc;", "\u{227B}"),
+    ("scE;", "\u{2AB4}"),
+    ("scap;", "\u{2AB8}"),
+    ("scaron;", "\u{0161}"),
+    ("sccue;", "\u{227D}"),
+    ("sce;", "\u{2AB0}"),
+    ("scedil;", "\u{015F}"),
+    ("scirc;", "\u{015D}"),
+    ("scnE;", "\u{2AB6}"),
+    ("scnap;", "\u{2ABA}"),
+    ("scnsim;", "\u{22E9}"),
+    ("scpolint;", "\u{2A13}"),
+    ("scsim;", "\u{227F}"),
+    ("scy;", "\u{0441}"),
+    ("sdot;", "\u{22C5}"),
+    ("sdotb;", "\u{22A1}"),
+    ("sdote;", "\u{2A66}"),
+    ("seArr;", "\u{21D8}"),
+    ("searhk;", "\u{2925}"),
+    ("searr;", "\u{2198}"),
+    ("searrow;", "\u{2198}"),
+    ("sect", "\u{00A7}"),
+    ("sect;", "\u{00A7}"),
+    ("semi;", "\u{003B}"),
+    ("seswar;", "\u{2929}"),
+    ("setminus;", "\u{2216}"),
+    ("setmn;", "\u{2216}"),
+    ("sext;", "\u{2736}"),
+    ("sfr;", "\u{1D530}"),
+    ("sfrown;", "\u{2322}"),
+    ("sharp;", "\u{266F}"),
+    ("shchcy;", "\u{0449}"),
+    ("shcy;", "\u{0448}"),
+    ("shortmid;", "\u{2223}"),
+    ("shortparallel;", "\u{2225}"),
+    ("shy", "\u{00AD}"),
+    ("shy;", "\u{00AD}"),
+    ("sigma;", "\u{03C3}"),
+    ("sigmaf;", "\u{03C2}"),
+    ("sigmav;", "\u{03C2}"),
+    ("sim;", "\u{223C}"),
+    ("simdot;", "\u{2A6A}"),
+    ("sime;", "\u{2243}"),
+    ("simeq;", "\u{2243}"),
+    ("simg;", "\u{2A9E}"),
+    ("simgE;", "\u{2AA0}"),
+    ("siml;", "\u{2A9D}"),
+    ("simlE;", "\u{2A9F}"),
+    ("simne;", "\u{2246}"),
+    ("simplus;", "\u{2A24}"),
+    ("simrarr;", "\u{2972}"),
+    ("slarr;", "\u{2190}"),
+    ("smallsetminus;", "\u{2216}"),
+    ("smashp;", "\u{2A33}"),
+    ("smeparsl;", "\u{29E4}"),
+    ("smid;", "\u{2223}"),
+    ("smile;", "\u{2323}"),
+    ("smt;", "\u{2AAA}"),
+    ("smte;", "\u{2AAC}"),
+    ("smtes;", "\u{2AAC}\u{FE00}"),
+    ("softcy;", "\u{044C}"),
+    ("sol;", "\u{002F}"),
+    ("solb;", "\u{29C4}"),
+    ("solbar;", "\u{233F}"),
+    ("sopf;", "\u{1D564}"),
+    ("spades;", "\u{2660}"),
+    ("spadesuit;", "\u{2660}"),
+    ("spar;", "\u{2225}"),
+    ("sqcap;", "\u{2293}"),
+    ("sqcaps;", "\u{2293}\u{FE00}"),
+    ("sqcup;", "\u{2294}"),
+    ("sqcups;", "\u{2294}\u{FE00}"),
+    ("sqsub;", "\u{228F}"),
+    ("sqsube;", "\u{2291}"),
+    ("sqsubset;", "\u{228F}"),
+    ("sqsubseteq;", "\u{2291}"),
+    ("sqsup;", "\u{2290}"),
+    ("sqsupe;", "\u{2292}"),
+    ("sqsupset;", "\u{2290}"),
+    ("sqsupseteq;", "\u{2292}"),
+    ("squ;", "\u{25A1}"),
+    ("square;", "\u{25A1}"),
+    ("squarf;", "\u{25AA}"),
+    ("squf;", "\u{25AA}"),
+    ("srarr;", "\u{2192}"),
+    ("sscr;", "\u{1D4C8}"),
+    ("ssetmn;", "\u{2216}"),
+    ("ssmile;", "\u{2323}"),
+    ("sstarf;", "\u{22C6}"),
+    ("star;", "\u{2606}"),
+    ("starf;", "\u{2605}"),
+    ("straightepsilon;", "\u{03F5}"),
+    ("straightphi;", "\u{03D5}"),
+    ("strns;", "\u{00AF}"),
+    ("sub;", "\u{2282}"),
+    ("subE;", "\u{2AC5}"),
+    ("subdot;", "\u{2ABD}"),
+    ("sube;", "\u{2286}"),
+    ("subedot;", "\u{2AC3}"),
+    ("submult;", "\u{2AC1}"),
+    ("subnE;", "\u{2ACB}"),
+    ("subne;", "\u{228A}"),
+    ("subplus;", "\u{2ABF}"),
+    ("subrarr;", "\u{2979}"),
+    ("subset;", "\u{2282}"),
+    ("subseteq;", "\u{2286}"),
+    ("subseteqq;", "\u{2AC5}"),
+    ("subsetneq;", "\u{228A}"),
+    ("subsetneqq;", "\u{2ACB}"),
+    ("subsim;", "\u{2AC7}"),
+    ("subsub;", "\u{2AD5}"),
+    ("subsup;", "\u{2AD3}"),
+    ("succ;", "\u{227B}"),
+    ("succapprox;", "\u{2AB8}"),
+    ("succcurlyeq;", "\u{227D}"),
+    ("succeq;", "\u{2AB0}"),
+    ("succnapprox;", "\u{2ABA}"),
+    ("succneqq;", "\u{2AB6}"),
+    ("succnsim;", "\u{22E9}"),
+    ("succsim;", "\u{227F}"),
+    ("sum;", "\u{2211}"),
+    ("sung;", "\u{266A}"),
+    ("sup1", "\u{00B9}"),
+    ("sup1;", "\u{00B9}"),
+    ("sup2", "\u{00B2}"),
+    ("sup2;", "\u{00B2}"),
+    ("sup3", "\u{00B3}"),
+    ("sup3;", "\u{00B3}"),
+    ("sup;", "\u{2283}"),
+    ("supE;", "\u{2AC6}"),
+    ("supdot;", "\u{2ABE}"),
+    ("supdsub;", "\u{2AD8}"),
+    ("supe;", "\u{2287}"),
+    ("supedot;", "\u{2AC4}"),
+    ("suphsol;", "\u{27C9}"),
+    ("suphsub;", "\u{2AD7}"),
+    ("suplarr;", "\u{297B}"),
+    ("supmult;", "\u{2AC2}"),
+    ("supnE;", "\u{2ACC}"),
+    ("supne;", "\u{228B}"),
+    ("supplus;", "\u{2AC0}"),
+    ("supset;", "\u{2283}"),
+    ("supseteq;", "\u{2287}"),
+    ("supseteqq;", "\u{2AC6}"),
+    ("supsetneq;", "\u{228B}"),
+    ("supsetneqq;", "\u{2ACC}"),
+    ("supsim;", "\u{2AC8}"),
+    ("supsub;", "\u{2AD4}"),
+    ("supsup;", "\u{2AD6}"),
+    ("swArr;", "\u{21D9}"),
+    ("swarhk;", "\u{2926}"),
+    ("swarr;", "\u{2199}"),
+    ("swarrow;", "\u{2199}"),
+    ("swnwar;", "\u{292A}"),
+    ("szlig", "\u{00DF}"),
+    ("szlig;", "\u{00DF}"),
+    ("target;", "\u{2316}"),
+    ("tau;", "\u{03C4}"),
+    ("tbrk;", "\u{23B4}"),
+    ("tcaron;", "\u{0165}"),
+    ("tcedil;", "\u{0163}"),
+    ("tcy;", "\u{0442}"),
+    ("tdot;", "\u{20DB}"),
+    ("telrec;", "\u{2315}"),
+    ("tfr;", "\u{1D531}"),
+    ("there4;", "\u{2234}"),
+    ("therefore;", "\u{2234}"),
+    ("theta;", "\u{03B8}"),
+    ("thetasym;", "\u{03D1}"),
+    ("thetav;", "\u{03D1}"),
+    ("thickapprox;", "\u{2248}"),
+    ("thicksim;", "\u{223C}"),
+    ("thinsp;", "\u{2009}"),
+    ("thkap;", "\u{2248}"),
+    ("thksim;", "\u{223C}"),
+    ("thorn", "\u{00FE}"),
+    ("thorn;", "\u{00FE}"),
+    ("tilde;", "\u{02DC}"),
+    ("times", "\u{00D7}"),
+    ("times;", "\u{00D7}"),
+    ("timesb;", "\u{22A0}"),
+    ("timesbar;", "\u{2A31}"),
+    ("timesd;", "\u{2A30}"),
+    ("tint;", "\u{222D}"),
+    ("toea;", "\u{2928}"),
+    ("top;", "\u{22A4}"),
+    ("topbot;", "\u{2336}"),
+    ("topcir;", "\u{2AF1}"),
+    ("topf;", "\u{1D565}"),
+    ("topfork;", "\u{2ADA}"),
+    ("tosa;", "\u{2929}"),
+    ("tprime;", "\u{2034}"),
+    ("trade;", "\u{2122}"),
+    ("triangle;", "\u{25B5}"),
+    ("triangledown;", "\u{25BF}"),
+    ("triangleleft;", "\u{25C3}"),
+    ("trianglelefteq;", "\u{22B4}"),
+    ("triangleq;", "\u{225C}"),
+    ("triangleright;", "\u{25B9}"),
+    ("trianglerighteq;", "\u{22B5}"),
+    ("tridot;", "\u{25EC}"),
+    ("trie;", "\u{225C}"),
+    ("triminus;", "\u{2A3A}"),
+    ("triplus;", "\u{2A39}"),
+    ("trisb;", "\u{29CD}"),
+    ("tritime;", "\u{2A3B}"),
+    ("trpezium;", "\u{23E2}"),
+    ("tscr;", "\u{1D4C9}"),
+    ("tscy;", "\u{0446}"),
+    ("tshcy;", "\u{045B}"),
+    ("tstrok;", "\u{0167}"),
+    ("twixt;", "\u{226C}"),
+    ("twoheadleftarrow;", "\u{219E}"),
+    ("twoheadrightarrow;", "\u{21A0}"),
+    ("uArr;", "\u{21D1}"),
+    ("uHar;", "\u{2963}"),
+    ("uacute", "\u{00FA}"),
+    ("uacute;", "\u{00FA}"),
+    ("uarr;", "\u{2191}"),
+    ("ubrcy;", "\u{045E}"),
+    ("ubreve;", "\u{016D}"),
+    ("ucirc", "\u{00FB}"),
+    ("ucirc;", "\u{00FB}"),
+    ("ucy;", "\u{0443}"),
+    ("udarr;", "\u{21C5}"),
+    ("udblac;", "\u{0171}"),
+    ("udhar;", "\u{296E}"),
+    ("ufisht;", "\u{297E}"),
+    ("ufr;", "\u{1D532}"),
+    ("ugrave", "\u{00F9}"),
+    ("ugrave;", "\u{00F9}"),
+    ("uharl;", "\u{21BF}"),
+    ("uharr;", "\u{21BE}"),
+    ("uhblk;", "\u{2580}"),
+    ("ulcorn;", "\u{231C}"),
+    ("ulcorner;", "\u{231C}"),
+    ("ulcrop;", "\u{230F}"),
+    ("ultri;", "\u{25F8}"),
+    ("umacr;", "\u{016B}"),
+    ("uml", "\u{00A8}"),
+    ("uml;", "\u{00A8}"),
+    ("uogon;", "\u{0173}"),
+    ("uopf;", "\u{1D566}"),
+    ("uparrow;", "\u{2191}"),
+    ("updownarrow;", "\u{2195}"),
+    ("upharpoonleft;", "\u{21BF}"),
+    ("upharpoonright;", "\u{21BE}"),
+    ("uplus;", "\u{228E}"),
+    ("upsi;", "\u{03C5}"),
+    ("upsih;", "\u{03D2}"),
+    ("upsilon;", "\u{03C5}"),
+    ("upuparrows;", "\u{21C8}"),
+    ("urcorn;", "\u{231D}"),
+    ("urcorner;", "\u{231D}"),
+    ("urcrop;", "\u{230E}"),
+    ("uring;", "\u{016F}"),
+    ("urtri;", "\u{25F9}"),
+    ("uscr;", "\u{1D4CA}"),
+    ("utdot;", "\u{22F0}"),
+    ("utilde;", "\u{0169}"),
+    ("utri;", "\u{25B5}"),
+    ("utrif;", "\u{25B4}"),
+    ("uuarr;", "\u{21C8}"),
+    ("uuml", "\u{00FC}"),
+    ("uuml;", "\u{00FC}"),
+    ("uwangle;", "\u{29A7}"),
+    ("vArr;", "\u{21D5}"),
+    ("vBar;", "\u{2AE8}"),
+    ("vBarv;", "\u{2AE9}"),
+    ("vDash;", "\u{22A8}"),
+    ("vangrt;", "\u{299C}"),
+    ("varepsilon;", "\u{03F5}"),
+    ("varkappa;", "\u{03F0}"),
+    ("varnothing;", "\u{2205}"),
+    ("varphi;", "\u{03D5}"),
+    ("varpi;", "\u{03D6}"),
+    ("varpropto;", "\u{221D}"),
+    ("varr;", "\u{2195}"),
+    ("varrho;", "\u{03F1}"),
+    ("varsigma;", "\u{03C2}"),
+    ("varsubsetneq;", "\u{228A}\u{FE00}"),
+    ("varsubsetneqq;", "\u{2ACB}\u{FE00}"),
+    ("varsupsetneq;", "\u{228B}\u{FE00}"),
+    ("varsupsetneqq;", "\u{2ACC}\u{FE00}"),
+    ("vartheta;", "\u{03D1}"),
+    ("vartriangleleft;", "\u{22B2}"),
+    ("vartriangleright;", "\u{22B3}"),
+    ("vcy;", "\u{0432}"),
+    ("vdash;", "\u{22A2}"),
+    ("vee;", "\u{2228}"),
+    ("veebar;", "\u{22BB}"),
+    ("veeeq;", "\u{225A}"),
+    ("vellip;", "\u{22EE}"),
+    ("verbar;", "\u{007C}"),
+    ("vert;", "\u{007C}"),
+    ("vfr;", "\u{1D533}"),
+    ("vltri;", "\u{22B2}"),
+    ("vnsub;", "\u{2282}\u{20D2}"),
+    ("vnsup;", "\u{2283}\u{20D2}"),
+    ("vopf;", "\u{1D567}"),
+    ("vprop;", "\u{221D}"),
+    ("vrtri;", "\u{22B3}"),
+    ("vscr;", "\u{1D4CB}"),
+    ("vsubnE;", "\u{2ACB}\u{FE00}"),
+    ("vsubne;", "\u{228A}\u{FE00}"),
+    ("vsupnE;", "\u{2ACC}\u{FE00}"),
+    ("vsupne;", "\u{228B}\u{FE00}"),
+    ("vzigzag;", "\u{299A}"),
+    ("wcirc;", "\u{0175}"),
+    ("wedbar;", "\u{2A5F}"),
+    ("wedge;", "\u{2227}"),
+    ("wedgeq;", "\u{2259}"),
+    ("weierp;", "\u{2118}"),
+    ("wfr;", "\u{1D534}"),
+    ("wopf;", "\u{1D568}"),
+    ("wp;", "\u{2118}"),
+    ("wr;", "\u{2240}"),
+    ("wreath;", "\u{2240}"),
+    ("wscr;", "\u{1D4CC}"),
+    ("xcap;", "\u{22C2}"),
+    ("xcirc;", "\u{25EF}"),
+    ("xcup;", "\u{22C3}"),
+    ("xdtri;", "\u{25BD}"),
+    ("xfr;", "\u{1D535}"),
+    ("xhArr;", "\u{27FA}"),
+    ("xharr;", "\u{27F7}"),
+    ("xi;", "\u{03BE}"),
+    ("xlArr;", "\u{27F8}"),
+    ("xlarr;", "\u{27F5}"),
+    ("xmap;", "\u{27FC}"),
+    ("xnis;", "\u{22FB}"),
+    ("xodot;", "\u{2A00}"),
+    ("xopf;", "\u{1D569}"),
+    ("xoplus;", "\u{2A01}"),
+    ("xotime;", "\u{2A02}"),
+    ("xrArr;", "\u{27F9}"),
+    ("xrarr;", "\u{27F6}"),
+    ("xscr;", "\u{1D4CD}"),
+    ("xsqcup;", "\u{2A06}"),
+    ("xuplus;", "\u{2A04}"),
+    ("xutri;", "\u{25B3}"),
+    ("xvee;", "\u{22C1}"),
+    ("xwedge;", "\u{22C0}"),
+    ("yacute", "\u{00FD}"),
+    ("yacute;", "\u{00FD}"),
+    ("yacy;", "\u{044F}"),
+    ("ycirc;", "\u{0177}"),
+    ("ycy;", "\u{044B}"),
+    ("yen", "\u{00A5}"),
+    ("yen;", "\u{00A5}"),
+    ("yfr;", "\u{1D536}"),
+    ("yicy;", "\u{0457}"),
+    ("yopf;", "\u{1D56A}"),
+    ("yscr;", "\u{1D4CE}"),
+    ("yucy;", "\u{044E}"),
+    ("yuml", "\u{00FF}"),
+    ("yuml;", "\u{00FF}"),
+    ("zacute;", "\u{017A}"),
+    ("zcaron;", "\u{017E}"),
+    ("zcy;", "\u{0437}"),
+    ("zdot;", "\u{017C}"),
+    ("zeetrf;", "\u{2128}"),
+    ("zeta;", "\u{03B6}"),
+    ("zfr;", "\u{1D537}"),
+    ("zhcy;", "\u{0436}"),
+    ("zigrarr;", "\u{21DD}"),
+    ("zopf;", "\u{1D56B}"),
+    ("zscr;", "\u{1D4CF}"),
+    ("zwj;", "\u{200D}"),
+    ("zwnj;", "\u{200C}"),
 ];
@@ -0,0 +1,264 @@
+use std::borrow::Cow;
+use std::fmt::Write;
+
+use crate::entity;
+
+/// The longest name of a legacy entity that may appear without a semicolon.
+const LONGEST_LEGACY_NAME: usize = 6;
+
+fn decode_named_entity(name: &str) -> Option<&'static str> {
+    entity::ENTITIES
+        .binary_search_by_key(&name, |t| t.0)
+        .map(|idx| entity::ENTITIES[idx].1)
+        .ok()
+}
+
+/// Parses the named reference at the start of `s`, which follows an
+/// ampersand. Returns the text it stands for and its length.
+///
+/// Like in browsers, the legacy entities such as `&amp` or `&not` don't need
+/// a semicolon, and are found even if more letters follow: `&notit;` is
+/// `¬it;`. In attribute values, that is not done when a letter, digit or `=`
+/// follows, so that URLs like `?a=1&copy=2` are kept as they are.
+fn named_reference(s: &str, in_attribute: bool) -> Option<(&'static str, usize)> {
+    let name_len = s
+        .find(|c: char| !c.is_ascii_alphanumeric())
+        .unwrap_or(s.len());
+    if name_len == 0 {
+        return None;
+    }
+    if s[name_len..].starts_with(';') {
+        if let Some(text) = decode_named_entity(&s[..=name_len]) {
+            return Some((text, name_len + 1));
+        }
+    }
+
+    let (text, len) = (1..=name_len.min(LONGEST_LEGACY_NAME))
+        .rev()
+        .find_map(|len| decode_named_entity(&s[..len]).map(|text| (text, len)))?;
+    if in_attribute && s[len..].starts_with(|c: char| c.is_ascii_alphanumeric() || c == '=') {
+        return None;
+    }
+    Some((text, len))
+}
+
+/// The characters that the bytes 0x80 to 0x9F stand for in Windows-1252. The
+/// five bytes that are undefined stand for the C1 control characters.
+pub(crate) const WINDOWS_1252: [char; 32] = [
+    '\u{20AC}', '\u{81}', '\u{201A}', '\u{192}', '\u{201E}', '\u{2026}', '\u{2020}', '\u{2021}',
+    '\u{2C6}', '\u{2030}', '\u{160}', '\u{2039}', '\u{152}', '\u{8D}', '\u{17D}', '\u{8F}',
+    '\u{90}', '\u{2018}', '\u{2019}', '\u{201C}', '\u{201D}', '\u{2022}', '\u{2013}', '\u{2014}',
+    '\u{2DC}', '\u{2122}', '\u{161}', '\u{203A}', '\u{153}', '\u{9D}', '\u{17E}', '\u{178}',
+];
+
+/// Parses the numeric reference at the start of `s`, which follows `&#`.
+/// Returns the character and the length of the reference. The semicolon at
+/// the end is optional.
+fn numeric_reference(s: &str) -> Option<(char, usize)> {
+    let (digits, radix, prefix_len) = match s.strip_prefix(['x', 'X']) {
+        Some(hex) => (hex, 16, 1),
+        None => (s, 10, 0),
+    };
+    let len = digits
+        .find(|c: char| !c.is_digit(radix))
+        .unwrap_or(digits.len());
+    if len == 0 {
+        return None;
+    }
+    // too large numbers saturate, they are out of range either way
+    let n = digits[..len].chars().fold(0u32, |n, digit| {
+        n.saturating_mul(radix)
+            .saturating_add(digit.to_digit(radix).unwrap())
+    });
+    let semicolon_len = usize::from(digits[len..].starts_with(';'));
+    Some((numeric_char(n), prefix_len + len + semicolon_len))
+}
+
+/// The character that a numeric reference to `n` stands for. As in browsers,
+/// references to 0x80 to 0x9F are taken to be Windows-1252, which many
+/// documents mistakenly use, and references to null, surrogates or numbers
+/// outside of Unicode become U+FFFD.
+fn numeric_char(n: u32) -> char {
+    match n {
+        0x80..=0x9F => WINDOWS_1252[n as usize - 0x80],
+        0 => char::REPLACEMENT_CHARACTER,
+        n => char::from_u32(n).unwrap_or(char::REPLACEMENT_CHARACTER),
+    }
+}
+
+/// Replaces the named and numeric character references in `s`, like `&amp;` or
+/// `&#8212;`, with the characters they stand for. Text that isn't a valid
+/// reference is kept as it is.
+///
+/// References are recognized like browsers do in text, including the legacy
+/// ones that may appear without a semicolon.
+///
+/// The text is only copied if it contains a reference.
+///
+/// ```
+/// use nanohtml2text::decode_entities;
+///
+/// assert_eq!(decode_entities("Tom &amp; Jerry &#8212; &copy; 1940"), "Tom & Jerry — © 1940");
+/// assert_eq!(decode_entities("R&D &notit; &nvlt;"), "R&D ¬it; <\u{20D2}");
+/// ```
+pub fn decode_entities(s: &str) -> Cow<'_, str> {
+    decode(s, false)
+}
+
+/// Like [`decode_entities`], but follows the rules for attribute values.
+pub(crate) fn decode_attribute(s: &str) -> Cow<'_, str> {
+    decode(s, true)
+}
+
+fn decode(s: &str, in_attribute: bool) -> Cow<'_, str> {
+    let mut out = String::new();
+    // the end of the text that has been decoded into `out`
+    let mut decoded = 0;
+    let mut search = 0;
+    while let Some(i) = s[search..].find('&').map(|i| search + i) {
+        let rest = &s[i + 1..];
+        let mut buffer = [0; 4];
+        let reference = match rest.strip_prefix('#') {
+            Some(number) => {
+                numeric_reference(number).map(|(c, len)| (&*c.encode_utf8(&mut buffer), len + 1))
+            }
+            None => named_reference(rest, in_attribute),
+        };
+        search = i + 1;
+        if let Some((text, len)) = reference {
+            out.push_str(&s[decoded..i]);
+            out.push_str(text);
+            decoded = i + 1 + len;
+            search = decoded;
+        }
+    }
+
+    if decoded == 0 {
+        return Cow::Borrowed(s);
+    }
+    out.push_str(&s[decoded..]);
+    Cow::Owned(out)
+}
+
+/// Which characters [`encode_entities`] replaces with character references.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum EncodeMode {
+    /// Only `&`, `<` and `>`, which is enough for text between tags.
+    Minimal,
+    /// Also `"` and `'`, so that the text can be used as an attribute value
+    /// in either kind of quotes.
+    Attribute,
+    /// Like `Attribute`, and additionally every character outside of ASCII
+    /// is replaced with a numeric reference, for documents that have to be
+    /// ASCII.
+    Ascii,
+}
+
+/// Escapes `s` so that it can be inserted into HTML, the inverse of
+/// [`decode_entities`]. The text is only copied if something has to be
+/// replaced.
+///
+/// ```
+/// use nanohtml2text::{encode_entities, EncodeMode};
+///
+/// assert_eq!(encode_entities("a < b & c", EncodeMode::Minimal), "a &lt; b &amp; c");
+/// assert_eq!(encode_entities("\"Tom's\"", EncodeMode::Attribute), "&quot;Tom&#39;s&quot;");
+/// assert_eq!(encode_entities("café ☕", EncodeMode::Ascii), "caf&#xE9; &#x2615;");
+/// ```
+pub fn encode_entities(s: &str, mode: EncodeMode) -> Cow<'_, str> {
+    let needs_escape = |c: char| match c {
+        '&' | '<' | '>' => true,
+        '"' | '\'' => mode != EncodeMode::Minimal,
+        c => mode == EncodeMode::Ascii && !c.is_ascii(),
+    };
+    let start = match s.find(needs_escape) {
+        Some(start) => start,
+        None => return Cow::Borrowed(s),
+    };
+
+    let mut out = String::with_capacity(s.len() + 16);
+    out.push_str(&s[..start]);
+    for c in s[start..].chars() {
+        match c {
+            '&' => out.push_str("&amp;"),
+            '<' => out.push_str("&lt;"),
+            '>' => out.push_str("&gt;"),
+            '"' if needs_escape(c) => out.push_str("&quot;"),
+            '\'' if needs_escape(c) => out.push_str("&#39;"),
+            c if needs_escape(c) => {
+                write!(out, "&#x{:X};", u32::from(c)).expect("writing to a String never fails")
+            }
+            c => out.push(c),
+        }
+    }
+    Cow::Owned(out)
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn decode_borrows() {
+        assert!(matches!(decode_entities("no references"), Cow::Borrowed(_)));
+        assert!(matches!(decode_entities("R&D & more"), Cow::Borrowed(_)));
+        assert!(matches!(decode_entities("&lt;"), Cow::Owned(_)));
+    }
+
+    #[test]
+    fn named_references() {
+        for &(html, text) in &[
+            ("&amp done", "& done"),
+            ("&AMP;&amp;&ampx", "&&&x"),
+            ("&notit; &notin; &not;", "¬it; ∉ ¬"),
+            ("&NotEqualTilde;", "\u{2242}\u{338}"),
+            ("&hellip &hellip;", "&hellip …"),
+            ("&unknown; &; & &#; &#x;", "&unknown; &; & &#; &#x;"),
+            ("&#65&#x42;&#X43", "ABC"),
+        ] {
+            assert_eq!(decode_entities(html), text, "{}", html);
+        }
+    }
+
+    #[test]
+    fn numeric_references() {
+        for &(html, text) in &[
+            ("&#150; &#147;quoted&#148; &#x80;", "– “quoted” €"),
+            ("&#129;&#x9d;", "\u{81}\u{9D}"),
+            ("&#0;&#xD800;&#x110000;", "\u{FFFD}\u{FFFD}\u{FFFD}"),
+            ("&#99999999999999999999;", "\u{FFFD}"),
+            ("&#xFFFF;&#127;", "\u{FFFF}\u{7F}"),
+            ("a&#32;b&#9;c&#x0A;d&#13;", "a b\tc\nd\r"),
+        ] {
+            assert_eq!(decode_entities(html), text, "{}", html);
+        }
+    }
+
+    #[test]
+    fn attribute_values() {
+        assert_eq!(decode_attribute("?a=1&copy=2&not3"), "?a=1&copy=2&not3");
+        assert_eq!(decode_attribute("&copy;&copy &amp"), "©© &");
+        assert_eq!(decode_entities("?a=1&copy=2"), "?a=1©=2");
+    }
+
+    #[test]
+    fn round_trip() {
+        let text = "<a href=\"x\">Tom & 'Jerry'</a> — naïve 🦀";
+        for &mode in &[
+            EncodeMode::Minimal,
+            EncodeMode::Attribute,
+            EncodeMode::Ascii,
+        ] {
+            assert_eq!(decode_entities(&encode_entities(text, mode)), text);
+        }
+        assert!(encode_entities(text, EncodeMode::Ascii).is_ascii());
+        assert_eq!(
+            encode_entities(text, EncodeMode::Minimal),
+            "&lt;a href=\"x\"&gt;Tom &amp; 'Jerry'&lt;/a&gt; — naïve 🦀"
+        );
+        assert!(matches!(
+            encode_entities("plain \"text\"", EncodeMode::Minimal),
+            Cow::Borrowed(_)
+        ));
+    }
+}
@@ -1,223 +1,248 @@
+mod attributes;
+mod encoding;
 mod entity;
+mod escape;
+mod link;
+mod list;
+mod markdown;
+#[cfg(feature = "mime")]
+mod mime;
+mod options;
+mod render;
+mod stream;
+mod table;
+mod url;
+mod width;
+mod wrap;
 
-fn decode_named_entity(entity: &str) -> Option<char> {
-    entity::ENTITIES
-        .binary_search_by_key(&entity, |t| t.0)
-        .map(|idx| entity::ENTITIES[idx].1)
-        .ok()
+pub use attributes::{attributes, Attribute, Attributes};
+pub use encoding::Encoding;
+pub use escape::{decode_entities, encode_entities, EncodeMode};
+pub use link::Link;
+pub use options::{BlockSeparator, ImageStyle, LineEnding, LinkStyle, Options, OutputFormat};
+use render::{Renderer, Scratch};
+use std::fmt;
+use std::io;
+use std::sync::{Mutex, OnceLock};
+pub use stream::StreamConverter;
+
+/// Converts HTML to plain text according to a set of [`Options`].
+///
+/// [`html2text`] is a shorthand for converting with the default options.
+///
+/// A converter keeps the buffers it needs from one conversion to the next, so
+/// reusing a converter for many conversions avoids allocating them every
+/// time.
+#[derive(Default)]
+pub struct Converter {
+    options: Options,
+    /// The buffers of the last conversion.
+    scratch: Mutex<Scratch>,
 }
 
-fn parse_html_entity(ent_name: &str) -> Option<char> {
-    let d = decode_named_entity(ent_name);
-    if d.is_some() {
-        return d;
+impl Clone for Converter {
+    fn clone(&self) -> Self {
+        Converter::new(self.options.clone())
     }
+}
 
-    let num = ent_name.strip_prefix("#")?;
-    if num.chars().next()? == 'x' {
-        u32::from_str_radix(&num[1..].to_lowercase(), 16)
-    } else {
-        // remaining string may be empty, but that will generate an Err(Empty)
-        u32::from_str_radix(num, 10)
+impl fmt::Debug for Converter {
+    fn fmt(&self, f: &mut fmt::Formatter<'_>) -> fmt::Result {
+        f.debug_struct("Converter")
+            .field("options", &self.options)
+            .finish_non_exhaustive()
     }
-    .ok()
-    .filter(|n| !matches!(n, 9 | 10 | 13 | 32))
-    .and_then(|n| char::from_u32(n))
 }
 
-fn html_entitities_to_text(s: &str) -> String {
-    let mut out = String::new();
-
-    // except for the first part, every part will have started with an ampersand
-    // thus the start of the remaining parts is a HTML entity
-    let mut parts = s.split('&');
-    /*
-    skip first part. if the string started with an ampersand, the first part
-    will be an empty string
-
-    if the string was empty, the first part will also be an empty string so its
-    safe to unwrap
-    */
-    out.push_str(parts.next().unwrap());
-
-    for part in parts {
-        let end = part
-            // entity can be terminated by semicolon or whitespace
-            .find(|c: char| c.is_whitespace() || c == ';')
-            // entity can also terminated by end of string or start of
-            // another entity
-            .unwrap_or_else(|| part.len());
-        if let Some(entity) = parse_html_entity(&part[..end]) {
-            out.push(entity);
-            // get byte length of the char we did `find` above
-            let real_end = if let Some(next) = &part[end..].chars().next() {
-                end + next.len_utf8()
-            } else {
-                // invalid html entity that doesn't end with `;`
-                end
-            };
-
-            out.push_str(&part[real_end..]);
-        } else {
-            out.push('&');
-            out.push_str(part);
+impl Converter {
+    /// Creates a converter using the given options.
+    pub fn new(options: Options) -> Self {
+        Converter {
+            options,
+            scratch: Mutex::default(),
         }
     }
 
-    out
-}
+    /// The options used by this converter.
+    pub fn options(&self) -> &Options {
+        &self.options
+    }
 
-/// Function to parse and handle the individual tags.
-/// Assumes that there was a '<' before the given string
-///
-/// Returns the generated text and the byte length to skip.
-fn handle_tag(s: &str) -> (String, usize) {
-    let (tag, more) = match s.split_once('>') {
-        Some((tag, more)) if !tag.is_empty() => (tag, more),
-        _ => {
-            // was not actually a tag, so reinsert the '<'
-            return (String::from("<"), 0);
-        }
-    };
-
-    let (name, attribs) = if let Some((name, attribs)) = tag.split_once(char::is_whitespace) {
-        (name, Some(attribs))
-    } else {
-        (tag, None)
-    };
-
-    match name.to_lowercase().as_str() {
-        "a" => {
-            let href = attribs
-                .and_then(|attribs| {
-                    Some(
-                        attribs
-                            // check for the href and then discard everything before it
-                            .split_once("href")?
-                            .1
-                            // there might be whitespace between 'href' and '='
-                            .trim_start()
-                            // check for and then discard the equal sign
-                            .strip_prefix('=')?
-                            // remove whitespace after the equal sign
-                            .trim_start(),
-                    )
-                })
-                .and_then(|href_value|
-                    // find quoted string
-                    match href_value.chars().next()? {
-                        start @ '\'' | start @ '"' => {
-                            let (end, _) = href_value
-                                .char_indices()
-                                .skip(1)
-                                .find(|(_, c)| *c == start)?;
-                            Some(href_value[1..end].to_string())
-                        }
-                        _ => None,
-                    })
-                .filter(|href| !href.starts_with("javascript:"))
-                .map(|href| html_entitities_to_text(&href));
-            // only use to_ascii_lowercase here so the byte offsets dont get
-            // messed up from one uppercase symbol becoming two lowercase
-            // symbols or something like that
-            let more = more.to_ascii_lowercase();
-
-            let end_without_closing = more.find("</a");
-            let content = end_without_closing.map(|i| more[0..i].trim());
-
-            let end = end_without_closing
-                .map(|i| i + 3)
-                .and_then(|end_tag| more[end_tag..].find('>').map(|i| end_tag + i + 1))
-                .unwrap_or_else(|| more.len());
-
-            let link = match (content, href) {
-                (Some(content_value), Some(href_value)) => {
-                    if content_value == href_value {
-                        href_value
-                    } else {
-                        let cleaned_content_value = html2text(content_value);
-                        format!("{} ({})", cleaned_content_value, href_value)
-                    }
-                }
-                (None, Some(href_value)) => href_value,
-                (Some(content_value), None) => content_value.to_string(),
-                (None, None) => "".to_string(),
-            };
+    /// Convert some HTML to plain text. Only some simple HTML tags are handled:
+    /// - `a` tags are rendered according to the [`LinkStyle`]
+    /// - `img` tags are rendered as their alt text according to the
+    ///   [`ImageStyle`]
+    /// - paragraph, linebreak, heading, list, and list item tags insert different
+    ///   amounts of line breaks.
+    /// - items of ordered lists start with their number, those of unordered
+    ///   lists with the [bullet](Options::bullet) if one is set
+    /// - tables are laid out as a grid with aligned columns, except for tables
+    ///   that only lay out the page, see [`Options::detect_layout_tables`]
+    /// - HTML comments as well as silenced elements (by default `head`, `script`
+    ///   and `style`) are completely discarded, including their content
+    /// - unknown tags are skipped, but their content is printed
+    ///
+    /// HTML named entities will be replaced with the respecive Unicode code point,
+    /// and whitespace will be collapsed as is usual in HTML. Inside `pre` and
+    /// `textarea` elements, as well as elements styled with `white-space: pre`,
+    /// spaces and line breaks are kept as they are. `pre` blocks are separated
+    /// from the surrounding text like paragraphs.
+    ///
+    /// Line breaks are inserted using the configured [`LineEnding`], so the
+    /// output never needs to be post-processed to change them. If a wrap width is
+    /// set, lines are wrapped, except for preformatted text.
+    ///
+    /// With [`OutputFormat::Markdown`], headings, emphasis, lists, `pre` and
+    /// `blockquote` elements are turned into the corresponding Markdown syntax
+    /// instead.
+    pub fn convert(&self, html: &str) -> String {
+        let mut text = String::with_capacity(html.len() / 2);
+        self.convert_into(html, &mut text)
+            .expect("writing to a String never fails");
+        text
+    }
 
-            (link, tag.len() + 1 + end)
-        }
-        "br" | "br/" | "li" | "/ol" | "/ul" => (String::from("\r\n"), tag.len() + 1),
-        "p" | "h1" | "h2" | "h3" | "h4" | "h5" | "h6" | "/h1" | "/h2" | "/h3" | "/h4" | "/h5"
-        | "/h6" => (String::from("\r\n\r\n"), tag.len() + 1),
-        name @ "head" | name @ "script" | name @ "style" => {
-            // silence tags
-
-            // only use to_ascii_lowercase here so the byte offsets dont get
-            // messed up from one uppercase symbol becoming two lowercase
-            // symbols or something like that
-            let more = more.to_ascii_lowercase();
-            let end = more
-                .find(&format!("</{}", name))
-                .map(|i| i + 2 + name.len())
-                .and_then(|end_tag| more[end_tag..].find('>').map(|i| i + end_tag + 1))
-                .unwrap_or_else(|| more.len());
-            (String::new(), tag.len() + 1 + end)
-        }
-        "!--" => {
-            // HTML comment
-            (String::new(), s.find("-->").map_or(s.len(), |n| n + 3))
+    /// Like [`convert`](Converter::convert), but writes the text to `sink`
+    /// instead of returning a new `String`.
+    ///
+    /// ```
+    /// use nanohtml2text::Converter;
+    ///
+    /// let converter = Converter::default();
+    /// let mut text = String::new();
+    /// for html in ["<p>one</p>", "<p>two</p>"] {
+    ///     text.clear();
+    ///     converter.convert_into(html, &mut text).unwrap();
+    ///     assert!(text.len() == 3);
+    /// }
+    /// ```
+    pub fn convert_into<W: fmt::Write>(&self, html: &str, sink: &mut W) -> fmt::Result {
+        // when the converter is used by several threads at once, the buffers
+        // may be taken already
+        let scratch = match self.scratch.try_lock() {
+            Ok(mut scratch) => std::mem::take(&mut *scratch),
+            Err(_) => Scratch::default(),
+        };
+        let (result, scratch) =
+            Renderer::with_scratch(&self.options, scratch).render_into(html, sink);
+        if let Ok(mut previous) = self.scratch.try_lock() {
+            *previous = scratch;
         }
-        // other/unknown tags are just discarded
-        _ => (String::new(), tag.len() + 1),
+        result
+    }
+
+    /// Converts HTML given as bytes, whose encoding is detected as described
+    /// in [`Encoding::detect`].
+    ///
+    /// ```
+    /// use nanohtml2text::Converter;
+    ///
+    /// let html = b"<meta charset=iso-8859-1><p>\xA9 Caf\xE9</p>";
+    /// assert_eq!(Converter::default().convert_bytes(html), "© Café");
+    /// ```
+    pub fn convert_bytes(&self, html: &[u8]) -> String {
+        self.convert(&Encoding::detect(html).decode(html))
+    }
+
+    /// Converts HTML that is passed in chunks, writing the text to `writer`
+    /// as the conversion goes on. See [`StreamConverter`].
+    pub fn stream<W: fmt::Write>(&self, writer: W) -> StreamConverter<'_, W> {
+        StreamConverter::new(&self.options, writer)
+    }
+
+    /// Converts UTF-8 encoded HTML read from `reader` and writes the text to
+    /// `writer`, without reading all of the HTML into memory. Invalid UTF-8 is
+    /// replaced with U+FFFD.
+    ///
+    /// Unlike [`convert_bytes`](Converter::convert_bytes), this doesn't detect
+    /// the encoding, and `meta` elements declaring one are ignored. HTML in
+    /// other encodings has to be decoded first, e.g. with [`Encoding::decode`].
+    ///
+    /// ```
+    /// use nanohtml2text::Converter;
+    ///
+    /// let html = "<ul><li>one</li><li>two</li></ul>";
+    /// let mut text = Vec::new();
+    /// Converter::default()
+    ///     .convert_reader(html.as_bytes(), &mut text)
+    ///     .unwrap();
+    /// assert_eq!(text, b"one\r\ntwo\r\n");
+    /// ```
+    pub fn convert_reader<R: io::Read, W: io::Write>(
+        &self,
+        reader: R,
+        writer: W,
+    ) -> io::Result<()> {
+        stream::convert_reader(&self.options, reader, writer)
+    }
+
+    /// Finds all `a` elements in the HTML, in the order they appear in.
+    ///
+    /// Hrefs are resolved against the [base URL](Options::base_url) like they
+    /// would be when converting. Links inside silenced elements and comments
+    /// are left out.
+    ///
+    /// ```
+    /// use nanohtml2text::{Converter, Link};
+    ///
+    /// let html = "<p>Read <a href=\"/docs?a=1&amp;b=2\" title=\"The docs\">the docs</a></p>";
+    /// assert_eq!(
+    ///     Converter::default().links(html),
+    ///     [Link {
+    ///         text: "the docs".into(),
+    ///         href: Some("/docs?a=1&b=2".into()),
+    ///         title: Some("The docs".into()),
+    ///         offset: 8,
+    ///     }]
+    /// );
+    /// ```
+    pub fn links(&self, html: &str) -> Vec<Link> {
+        // the text of links should not contain any markup
+        let options = self.options.clone().format(OutputFormat::PlainText);
+        Renderer::new(&options).links(html)
     }
 }
 
-/// Convert some HTML to plain text. Only some simple HTML tags are handled:
-/// - `a` tags are transformed to their href attribute value
-/// - paragraph, linebreak, heading, list, and list item tags insert different
-///   amounts of line breaks.
-/// - HTML comments as well as `head`, `script` and `style` are completely
-///   discarded, including their content
-/// - unknown tags are skipped, but their content is printed
-///
-/// HTML named entities will be replaced with the respecive Unicode code point,
-/// and whitespace will be collapsed as is usual in HTML.
+/// Convert some HTML to plain text using the default [`Options`]. See
+/// [`Converter::convert`] for the handled HTML.
 ///
 /// The resulting string will have CRLF line endings.
 pub fn html2text(html: &str) -> String {
-    // collapse spaces
-    let html = html.split_whitespace().collect::<Vec<_>>().join(" ");
-
-    let mut out = String::new();
-
-    let mut i = 0;
-    while i < html.len() {
-        match html[i..].find('<') {
-            None => {
-                // no more tags in the input, done
-                out += &html_entitities_to_text(&html[i..]);
-                break;
-            }
-            Some(text_segment) => {
-                if text_segment > 0 {
-                    out += &html_entitities_to_text(&html[i..i + text_segment]);
-                    i += text_segment;
-                }
-                i += 1; // skip the '<'
-                let (s, advance) = handle_tag(&html[i..]);
-                if !s.is_empty() {
-                    if out.ends_with("\r\n\r\n") || out.is_empty() {
-                        out += &s.trim_start();
-                    } else {
-                        out += &s;
-                    }
-                }
-                i += advance;
-            }
-        }
-    }
+    let mut text = String::with_capacity(html.len() / 2);
+    html2text_into(html, &mut text).expect("writing to a String never fails");
+    text
+}
+
+/// Like [`html2text`], but takes the HTML as bytes in any of the supported
+/// [`Encoding`]s, see [`Converter::convert_bytes`].
+pub fn html2text_bytes(html: &[u8]) -> String {
+    Converter::default().convert_bytes(html)
+}
+
+/// Converts a raw email message to text with the default options, see
+/// [`Converter::convert_email`]. Requires the `mime` feature.
+#[cfg(feature = "mime")]
+pub fn email2text(message: &[u8]) -> String {
+    Converter::default().convert_email(message)
+}
+
+/// Like [`html2text`], but writes the text to `sink` instead of returning a
+/// new `String`.
+///
+/// This and [`html2text`] share one [`Converter`] with the default options, so
+/// they reuse its buffers from one call to the next. With other options, keep a
+/// `Converter` around instead of creating one per call.
+pub fn html2text_into<W: fmt::Write>(html: &str, sink: &mut W) -> fmt::Result {
+    static CONVERTER: OnceLock<Converter> = OnceLock::new();
+    CONVERTER
+        .get_or_init(Converter::default)
+        .convert_into(html, sink)
+}
 
-    out
+/// Finds all `a` elements in some HTML using the default [`Options`]. See
+/// [`Converter::links`].
+pub fn links(html: &str) -> Vec<Link> {
+    Converter::default().links(html)
 }
 
 #[cfg(test)]
@@ -259,6 +284,23 @@ mod tests {
         link_ignore_content_tags:
             "click <a href=\"test\"><span>here</span> or here</a>"
             to "click here or here (test)",
+        link_similar_attributes:
+            "<a data-href=\"x\" hreflang=\"en\" href=\"test\">here</a>"
+            to "here (test)",
+        link_unquoted_href:
+            "<a HREF=test?a=1&amp;b=2 target=_blank>here</a>"
+            to "here (test?a=1&b=2)",
+        ordered_list_unquoted_attributes:
+            "<ol start=3 reversed><li>a<li>b</ol>"
+            to "3. a\r\n2. b\r\n",
+        link_quoted_angle_bracket:
+            "<a title=\"a > b\" href=\"x\" style='content: \">\"'>here</a> <p don't>ok</p>"
+            to "here (x)\r\n\r\nok",
+        link_unterminated_quote:
+            "<a href=\"x>here</a> more"
+            to "here (x) more",
+        stray_angle_brackets:
+            "1 < 2 <b and <x &lt;y" to "1 < 2 <b and <x <y",
         link_absolute_url:
             "click <a href=\"http://bit.ly/2n4wXRs\">news</a>"
             to "click news (http://bit.ly/2n4wXRs)",
@@ -279,6 +321,8 @@ mod tests {
             "a\nb\nc" to "a b c",
         collapse_mixed:
             "should \nignore \r\nnew lines" to "should ignore new lines",
+        collapse_across_tags:
+            "a <b> c </b> d" to "a c d",
         br_tag:
             "two<br>line<br/>breaks" to "two\r\nline\r\nbreaks",
         paragraph:
@@ -313,6 +357,45 @@ mod tests {
             "<h6>Sixth</h6>next section" to "Sixth\r\n\r\nnext section",
         no_h7:
             "<h7>Not Header</h7>next section" to "Not Headernext section",
+        // preformatted text
+        pre:
+            "<p>code:</p><pre>\n  fn main() {\n\n      x &amp; y\n  }\n</pre><p>after</p>"
+            to "code:\r\n\r\n  fn main() {\r\n\r\n      x & y\r\n  }\r\n\r\nafter",
+        pre_inline:
+            "before<pre>a  b</pre>after" to "before\r\n\r\na  b\r\n\r\nafter",
+        pre_nested_tags:
+            "<PRE><b>bold</b>   <i>x</i>\r\ny</PRE>" to "bold   x\r\ny\r\n\r\n",
+        textarea:
+            "a <textarea>\nx\n  y</textarea> b" to "a x\r\n  y b",
+        white_space_pre_style:
+            "<div style=\"color: red; white-space: pre\">a  b\n<div>c</div>  d</div>e   f"
+            to "a  b\r\nc  de f",
+        white_space_normal_style:
+            "<div style=\"white-space: normal\">a  b\nc</div>" to "a b c",
+        // tables
+        table:
+            "<p>Receipt</p><table><tr><th>Item</th><th>Qty</th></tr><tr><td>Coffee</td><td>2</td></tr><tr><td colspan=\"2\">Total: 2 items</td></tr></table>Thanks"
+            to "Receipt\r\n\r\nItem    Qty\r\n------  ------\r\nCoffee  2\r\nTotal: 2 items\r\n\r\nThanks",
+        table_multiline_cells:
+            "<table><tr><td><b>x</b> y<br>z</td><td>w</td></tr></table>"
+            to "x y  w\r\nz\r\n\r\n",
+        table_nested:
+            "<table><tr><td><table><tr><td>a</td><td>b</td></tr></table></td><td>c</td></tr></table>"
+            to "a  b\r\n\r\nc\r\n\r\n",
+        table_layout_presentation:
+            "<table role=\"presentation\"><tr><td>a</td><td>b</td></tr></table>"
+            to "a\r\nb\r\n\r\n",
+        table_layout_single_column:
+            "<p>x</p><table><tr><td>a</td></tr><tr><td>b</td></tr></table><p>y</p>"
+            to "x\r\n\r\na\r\nb\r\n\r\ny",
+        table_layout_block_content:
+            "<table><tr><td><p>one</p><p>two</p></td><td>three</td></tr></table>"
+            to "one\r\n\r\ntwo\r\nthree\r\n\r\n",
+        table_unclosed:
+            "<table><tr><td>a<td>b" to "a  b\r\n\r\n",
+        table_huge_colspan:
+            "<table><tr><td colspan=4000000000>x</td><td>y</td></tr></table>"
+            to "x y\r\n\r\n",
         // html entitites
         entity_nbsp:
             "two&nbsp;&nbsp;spaces" to "two  spaces",
@@ -329,9 +412,45 @@ mod tests {
             "this &neither; as you see" to "this &neither; as you see",
         entity_amp:
             "fish &amp; chips" to "fish & chips",
+        entity_whitespace:
+            "a&#32;b&#x20;&#10;c" to "a b c",
+        entity_whitespace_pre:
+            "<pre>a&#9;b&#32;&#32;c</pre>" to "a\tb  c\r\n\r\n",
         unordered_list:
             "list of items<ul><li>One</li><li>Two</li><li>Three</li></ul>"
             to "list of items\r\nOne\r\nTwo\r\nThree\r\n",
+        ordered_list:
+            "<ol><li>One</li><li>Two</li></ol>" to "1. One\r\n2. Two\r\n",
+        ordered_list_start:
+            "<ol start=\"4\"><li>Four<li>Five</ol>" to "4. Four\r\n5. Five\r\n",
+        ordered_list_reversed:
+            "<ol reversed><li>Three<li>Two<ol><li>nested</ol><li>One</ol>"
+            to "3. Three\r\n2. Two\r\n  1. nested\r\n1. One\r\n",
+        ordered_list_reversed_nested:
+            "<ol reversed><li>b<ol reversed><li>y<li>x</ol><li>a<ol reversed><li>z</ol></ol>"
+            to "2. b\r\n  2. y\r\n  1. x\r\n1. a\r\n  1. z\r\n",
+        ordered_list_reversed_start:
+            "<ol reversed start=\"10\"><li>Ten<li>Nine</ol>" to "10. Ten\r\n9. Nine\r\n",
+        ordered_list_huge_start:
+            "<ol start=9223372036854775807><li>a<li>b</ol>"
+            to "9223372036854775807. a\r\n9223372036854775807. b\r\n",
+        ordered_list_value:
+            "<ol><li>One<li value=\"7\">Seven<li>Eight</ol>"
+            to "1. One\r\n7. Seven\r\n8. Eight\r\n",
+        ordered_list_alpha:
+            "<ol type=\"a\"><li>a<li>b</ol><ol type=\"A\" start=\"27\"><li>AA</ol>"
+            to "a. a\r\nb. b\r\nAA. AA\r\n",
+        ordered_list_roman:
+            "<ol type=\"i\" start=\"3\"><li>x<li>y</ol><ol type=\"I\" start=\"9\"><li>z</ol>"
+            to "iii. x\r\niv. y\r\nIX. z\r\n",
+        nested_lists:
+            "<ul><li>A<ul><li>B<ol><li>C</li></ol></li></ul>after A</li><li>D</li></ul>"
+            to "A\r\n  B\r\n    1. C\r\nafter A\r\nD\r\n",
+        list_item_continuation:
+            "<ol><li>first line<br>second line<p>paragraph</p></li></ol>"
+            to "1. first line\r\n   second line\r\n\r\n   paragraph\r\n",
+        list_empty_item:
+            "<ol><li></li><li>Two</li></ol>" to "2. Two\r\n",
         entity_quot:
             "&quot;I'm sorry, Dave. I'm afraid I can't do that.&quot; – HAL, 2001: A Space Odyssey"
             to "\"I'm sorry, Dave. I'm afraid I can't do that.\" – HAL, 2001: A Space Odyssey",
@@ -361,6 +480,529 @@ mod tests {
             "<aa >hello</aa>" to "hello",
         ignore_unknown_tag_attributes:
             "<aa x=\"1\">hello</aa>" to "hello",
-        invalid_html_entity_without_semicolon: "&hellip" to "…",
+        invalid_html_entity_without_semicolon: "&hellip &amp" to "&hellip &",
+    }
+
+    fn convert_with(options: Options, html: &str) -> String {
+        Converter::new(options).convert(html)
+    }
+
+    #[test]
+    fn option_line_ending_lf() {
+        assert_eq!(
+            convert_with(
+                Options::new().line_ending(LineEnding::Lf),
+                "<h1>Title</h1>one<br>two"
+            ),
+            "Title\n\none\ntwo"
+        );
+    }
+
+    #[test]
+    fn option_line_ending_lf_collapses_breaks() {
+        let options = Options::new().line_ending(LineEnding::Lf);
+        assert_eq!(
+            convert_with(options.clone(), "a<br><br><p>b</p><h2>c</h2>"),
+            "a\n\nb\n\nc\n\n"
+        );
+        assert_eq!(convert_with(options, "<p>start</p>"), "start");
+    }
+
+    #[test]
+    fn option_line_ending_native() {
+        let expected = if cfg!(windows) { "a\r\nb" } else { "a\nb" };
+        assert_eq!(
+            convert_with(Options::new().line_ending(LineEnding::Native), "a<br>b"),
+            expected
+        );
+    }
+
+    #[test]
+    fn option_link_text_only() {
+        assert_eq!(
+            convert_with(
+                Options::new().link_style(LinkStyle::TextOnly),
+                "click <a href=\"test\">here</a>"
+            ),
+            "click here"
+        );
+    }
+
+    #[test]
+    fn option_link_footnote() {
+        let options = Options::new()
+            .line_ending(LineEnding::Lf)
+            .link_style(LinkStyle::Footnote);
+        assert_eq!(
+            convert_with(
+                options.clone(),
+                "<ul><li><a href=\"https://a.example/x?utm=1\">one</a> and \
+                 <a href=\"https://b.example\">two</a></li>\
+                 <li><a href=\"https://a.example/x?utm=1\">again</a> \
+                 <a href=\"https://c.example\">https://c.example</a></li>"
+            ),
+            "one[1] and two[2]\n\
+             again[1] https://c.example\n\
+             \n\
+             [1]: https://a.example/x?utm=1\n\
+             [2]: https://b.example"
+        );
+        assert_eq!(
+            convert_with(
+                options.clone().wrap_width(10),
+                "<a href=\"https://a.example/long\">a b c d e</a>"
+            ),
+            "a b c d\ne[1]\n\n[1]: https://a.example/long"
+        );
+        assert_eq!(
+            convert_with(
+                options.format(OutputFormat::Markdown),
+                "<a href=\"/a (b)\">one</a> <a href=\"/c\">two</a> <a href=\"/a (b)\">three</a>"
+            ),
+            "[one][1] [two][2] [three][1]\n\n[1]: /a%20%28b%29\n[2]: /c"
+        );
+    }
+
+    #[test]
+    fn option_base_url() {
+        let html = "<a href=\"/wiki/yet#English\">yet</a>, <a href=\"//cdn.example/x\">x</a>";
+        assert_eq!(
+            convert_with(
+                Options::new().base_url("https://en.wiktionary.org/wiki/Main"),
+                html
+            ),
+            "yet (https://en.wiktionary.org/wiki/yet#English), x (https://cdn.example/x)"
+        );
+        let html = "<html><head><title>t</title><base target=\"_blank\">\
+                    <BASE href=\"docs/\"></head><body><a href=\"../a?b&amp;c\">a</a></body>";
+        assert_eq!(
+            convert_with(Options::new().base_url("https://x.y/v1/"), html),
+            "a (https://x.y/v1/a?b&c)"
+        );
+        assert_eq!(convert_with(Options::new(), html), "a (../a?b&c)");
+        assert_eq!(
+            convert_with(
+                Options::new(),
+                "<base href=\"https://x.y/\"><a href=\"z\">z</a><base href=\"https://q/\"><a href=\"z\">z</a>"
+            ),
+            "z (https://x.y/z)z (https://x.y/z)"
+        );
+        // tags in scripts and the like don't count
+        let html = "<head><script>let b = \"<base href='https://q/'>\";</SCRIPT >\
+                    <noscript><base href=https://q/></noscript><base href=\"https://x.y/\"></head>\
+                    <a href=\"z\">z</a>";
+        assert_eq!(convert_with(Options::new(), html), "z (https://x.y/z)");
+    }
+
+    #[test]
+    fn links() {
+        let html = "<head><a href=\"x\">no</a></head><p>A <a href=\"/a?b&amp;c\" title=\"T &amp; C\">first\n\
+                    <b>link</b></a><a name=\"top\">anchor<br>text</a> <A HREF='javascript:void(0)'>js</A>\
+                    <a href=\"https://x.y/\"><img src=\"i.png\"></a><a href=\"unclosed\">end";
+        let link = |text: &str, href: Option<&str>, title: Option<&str>, offset| Link {
+            text: text.into(),
+            href: href.map(String::from),
+            title: title.map(String::from),
+            offset,
+        };
+        let links = Converter::new(Options::new().base_url("https://x.y/")).links(html);
+        assert_eq!(
+            links,
+            [
+                link("first link", Some("https://x.y/a?b&c"), Some("T & C"), 36),
+                link("anchor text", None, None, 96),
+                link("js", None, None, 129),
+                link("", Some("https://x.y/"), None, 164),
+                link("end", Some("https://x.y/unclosed"), None, 208),
+            ]
+        );
+        for link in &links {
+            assert!(html[link.offset..].to_lowercase().starts_with("<a "));
+        }
+    }
+
+    #[test]
+    fn option_image_style() {
+        let html = "<p><img src=\"logo.png\" alt=\"ACME &amp; Co\"> news\
+                    <img src=\"spacer.gif\" alt=\"\"><img src=\"deco.gif\">\
+                    <img src=\"https://t.example/p\" alt=\"x\" width=\"1\" height=\"1px\"> \
+                    <a href=\"https://example.com/buy\"><img alt=\"Buy now\" src=\"/b.png\"></a></p>";
+        let options = Options::new().base_url("https://example.com/");
+        assert_eq!(
+            convert_with(options.clone(), html),
+            "ACME & Co news Buy now (https://example.com/buy)"
+        );
+        assert_eq!(
+            convert_with(options.clone().image_style(ImageStyle::Labeled), html),
+            "[image: ACME & Co] news [image: Buy now] (https://example.com/buy)"
+        );
+        assert_eq!(
+            convert_with(options.clone().image_style(ImageStyle::Omit), html),
+            "news https://example.com/buy"
+        );
+        assert_eq!(
+            convert_with(options.clone().image_sources(true), html),
+            "ACME & Co (https://example.com/logo.png) news \
+             Buy now (https://example.com/b.png) (https://example.com/buy)"
+        );
+        assert_eq!(
+            convert_with(
+                options
+                    .clone()
+                    .image_sources(true)
+                    .format(OutputFormat::Markdown),
+                html
+            ),
+            "![ACME & Co](https://example.com/logo.png) news \
+             [![Buy now](https://example.com/b.png)](https://example.com/buy)"
+        );
+        let options = options.format(OutputFormat::Markdown);
+        assert_eq!(
+            convert_with(options.clone().image_style(ImageStyle::Labeled), html),
+            "image: ACME & Co news [image: Buy now](https://example.com/buy)"
+        );
+        assert_eq!(
+            convert_with(
+                options.image_style(ImageStyle::Labeled).image_sources(true),
+                html
+            ),
+            "![ACME & Co](https://example.com/logo.png) news \
+             [![Buy now](https://example.com/b.png)](https://example.com/buy)"
+        );
+    }
+
+    #[test]
+    fn stream_split_anywhere() {
+        let html =
+            "<html><HEAD><title>x <base href=\"y\"></title><base href=\"https://x.y/\"></head>\
+                    <body><!-- a <p> comment --><!--><p>Tom &amp; Jerry&nbsp;&#x263a;</p>\
+                    <script type=\"text/javascript\">if (a </script> b) {}</script>\
+                    <a title=\"a > b\" href=\"/x\">link</a> &lt; 日本語 a < b\
+                    <pre>\r\n  keep\r\n  this</pre>\
+                    <ol reversed><li>a<ol reversed><li>x<li>y</ol><li>b</ol>\
+                    <table><tr><th>h1<th>h2<tr><td>1<td>2</table>\
+                    <table><tr><td><p>layout</p></table>&amp done";
+        let options = Options::new().line_ending(LineEnding::Lf);
+        let converter = Converter::new(options);
+        let expected = converter.convert(html);
+        for (i, _) in html.char_indices() {
+            let mut stream = converter.stream(String::new());
+            stream.push(&html[..i]).unwrap();
+            stream.push(&html[i..]).unwrap();
+            assert_eq!(stream.finish().unwrap(), expected, "split at {}", i);
+        }
+        let chars: Vec<char> = html.chars().collect();
+        for size in 1..8 {
+            let mut stream = converter.stream(String::new());
+            for chunk in chars.chunks(size) {
+                stream.push(&chunk.iter().collect::<String>()).unwrap();
+            }
+            assert_eq!(stream.finish().unwrap(), expected, "chunks of {}", size);
+        }
+    }
+
+    #[test]
+    fn stream_reader() {
+        let html = "<p>caf\u{e9} &eacute;</p>".repeat(2000);
+        // reads a few bytes at a time, cutting characters in half
+        struct Trickle<'a>(&'a [u8]);
+        impl std::io::Read for Trickle<'_> {
+            fn read(&mut self, buf: &mut [u8]) -> std::io::Result<usize> {
+                let n = self.0.len().min(buf.len()).min(7);
+                buf[..n].copy_from_slice(&self.0[..n]);
+                self.0 = &self.0[n..];
+                Ok(n)
+            }
+        }
+        let mut text = Vec::new();
+        Converter::default()
+            .convert_reader(Trickle(html.as_bytes()), &mut text)
+            .unwrap();
+        assert_eq!(String::from_utf8(text).unwrap(), html2text(&html));
+
+        let mut text = Vec::new();
+        Converter::default()
+            .convert_reader(&b"<p>a\xff\xe6</p>"[..], &mut text)
+            .unwrap();
+        assert_eq!(text, "a\u{fffd}\u{fffd}".as_bytes());
+    }
+
+    #[test]
+    fn convert_into() {
+        let mut text = String::from("before: ");
+        html2text_into("<p>Tom &amp; <b>Jerry</b></p>", &mut text).unwrap();
+        assert_eq!(text, "before: Tom & Jerry");
+
+        // the buffers of earlier conversions must not leak into later ones
+        let converter = Converter::new(Options::new().line_ending(LineEnding::Lf));
+        let documents = [
+            "<blockquote><ul><li>unfinished <b>list",
+            "<table><tr><td>a<td>b",
+            "<pre>  x",
+            "<p>plain</p>",
+        ];
+        for html in documents.iter().chain(&documents) {
+            let mut text = String::new();
+            converter.convert_into(html, &mut text).unwrap();
+            assert_eq!(text, converter.convert(html));
+            assert_eq!(
+                text,
+                Converter::new(converter.options.clone()).convert(html)
+            );
+        }
+    }
+
+    #[test]
+    fn convert_into_large() {
+        // large documents are written while they are converted
+        struct Writes(Vec<String>);
+        impl fmt::Write for Writes {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0.push(s.to_string());
+                Ok(())
+            }
+        }
+        let converter = Converter::default();
+        let html = "<p>caf\u{e9} &amp; <b>cr\u{e8}me</b></p>".repeat(50_000);
+        let mut writes = Writes(Vec::new());
+        converter.convert_into(&html, &mut writes).unwrap();
+        assert!(writes.0.len() > 1);
+        let mut stream = converter.stream(String::new());
+        stream.push(&html).unwrap();
+        assert_eq!(writes.0.concat(), stream.finish().unwrap());
+    }
+
+    #[test]
+    fn converter_is_shareable() {
+        fn assert_send_sync<T: Send + Sync>(_: &T) {}
+        let converter = Converter::default();
+        assert_send_sync(&converter);
+        std::thread::scope(|scope| {
+            for i in 0..4 {
+                let converter = &converter;
+                scope.spawn(move || {
+                    for _ in 0..100 {
+                        let html = format!("<ul><li>{}</li></ul>", i);
+                        assert_eq!(converter.convert(&html), format!("{}\r\n", i));
+                    }
+                });
+            }
+        });
+    }
+
+    #[test]
+    fn option_block_separator_newline() {
+        assert_eq!(
+            convert_with(
+                Options::new().block_separator(BlockSeparator::Newline),
+                "<p>two</p><p>paragraphs</p>"
+            ),
+            "two\r\nparagraphs"
+        );
+    }
+
+    #[test]
+    fn option_wrap_width() {
+        let options = Options::new().line_ending(LineEnding::Lf).wrap_width(20);
+        assert_eq!(
+            convert_with(
+                options,
+                "<h1>A heading that is too long</h1><p>see <a href=\"https://example.com/a/very/long/url\">this page</a> for more</p><ul><li>short</li><li>a list item that needs wrapping</li></ul>"
+            ),
+            "A heading that is\ntoo long\n\nsee this page\n(https://example.com/a/very/long/url)\nfor more\nshort\na list item that\nneeds wrapping\n"
+        );
+    }
+
+    #[test]
+    fn option_wrap_width_keeps_pre() {
+        let options = Options::new().line_ending(LineEnding::Lf).wrap_width(10);
+        assert_eq!(
+            convert_with(
+                options,
+                "<p>some text to wrap</p><pre>  a preformatted line</pre>"
+            ),
+            "some text\nto wrap\n\n  a preformatted line\n\n"
+        );
+    }
+
+    macro_rules! test_markdown {
+        ($($name:ident: $from:literal to $to:literal,)* $(,)?) => {
+            $(
+                #[test]
+                fn $name() {
+                    let options = Options::new()
+                        .format(OutputFormat::Markdown)
+                        .line_ending(LineEnding::Lf);
+                    assert_eq!(convert_with(options, $from), $to);
+                }
+            )*
+        };
+    }
+
+    test_markdown! {
+        markdown_headings:
+            "<h1>Title</h1><h3>Sub</h3>text<h2></h2>more" to "# Title\n\n### Sub\n\ntext\n\nmore",
+        markdown_link:
+            "see <a href=\"http://x.y/a (b)\">the <b>docs</b></a>."
+            to "see [the **docs**](http://x.y/a%20%28b%29).",
+        markdown_link_without_text:
+            "<a href=\"http://x.y\"></a>" to "<http://x.y>",
+        markdown_relative_link_without_text:
+            "<a href=\"/a_b\"></a>" to "[/a\\_b](/a_b)",
+        markdown_link_without_href:
+            "<a name=\"top\">top</a>" to "top",
+        markdown_lists:
+            "<ul><li>one</li><li>two</li></ul><ol type=\"i\" start=\"3\"><li>a</li><li>b</li></ol>"
+            to "* one\n* two\n3. a\n4. b",
+        markdown_nested_lists:
+            "<ol><li>a<ul><li>b</li></ul>c</li><li>d</li></ol>"
+            to "1. a\n   * b\n   c\n2. d",
+        markdown_heading_in_list:
+            "<ul><li><h2>x</h2></li><li><h3></h3>y</li></ul>" to "* ## x\n\n* y",
+        markdown_list_in_emphasis:
+            "<ul><b><li>x</b></ul><ol><a href=\"y\"><li>z</a></ol>" to "* **x**\n1. [z](y)",
+        markdown_table:
+            "<table><tr><th>A</th><th>B</th></tr><tr><td>*1*</td><td><a href=\"x\">l</a></td></tr></table>"
+            to "| A     | B      |\n| ----- | ------ |\n| \\*1\\* | [l](x) |",
+        markdown_emphasis:
+            "<em>it</em> and <strong> </strong><i>x </i>y" to "_it_ and _x_ y",
+        markdown_pre:
+            "<p>code</p><pre>\nlet x = 1;\n\n*y*;\n</pre>after"
+            to "code\n\n```\nlet x = 1;\n\n*y*;\n```\n\nafter",
+        markdown_pre_backticks:
+            "<pre>```\nx\n&#96;&#96;&#96;&#96;</pre>" to "`````\n```\nx\n````\n`````",
+        markdown_pre_nested:
+            "<pre>a<pre>b</pre>c</pre>d" to "```\nabc\n```\n\nd",
+        markdown_pre_unclosed:
+            "<pre>a<pre>b" to "```\nab\n```",
+        markdown_blockquote:
+            "<blockquote><p>quoted *text*</p><p>more</p></blockquote>after"
+            to "> quoted \\*text\\*\n>\n> more\n\nafter",
+        markdown_escaping:
+            "# not * a heading_" to "\\# not \\* a heading\\_",
+        markdown_escaping_entities:
+            "&amp;copy; &amp; ~~x~~" to "\\&copy; & \\~\\~x\\~\\~",
+    }
+
+    #[test]
+    fn markdown_wrap_blockquote() {
+        let options = Options::new()
+            .format(OutputFormat::Markdown)
+            .line_ending(LineEnding::Lf)
+            .wrap_width(10);
+        assert_eq!(
+            convert_with(options, "<blockquote>aaa bbb ccc ddd</blockquote>"),
+            "> aaa bbb\n> ccc ddd"
+        );
+    }
+
+    #[test]
+    fn markdown_wrap_escapes_line_start() {
+        let options = Options::new()
+            .format(OutputFormat::Markdown)
+            .line_ending(LineEnding::Lf)
+            .wrap_width(10);
+        assert_eq!(
+            convert_with(options, "aaaaaaaa 1. b aaaaaaaaa - c"),
+            "aaaaaaaa\n1\\. b\naaaaaaaaa\n\\- c"
+        );
+    }
+
+    #[test]
+    fn markdown_blockquote_depth_limit() {
+        let options = Options::new()
+            .format(OutputFormat::Markdown)
+            .line_ending(LineEnding::Lf);
+        let html = format!("{}deep", "<blockquote>".repeat(40));
+        assert_eq!(
+            convert_with(options, &html),
+            format!("{}deep", "> ".repeat(16))
+        );
+    }
+
+    #[test]
+    fn option_bullet() {
+        assert_eq!(
+            convert_with(
+                Options::new().bullet("- "),
+                "<ul><li>One</li></ul><ol><li>Two</li></ol>"
+            ),
+            "- One\r\n1. Two\r\n"
+        );
+    }
+
+    #[test]
+    fn option_list_indent() {
+        assert_eq!(
+            convert_with(
+                Options::new().bullet("* ").list_indent(4),
+                "<ul><li>A<ul><li>B</li></ul></li></ul>"
+            ),
+            "* A\r\n    * B\r\n"
+        );
+    }
+
+    #[test]
+    fn list_depth_limit() {
+        let html = format!("{}<li>deep", "<ol>".repeat(40));
+        assert_eq!(
+            convert_with(Options::new().list_indent(1), &html),
+            format!("{}1. deep", " ".repeat(16))
+        );
+    }
+
+    #[test]
+    fn option_wrap_width_list_items() {
+        let options = Options::new()
+            .line_ending(LineEnding::Lf)
+            .wrap_width(12)
+            .bullet("* ");
+        assert_eq!(
+            convert_with(
+                options,
+                "<ul><li>one two three four<ol><li>five six seven</li></ol></li></ul>"
+            ),
+            "* one two\n  three four\n  1. five\n     six\n     seven\n"
+        );
+    }
+
+    #[test]
+    fn option_silence() {
+        let options = Options::new().silence("NAV");
+        assert_eq!(convert_with(options, "<nav>menu</nav>content"), "content");
+        let options = Options::new().silenced_elements(["nav"]);
+        assert_eq!(
+            convert_with(options, "<nav>menu</nav><style>x</style>content"),
+            "xcontent"
+        );
+    }
+
+    #[test]
+    fn option_detect_layout_tables() {
+        let html = "<table><tr><td>a</td></tr><tr><td>b</td></tr></table>";
+        let options = Options::new().line_ending(LineEnding::Lf);
+        assert_eq!(convert_with(options.clone(), html), "a\nb\n\n");
+        let options = options.detect_layout_tables(false).table_borders(true);
+        assert_eq!(
+            convert_with(options, html),
+            "+---+\n| a |\n| b |\n+---+\n\n"
+        );
+    }
+
+    #[test]
+    fn table_depth_limit() {
+        // only the outer tables are laid out, the rest are written as text
+        let html = format!("{}x", "<table><tr><td>".repeat(40));
+        let options = Options::new()
+            .line_ending(LineEnding::Lf)
+            .detect_layout_tables(false)
+            .table_borders(true);
+        let text = convert_with(options, &html);
+        let lines: Vec<_> = text.trim_end().lines().collect();
+        assert_eq!(lines.len(), 33);
+        assert_eq!(
+            lines[16],
+            format!("{}x{}", "| ".repeat(16), " |".repeat(16))
+        );
     }
 }
@@ -0,0 +1,15 @@
+/// A link found by [`Converter::links`](crate::Converter::links).
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Link {
+    /// The text of the link as it appears in the plain text output, with
+    /// whitespace collapsed and leading and trailing whitespace removed.
+    pub text: String,
+    /// The `href` attribute with entities decoded and resolved against the
+    /// base URL, if there is one. `None` for anchors without an href and for
+    /// `javascript:` links.
+    pub href: Option<String>,
+    /// The `title` attribute with entities decoded.
+    pub title: Option<String>,
+    /// The byte offset of the `<` of the `a` tag in the HTML.
+    pub offset: usize,
+}
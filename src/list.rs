@@ -0,0 +1,270 @@
+use crate::render::tags;
+use crate::width::display_width;
+
+/// How the items of an ordered list are numbered, set with the `type`
+/// attribute.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+enum Numbering {
+    Decimal,
+    LowerAlpha,
+    UpperAlpha,
+    LowerRoman,
+    UpperRoman,
+}
+
+impl Numbering {
+    fn from_type(kind: &str) -> Self {
+        match kind.trim() {
+            "a" => Numbering::LowerAlpha,
+            "A" => Numbering::UpperAlpha,
+            "i" => Numbering::LowerRoman,
+            "I" => Numbering::UpperRoman,
+            _ => Numbering::Decimal,
+        }
+    }
+
+    fn format(self, n: i64) -> String {
+        match self {
+            Numbering::LowerAlpha if n > 0 => alpha(n as u64),
+            Numbering::UpperAlpha if n > 0 => alpha(n as u64).to_uppercase(),
+            Numbering::LowerRoman if n > 0 && n < 4000 => roman(n as u64),
+            Numbering::UpperRoman if n > 0 && n < 4000 => roman(n as u64).to_uppercase(),
+            // numbers that can't be written in the requested style fall back
+            // to decimal, like browsers do
+            _ => n.to_string(),
+        }
+    }
+}
+
+/// An open `ul` or `ol` element.
+#[derive(Debug)]
+pub(crate) struct List {
+    /// `None` for unordered lists.
+    numbering: Option<Numbering>,
+    next: i64,
+    reversed: bool,
+    /// The width of the marker of the current item.
+    pub(crate) hang: usize,
+}
+
+impl List {
+    pub(crate) fn unordered() -> Self {
+        List {
+            numbering: None,
+            next: 1,
+            reversed: false,
+            hang: 0,
+        }
+    }
+
+    /// Creates an ordered list from the values of its `start`, `reversed` and
+    /// `type` attributes. `item_count` is only needed for reversed lists
+    /// without a `start` value, which count down to one.
+    pub(crate) fn ordered(
+        start: Option<&str>,
+        reversed: bool,
+        kind: Option<&str>,
+        item_count: impl FnOnce() -> usize,
+    ) -> Self {
+        let next = start
+            .and_then(|start| start.trim().parse().ok())
+            .unwrap_or_else(|| if reversed { item_count() as i64 } else { 1 });
+        List {
+            numbering: Some(kind.map_or(Numbering::Decimal, Numbering::from_type)),
+            next,
+            reversed,
+            hang: 0,
+        }
+    }
+
+    /// Returns the marker for the next list item, which may set its own number
+    /// with the `value` attribute. `decimal_only` ignores the `type` of the
+    /// list, e.g. because Markdown can't express it.
+    pub(crate) fn next_marker(
+        &mut self,
+        value: Option<&str>,
+        bullet: &str,
+        decimal_only: bool,
+    ) -> String {
+        let marker = self.format_marker(value, bullet, decimal_only);
+        self.hang = display_width(&marker);
+        marker
+    }
+
+    fn format_marker(&mut self, value: Option<&str>, bullet: &str, decimal_only: bool) -> String {
+        let numbering = match self.numbering {
+            Some(numbering) => numbering,
+            None => return bullet.to_string(),
+        };
+
+        if let Some(value) = value.and_then(|value| value.trim().parse().ok()) {
+            self.next = value;
+        }
+        let n = self.next;
+        // numbers given in the HTML may be as large as they get
+        self.next = if self.reversed {
+            n.saturating_sub(1)
+        } else {
+            n.saturating_add(1)
+        };
+
+        let number = if decimal_only {
+            n.to_string()
+        } else {
+            numbering.format(n)
+        };
+        format!("{}. ", number)
+    }
+}
+
+/// The number of items of a list and of the `ol` lists nested in it, found
+/// by [`count_items`].
+#[derive(Debug, Default, PartialEq, Eq)]
+pub(crate) struct ItemCounts {
+    /// The items of the list itself.
+    pub(crate) count: usize,
+    /// The byte offsets of the start tags of the nested `ol` lists in the HTML
+    /// that was searched, and their items, in the order of the start tags.
+    pub(crate) nested: Vec<(usize, usize)>,
+}
+
+/// Counts the `li` elements that belong directly to a list, given the HTML that
+/// follows its start tag, and those of the `ol` lists nested in it, so that
+/// they don't have to be counted again when the nested lists start. Returns
+/// `None` if the end of the list isn't part of the HTML yet and more of it may
+/// still follow, unless `complete` is set.
+pub(crate) fn count_items(html: &str, complete: bool) -> Option<ItemCounts> {
+    let mut counts = ItemCounts::default();
+    // the open nested lists, with the index of their entry in `nested`
+    let mut open: Vec<Option<usize>> = Vec::new();
+    for (i, name) in tags(html) {
+        let count = match open.last() {
+            Some(Some(index)) => &mut counts.nested[*index].1,
+            Some(None) => &mut 0,
+            None => &mut counts.count,
+        };
+        match name.as_str() {
+            "ol" => {
+                open.push(Some(counts.nested.len()));
+                counts.nested.push((i, 0));
+            }
+            "ul" => open.push(None),
+            "/ul" | "/ol" if open.is_empty() => return Some(counts),
+            "/ul" | "/ol" => {
+                open.pop();
+            }
+            "li" => *count += 1,
+            _ => {}
+        }
+    }
+    complete.then_some(counts)
+}
+
+/// 1 is `a`, 26 is `z`, 27 is `aa` and so on.
+fn alpha(mut n: u64) -> String {
+    let mut letters = Vec::new();
+    while n > 0 {
+        n -= 1;
+        letters.push(b'a' + (n % 26) as u8);
+        n /= 26;
+    }
+    letters.iter().rev().map(|&c| char::from(c)).collect()
+}
+
+fn roman(mut n: u64) -> String {
+    const NUMERALS: &[(u64, &str)] = &[
+        (1000, "m"),
+        (900, "cm"),
+        (500, "d"),
+        (400, "cd"),
+        (100, "c"),
+        (90, "xc"),
+        (50, "l"),
+        (40, "xl"),
+        (10, "x"),
+        (9, "ix"),
+        (5, "v"),
+        (4, "iv"),
+        (1, "i"),
+    ];
+    let mut out = String::new();
+    for &(value, numeral) in NUMERALS {
+        while n >= value {
+            out.push_str(numeral);
+            n -= value;
+        }
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn alpha_numbering() {
+        assert_eq!(alpha(1), "a");
+        assert_eq!(alpha(26), "z");
+        assert_eq!(alpha(27), "aa");
+        assert_eq!(alpha(703), "aaa");
+    }
+
+    #[test]
+    fn roman_numbering() {
+        assert_eq!(roman(4), "iv");
+        assert_eq!(roman(9), "ix");
+        assert_eq!(roman(1994), "mcmxciv");
+        assert_eq!(Numbering::UpperRoman.format(14), "XIV");
+        assert_eq!(Numbering::UpperRoman.format(0), "0");
+    }
+
+    #[test]
+    fn numbers_saturate() {
+        let markers = |mut list: List, values: &[Option<&str>]| -> Vec<String> {
+            values
+                .iter()
+                .map(|value| list.next_marker(*value, "", false))
+                .collect()
+        };
+        let max = "9223372036854775807";
+        let min = "-9223372036854775808";
+        assert_eq!(
+            markers(List::ordered(Some(max), false, None, || 0), &[None, None]),
+            [format!("{}. ", max), format!("{}. ", max)]
+        );
+        assert_eq!(
+            markers(List::ordered(None, false, None, || 0), &[Some(max), None]),
+            [format!("{}. ", max), format!("{}. ", max)]
+        );
+        assert_eq!(
+            markers(List::ordered(Some(min), true, None, || 0), &[None, None]),
+            [format!("{}. ", min), format!("{}. ", min)]
+        );
+    }
+
+    #[test]
+    fn counts_direct_items() {
+        let html = "<li>a<LI>b<ol><li>c</li></ol><li>d</ol><li>e";
+        assert_eq!(
+            count_items(html, false),
+            Some(ItemCounts {
+                count: 3,
+                nested: vec![(10, 1)],
+            })
+        );
+        assert_eq!(count_items("<li>a<li>b", true).map(|c| c.count), Some(2));
+        assert_eq!(count_items("<li>a<li>b", false), None);
+    }
+
+    #[test]
+    fn counts_nested_items() {
+        let html = "<li><ol reversed><li>a<ul><li>b<ol><li>c<li>d</ol></ul></ol><ol><li>e";
+        assert_eq!(
+            count_items(html, true),
+            Some(ItemCounts {
+                count: 1,
+                nested: vec![(4, 1), (31, 2), (60, 1)],
+            })
+        );
+    }
+}
@@ -1,8 +1,591 @@
-use nanohtml2text::html2text;
-use std::io::Read;
+use nanohtml2text::{Converter, Encoding, LineEnding, LinkStyle, Options, OutputFormat};
+use std::collections::hash_map::{Entry, HashMap};
+use std::ffi::OsString;
+use std::fs::{self, File};
+use std::io::{self, BufWriter, Read, Write};
+use std::num::NonZeroUsize;
+use std::path::{Component, Path, PathBuf};
+use std::process;
+use std::sync::atomic::{AtomicUsize, Ordering};
+use std::sync::Mutex;
+use std::thread;
+
+const USAGE: &str = "\
+Usage: nanohtml2text [OPTION]... [FILE]...
+  or:  nanohtml2text --batch OUT_DIR [OPTION]... [DIR|FILE]...
+
+Converts HTML to plain text. Reads standard input if no FILE is given or
+FILE is -. The text of several files is written one after the other.
+
+With --batch, every file is converted into a text file of its own in
+OUT_DIR, with the extension .txt. The .html and .htm files in a DIR and
+its subdirectories are converted into the same structure of directories
+in OUT_DIR, other files into OUT_DIR by the path they were given as.
+Files that would be converted into the same text file, like a.html and
+a.htm, are converted only once and the others reported as failed.
+
+Options:
+  -o, --output FILE        write the text to FILE instead of standard output
+  -w, --width N            wrap lines longer than N columns, 0 to not wrap
+                           (the default)
+      --line-ending END    crlf (the default), lf or native
+      --links STYLE        how links are written: inline (the default),
+                           footnote or none
+  -f, --format FORMAT      text (the default) or markdown
+      --encoding LABEL     decode the input as LABEL, e.g. utf-8 or latin1,
+                           instead of detecting its encoding
+      --batch OUT_DIR      convert each file into a text file in OUT_DIR
+      --files-from LIST    with --batch, also convert the files listed in
+                           LIST, one path per line, or - for standard input
+  -j, --jobs N             with --batch, convert N files at once (by default
+                           as many as there are processors)
+  -h, --help               print this help and exit
+  -V, --version            print the version and exit
+
+Exit status:
+  0  if all files were converted
+  1  if a file couldn't be converted or the text couldn't be written
+  2  if the command line is invalid
+";
+
+/// What to do, as given on the command line.
+#[derive(Debug)]
+enum Command {
+    Convert(Box<Args>),
+    Help,
+    Version,
+}
+
+#[derive(Debug)]
+struct Args {
+    /// The files to convert, where `-` is standard input.
+    inputs: Vec<PathBuf>,
+    output: Option<PathBuf>,
+    options: Options,
+    line_ending: LineEnding,
+    encoding: Option<Encoding>,
+    /// The directory to write the text files to in batch mode.
+    batch: Option<PathBuf>,
+    /// Files listing more files to convert in batch mode.
+    files_from: Vec<PathBuf>,
+    jobs: Option<NonZeroUsize>,
+}
+
+fn parse_args(args: impl IntoIterator<Item = OsString>) -> Result<Command, String> {
+    let mut args = args.into_iter();
+    let mut inputs = Vec::new();
+    let mut output = None;
+    let mut options = Options::new();
+    let mut line_ending = LineEnding::Crlf;
+    let mut encoding = None;
+    let mut batch = None;
+    let mut files_from = Vec::new();
+    let mut jobs = None;
+
+    while let Some(arg) = args.next() {
+        let arg = match arg.to_str() {
+            Some(arg) if arg.starts_with('-') && arg != "-" => arg.to_string(),
+            _ => {
+                inputs.push(PathBuf::from(arg));
+                continue;
+            }
+        };
+        if arg == "--" {
+            inputs.extend(args.by_ref().map(PathBuf::from));
+            break;
+        }
+
+        // values may be given as `--name=value`, `-nvalue` or as the next
+        // argument
+        let short_value = arg.char_indices().nth(2).map(|(i, _)| i);
+        let (name, inline_value) = match (arg.split_once('='), short_value) {
+            (Some((name, value)), _) if arg.starts_with("--") => {
+                (name.to_string(), Some(value.into()))
+            }
+            (_, Some(i)) if !arg.starts_with("--") => {
+                (arg[..i].to_string(), Some(OsString::from(&arg[i..])))
+            }
+            _ => (arg, None),
+        };
+        let mut value = || -> Result<OsString, String> {
+            inline_value
+                .clone()
+                .or_else(|| args.next())
+                .ok_or_else(|| format!("option '{}' requires a value", name))
+        };
+        let mut text_value = || -> Result<String, String> {
+            value()?
+                .into_string()
+                .map_err(|value| format!("invalid value for '{}': {:?}", name, value))
+        };
+
+        match name.as_str() {
+            "-h" | "--help" => return Ok(Command::Help),
+            "-V" | "--version" => return Ok(Command::Version),
+            "-o" | "--output" => output = Some(PathBuf::from(value()?)),
+            "-w" | "--width" => {
+                let width = text_value()?;
+                let width: usize = width
+                    .parse()
+                    .map_err(|_| format!("invalid width '{}'", width))?;
+                options = options.wrap_width((width > 0).then_some(width));
+            }
+            "--line-ending" => {
+                line_ending = match text_value()?.as_str() {
+                    "crlf" => LineEnding::Crlf,
+                    "lf" => LineEnding::Lf,
+                    "native" => LineEnding::Native,
+                    other => return Err(format!("invalid line ending '{}'", other)),
+                };
+            }
+            "--links" => {
+                let style = match text_value()?.as_str() {
+                    "inline" => LinkStyle::Inline,
+                    "footnote" => LinkStyle::Footnote,
+                    "none" => LinkStyle::TextOnly,
+                    other => return Err(format!("invalid link style '{}'", other)),
+                };
+                options = options.link_style(style);
+            }
+            "-f" | "--format" => {
+                let format = match text_value()?.as_str() {
+                    "text" => OutputFormat::PlainText,
+                    "markdown" => OutputFormat::Markdown,
+                    other => return Err(format!("invalid format '{}'", other)),
+                };
+                options = options.format(format);
+            }
+            "--encoding" => {
+                let label = text_value()?;
+                encoding = Some(
+                    Encoding::for_label(&label)
+                        .ok_or_else(|| format!("unsupported encoding '{}'", label))?,
+                );
+            }
+            "--batch" => batch = Some(PathBuf::from(value()?)),
+            "--files-from" => files_from.push(PathBuf::from(value()?)),
+            "-j" | "--jobs" => {
+                let n = text_value()?;
+                jobs = Some(
+                    n.parse()
+                        .map_err(|_| format!("invalid number of jobs '{}'", n))?,
+                );
+            }
+            _ => return Err(format!("unknown option '{}'", name)),
+        }
+    }
+
+    if batch.is_none() {
+        if !files_from.is_empty() || jobs.is_some() {
+            return Err("--files-from and --jobs require --batch".into());
+        }
+        if inputs.is_empty() {
+            inputs.push(PathBuf::from("-"));
+        }
+    } else if output.is_some() {
+        return Err("--batch and --output can't be combined".into());
+    } else if inputs.is_empty() && files_from.is_empty() {
+        return Err("--batch requires files or directories to convert".into());
+    } else if inputs.iter().any(|input| input.as_os_str() == "-") {
+        return Err("--batch can't convert standard input".into());
+    }
+    Ok(Command::Convert(Box::new(Args {
+        inputs,
+        output,
+        options: options.line_ending(line_ending),
+        line_ending,
+        encoding,
+        batch,
+        files_from,
+        jobs,
+    })))
+}
+
+fn read_input(path: &Path) -> io::Result<Vec<u8>> {
+    let mut html = Vec::new();
+    if path.as_os_str() == "-" {
+        io::stdin().lock().read_to_end(&mut html)?;
+    } else {
+        File::open(path)?.read_to_end(&mut html)?;
+    }
+    Ok(html)
+}
+
+/// Converts all inputs, reporting errors as they happen. Returns the exit
+/// status.
+fn convert(args: Args) -> i32 {
+    let mut output: Box<dyn Write> = match &args.output {
+        Some(path) => match File::create(path) {
+            Ok(file) => Box::new(BufWriter::new(file)),
+            Err(error) => {
+                eprintln!("nanohtml2text: {}: {}", path.display(), error);
+                return 1;
+            }
+        },
+        None => Box::new(BufWriter::new(io::stdout().lock())),
+    };
+    let output_name = args
+        .output
+        .as_ref()
+        .map_or("standard output".into(), |path| path.display().to_string());
+
+    let converter = Converter::new(args.options);
+    let mut status = 0;
+    for path in &args.inputs {
+        let html = match read_input(path) {
+            Ok(html) => html,
+            Err(error) => {
+                let name = if path.as_os_str() == "-" {
+                    "standard input".into()
+                } else {
+                    path.display().to_string()
+                };
+                eprintln!("nanohtml2text: {}: {}", name, error);
+                status = 1;
+                continue;
+            }
+        };
+        let mut text = decode_and_convert(&converter, args.encoding, &html);
+        // keep the text of several files apart
+        if !text.is_empty() && !text.ends_with('\n') {
+            text.push_str(args.line_ending.as_str());
+        }
+        if let Err(error) = output.write_all(text.as_bytes()) {
+            return write_error(&output_name, error);
+        }
+    }
+    if let Err(error) = output.flush() {
+        return write_error(&output_name, error);
+    }
+    status
+}
+
+fn decode_and_convert(converter: &Converter, encoding: Option<Encoding>, html: &[u8]) -> String {
+    match encoding {
+        Some(encoding) => converter.convert(&encoding.decode(html)),
+        None => converter.convert_bytes(html),
+    }
+}
+
+/// A file to convert in batch mode and the file to write its text to.
+struct Job {
+    input: PathBuf,
+    output: PathBuf,
+}
+
+/// Converts every input into a file of its own in `out_dir`, using several
+/// threads. Prints a summary of the files that couldn't be converted and
+/// returns the exit status.
+fn convert_batch(args: Args, out_dir: &Path) -> i32 {
+    let mut jobs = Vec::new();
+    let mut failures = Vec::new();
+    for list in &args.files_from {
+        match read_input(list) {
+            Ok(list) => {
+                for path in String::from_utf8_lossy(&list).lines() {
+                    if !path.trim().is_empty() {
+                        jobs.push(Job {
+                            input: PathBuf::from(path),
+                            output: output_path(out_dir, Path::new(path)),
+                        });
+                    }
+                }
+            }
+            Err(error) => failures.push((list.clone(), error)),
+        }
+    }
+    for input in &args.inputs {
+        if input.is_dir() {
+            find_html_files(input, input, out_dir, &mut jobs, &mut failures);
+        } else {
+            jobs.push(Job {
+                input: input.clone(),
+                output: output_path(out_dir, input),
+            });
+        }
+    }
+
+    // file lists and directories that couldn't be read
+    let unread = failures.len();
+
+    // sorted, so that which of several inputs with the same output is
+    // converted doesn't depend on the order of directory entries
+    jobs.sort_by(|a, b| a.input.cmp(&b.input));
+    let total = jobs.len();
+    let jobs = remove_collisions(jobs, &mut failures);
+
+    let threads = args
+        .jobs
+        .or_else(|| thread::available_parallelism().ok())
+        .map_or(1, NonZeroUsize::get)
+        .min(jobs.len());
+    let encoding = args.encoding;
+    let converter = Converter::new(args.options);
+    let next = AtomicUsize::new(0);
+    let failures = Mutex::new(failures);
+    thread::scope(|scope| {
+        for _ in 0..threads {
+            scope.spawn(|| {
+                while let Some(job) = jobs.get(next.fetch_add(1, Ordering::Relaxed)) {
+                    if let Err(error) = convert_file(&converter, encoding, job) {
+                        failures.lock().unwrap().push((job.input.clone(), error));
+                    }
+                }
+            });
+        }
+    });
+
+    let mut failures = failures.into_inner().unwrap();
+    if failures.is_empty() {
+        return 0;
+    }
+    failures.sort_by(|a, b| a.0.cmp(&b.0));
+    eprintln!(
+        "nanohtml2text: {}",
+        summary(total, failures.len() - unread, unread)
+    );
+    for (path, error) in failures {
+        eprintln!("  {}: {}", path.display(), error);
+    }
+    1
+}
+
+/// Sums up a batch conversion with failures, of `total` files of which
+/// `failed` couldn't be converted, as well as `unread` file lists or
+/// directories.
+fn summary(total: usize, failed: usize, unread: usize) -> String {
+    let mut summary = format!("{} of {} files converted", total - failed, total);
+    if unread > 0 {
+        let inputs = if unread == 1 {
+            "file list or directory"
+        } else {
+            "file lists or directories"
+        };
+        summary.push_str(&format!(", {} {} couldn't be read", unread, inputs));
+    }
+    summary.push_str(", failed:");
+    summary
+}
+
+/// Removes the jobs that would overwrite the output of an earlier job, like
+/// `a.htm` after `a.html`, and adds them to the `failures`.
+fn remove_collisions(jobs: Vec<Job>, failures: &mut Vec<(PathBuf, io::Error)>) -> Vec<Job> {
+    let mut inputs = HashMap::new();
+    jobs.into_iter()
+        .filter(|job| match inputs.entry(job.output.clone()) {
+            Entry::Vacant(entry) => {
+                entry.insert(job.input.clone());
+                true
+            }
+            Entry::Occupied(entry) => {
+                let error = io::Error::new(
+                    io::ErrorKind::AlreadyExists,
+                    format!(
+                        "{} is already converted from {}",
+                        job.output.display(),
+                        entry.get().display()
+                    ),
+                );
+                failures.push((job.input.clone(), error));
+                false
+            }
+        })
+        .collect()
+}
+
+fn convert_file(converter: &Converter, encoding: Option<Encoding>, job: &Job) -> io::Result<()> {
+    let text = decode_and_convert(converter, encoding, &fs::read(&job.input)?);
+    if let Some(parent) = job.output.parent() {
+        fs::create_dir_all(parent)?;
+    }
+    fs::write(&job.output, text)
+}
+
+/// Adds a job for every `.html` or `.htm` file in `dir` and its
+/// subdirectories, to be written to the same path relative to `out_dir` as
+/// relative to `root`. Symbolic links to directories aren't followed.
+fn find_html_files(
+    root: &Path,
+    dir: &Path,
+    out_dir: &Path,
+    jobs: &mut Vec<Job>,
+    failures: &mut Vec<(PathBuf, io::Error)>,
+) {
+    let entries = match fs::read_dir(dir) {
+        Ok(entries) => entries,
+        Err(error) => return failures.push((dir.to_path_buf(), error)),
+    };
+    for entry in entries {
+        let entry = match entry {
+            Ok(entry) => entry,
+            Err(error) => return failures.push((dir.to_path_buf(), error)),
+        };
+        let path = entry.path();
+        if entry.file_type().is_ok_and(|file_type| file_type.is_dir()) {
+            find_html_files(root, &path, out_dir, jobs, failures);
+        } else if is_html_file(&path) {
+            let relative = path.strip_prefix(root).unwrap_or(&path);
+            jobs.push(Job {
+                output: output_path(out_dir, relative),
+                input: path,
+            });
+        }
+    }
+}
+
+fn is_html_file(path: &Path) -> bool {
+    path.extension().is_some_and(|extension| {
+        extension.eq_ignore_ascii_case("html") || extension.eq_ignore_ascii_case("htm")
+    })
+}
+
+/// The text file in `out_dir` that the HTML file at `relative` is converted
+/// into. Parts of the path that would lead out of `out_dir` are dropped.
+fn output_path(out_dir: &Path, relative: &Path) -> PathBuf {
+    let mut path = out_dir.to_path_buf();
+    path.extend(
+        relative
+            .components()
+            .filter(|component| matches!(component, Component::Normal(_))),
+    );
+    path.set_extension("txt");
+    path
+}
+
+fn write_error(output_name: &str, error: io::Error) -> i32 {
+    // the reader of the output went away, e.g. when piped to `head`
+    if error.kind() == io::ErrorKind::BrokenPipe {
+        return 0;
+    }
+    eprintln!("nanohtml2text: {}: {}", output_name, error);
+    1
+}
 
 fn main() {
-    let mut buffer = String::new();
-    std::io::stdin().read_to_string(&mut buffer).unwrap();
-    println!("{}", html2text(&buffer));
+    let status = match parse_args(std::env::args_os().skip(1)) {
+        Ok(Command::Convert(args)) => match args.batch.clone() {
+            Some(out_dir) => convert_batch(*args, &out_dir),
+            None => convert(*args),
+        },
+        Ok(Command::Help) => {
+            print!("{}", USAGE);
+            0
+        }
+        Ok(Command::Version) => {
+            println!("nanohtml2text {}", env!("CARGO_PKG_VERSION"));
+            0
+        }
+        Err(message) => {
+            eprintln!("nanohtml2text: {}", message);
+            eprintln!("Try 'nanohtml2text --help' for more information.");
+            2
+        }
+    };
+    process::exit(status);
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn parse(args: &[&str]) -> Result<Command, String> {
+        parse_args(args.iter().map(OsString::from))
+    }
+
+    #[test]
+    fn arguments() {
+        let args = match parse(&[
+            "a.html",
+            "-",
+            "-o",
+            "out.txt",
+            "--width=72",
+            "-fmarkdown",
+            "--links",
+            "none",
+            "--encoding",
+            "Latin1",
+            "--",
+            "-b.html",
+        ]) {
+            Ok(Command::Convert(args)) => args,
+            other => panic!("{:?}", other),
+        };
+        assert_eq!(args.inputs, ["a.html", "-", "-b.html"].map(PathBuf::from));
+        assert_eq!(args.output, Some(PathBuf::from("out.txt")));
+        assert_eq!(args.encoding, Some(Encoding::Windows1252));
+        let converter = Converter::new(args.options);
+        assert_eq!(converter.convert("<h1>a <a href=x>b</a></h1>"), "# a b");
+
+        match parse(&[]) {
+            Ok(Command::Convert(args)) => assert_eq!(args.inputs, [PathBuf::from("-")]),
+            other => panic!("{:?}", other),
+        }
+        assert!(matches!(parse(&["x", "--help"]), Ok(Command::Help)));
+        assert!(matches!(parse(&["-V"]), Ok(Command::Version)));
+    }
+
+    #[test]
+    fn output_collisions() {
+        let out_dir = Path::new("out");
+        let jobs = ["a.html", "b/a.html", "a.htm", "./a.html"]
+            .iter()
+            .map(|input| Job {
+                input: PathBuf::from(input),
+                output: output_path(out_dir, Path::new(input)),
+            })
+            .collect();
+        let mut failures = Vec::new();
+        let jobs = remove_collisions(jobs, &mut failures);
+        let inputs: Vec<_> = jobs.iter().map(|job| job.input.to_str().unwrap()).collect();
+        assert_eq!(inputs, ["a.html", "b/a.html"]);
+        let failed: Vec<_> = failures
+            .iter()
+            .map(|(input, error)| format!("{}: {}", input.display(), error))
+            .collect();
+        assert_eq!(
+            failed,
+            [
+                "a.htm: out/a.txt is already converted from a.html",
+                "./a.html: out/a.txt is already converted from a.html",
+            ]
+        );
+    }
+
+    #[test]
+    fn summaries() {
+        assert_eq!(summary(4, 1, 0), "3 of 4 files converted, failed:");
+        assert_eq!(
+            summary(4, 0, 1),
+            "4 of 4 files converted, 1 file list or directory couldn't be read, failed:"
+        );
+        assert_eq!(
+            summary(0, 0, 2),
+            "0 of 0 files converted, 2 file lists or directories couldn't be read, failed:"
+        );
+    }
+
+    #[test]
+    fn invalid_arguments() {
+        for (args, message) in [
+            (&["--width", "x"][..], "invalid width 'x'"),
+            (&["-o"], "option '-o' requires a value"),
+            (&["--links=all"], "invalid link style 'all'"),
+            (&["--encoding", "koi8-r"], "unsupported encoding 'koi8-r'"),
+            (&["--colour=auto"], "unknown option '--colour'"),
+            (&["-é"], "unknown option '-é'"),
+            (&["-éx"], "unknown option '-é'"),
+            (&["-j2", "a"], "--files-from and --jobs require --batch"),
+            (
+                &["--batch", "out", "-o", "x", "a"],
+                "--batch and --output can't be combined",
+            ),
+            (
+                &["--batch", "out"],
+                "--batch requires files or directories to convert",
+            ),
+        ] {
+            assert_eq!(parse(args).unwrap_err(), message);
+        }
+    }
 }
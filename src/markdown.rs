@@ -0,0 +1,148 @@
+use std::borrow::Cow;
+
+/// Escapes the characters in `text` that would otherwise be interpreted as
+/// inline Markdown syntax. Text at the start of a line also needs
+/// [`escape_line_start`].
+pub(crate) fn escape_text(text: &str) -> Cow<'_, str> {
+    let needs_escape = |i: usize, c: char| match c {
+        '\\' | '`' | '*' | '_' | '~' | '[' | ']' | '<' | '>' => true,
+        // an `&` is only a problem if it could start an entity, which the
+        // following text might complete
+        '&' => !text[i + 1..].starts_with(|c: char| !c.is_ascii_alphanumeric() && c != '#'),
+        _ => false,
+    };
+    if !text.char_indices().any(|(i, c)| needs_escape(i, c)) {
+        return Cow::Borrowed(text);
+    }
+
+    let mut out = String::with_capacity(text.len() + 4);
+    for (i, c) in text.char_indices() {
+        if needs_escape(i, c) {
+            out.push('\\');
+        }
+        out.push(c);
+    }
+    Cow::Owned(out)
+}
+
+/// Escapes the start of `line` if it would begin a heading, list item or
+/// another block element.
+pub(crate) fn escape_line_start(line: &str) -> Cow<'_, str> {
+    if !starts_block(line) {
+        return Cow::Borrowed(line);
+    }
+    let (marker, rest) = line.split_at(block_marker_position(line));
+    Cow::Owned(format!("{}\\{}", marker, rest))
+}
+
+/// Checks if `text` at the start of a line would begin a heading, list item or
+/// block quote.
+fn starts_block(text: &str) -> bool {
+    match text.chars().next() {
+        Some('#' | '-' | '+' | '=' | '|') => true,
+        Some('0'..='9') => {
+            let rest = text.trim_start_matches(|c: char| c.is_ascii_digit());
+            rest.starts_with('.') || rest.starts_with(')')
+        }
+        _ => false,
+    }
+}
+
+/// The byte offset of the character that has to be escaped for a line that
+/// [`starts_block`].
+fn block_marker_position(text: &str) -> usize {
+    if text.starts_with(|c: char| c.is_ascii_digit()) {
+        text.find(|c: char| !c.is_ascii_digit()).unwrap_or(0)
+    } else {
+        0
+    }
+}
+
+/// Makes sure a link destination can't end the `(...)` it is put in.
+pub(crate) fn escape_url(url: &str) -> Cow<'_, str> {
+    if !url.contains([' ', '(', ')', '<', '>']) {
+        return Cow::Borrowed(url);
+    }
+    Cow::Owned(
+        url.replace(' ', "%20")
+            .replace('(', "%28")
+            .replace(')', "%29")
+            .replace('<', "%3C")
+            .replace('>', "%3E"),
+    )
+}
+
+/// The length of the fence for a code block of which only the start is known,
+/// longer than the runs of backticks code usually contains.
+const LONG_FENCE: usize = 16;
+
+/// The fence for a code block with the given content, which is longer than
+/// any run of backticks in it so that the block isn't closed early. If the
+/// content is `partial`, the fence is at least [`LONG_FENCE`] long.
+pub(crate) fn fence(content: &str, partial: bool) -> String {
+    let longest = content.split(|c| c != '`').map(str::len).max().unwrap_or(0);
+    let min_len = if partial { LONG_FENCE } else { 3 };
+    "`".repeat((longest + 1).max(min_len))
+}
+
+/// Checks if `url` can be written as an autolink like `<https://a.b>`, which
+/// requires an absolute URL with a scheme.
+pub(crate) fn is_autolink(url: &str) -> bool {
+    let scheme_len = match url.find(':') {
+        Some(len) => len,
+        None => return false,
+    };
+    let scheme = &url[..scheme_len];
+    (2..=32).contains(&scheme.len())
+        && scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '.' | '-'))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn escapes_inline_syntax() {
+        assert_eq!(escape_text("plain"), "plain");
+        assert_eq!(escape_text("*a* _b_ [c]"), "\\*a\\* \\_b\\_ \\[c\\]");
+        assert_eq!(escape_text("1 < 2 `x` \\"), "1 \\< 2 \\`x\\` \\\\");
+        assert_eq!(escape_text("&copy; ~~a~~"), "\\&copy; \\~\\~a\\~\\~");
+        assert_eq!(escape_text("a&b & c"), "a\\&b & c");
+    }
+
+    #[test]
+    fn escapes_block_markers_at_line_start() {
+        assert_eq!(escape_line_start("# not a heading"), "\\# not a heading");
+        assert_eq!(escape_text("# inline"), "# inline");
+        assert_eq!(escape_line_start("- item"), "\\- item");
+        assert_eq!(escape_line_start("2020. year"), "2020\\. year");
+        assert_eq!(escape_line_start("2020 year"), "2020 year");
+        assert_eq!(escape_line_start("a 1. b"), "a 1. b");
+    }
+
+    #[test]
+    fn fences() {
+        assert_eq!(fence("no backticks", false), "```");
+        assert_eq!(fence("`a` ``b``", false), "```");
+        assert_eq!(fence("```\n````x", false), "`````");
+        assert_eq!(fence("```", true).len(), LONG_FENCE);
+    }
+
+    #[test]
+    fn autolinks() {
+        assert!(is_autolink("https://a.b/c"));
+        assert!(is_autolink("mailto:a@b.c"));
+        assert!(!is_autolink("/wiki/Main"));
+        assert!(!is_autolink("page.html?a=b:c"));
+        assert!(!is_autolink("c:/x"));
+    }
+
+    #[test]
+    fn escapes_urls() {
+        assert_eq!(escape_url("https://a.b/c"), "https://a.b/c");
+        assert_eq!(escape_url("/wiki/A (b)"), "/wiki/A%20%28b%29");
+    }
+}
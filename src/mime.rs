@@ -0,0 +1,484 @@
+use std::borrow::Cow;
+
+use crate::{Converter, Encoding};
+
+/// How deeply multipart messages may be nested, so that malicious messages
+/// can't overflow the stack.
+const MAX_DEPTH: usize = 32;
+
+impl Converter {
+    /// Converts a raw email message as described in
+    /// [RFC 5322](https://www.rfc-editor.org/rfc/rfc5322) and the MIME RFCs
+    /// to text. Requires the `mime` feature.
+    ///
+    /// The parts of `multipart/alternative` messages are alternative
+    /// versions of the same text, of which the HTML one is converted, or the
+    /// plain text one with [`Options::prefer_plain_text`](crate::Options::prefer_plain_text).
+    /// Of `multipart/related` messages, only the main part is converted, while
+    /// the text of every part of `multipart/mixed` messages, except for
+    /// attachments, is written one after the other. Quoted-printable and base64
+    /// encoded parts and their charsets are decoded. Parts that are neither
+    /// HTML nor plain text are left out.
+    ///
+    /// ```
+    /// use nanohtml2text::{Converter, LineEnding, Options};
+    ///
+    /// let message = b"From: a@example.com\r
+    /// Content-Type: multipart/alternative; boundary=\"b\"\r
+    /// \r
+    /// --b\r
+    /// Content-Type: text/plain; charset=utf-8\r
+    /// \r
+    /// *Hello* there\r
+    /// --b\r
+    /// Content-Type: text/html; charset=iso-8859-1\r
+    /// Content-Transfer-Encoding: quoted-printable\r
+    /// \r
+    /// <p><b>Hello</b> caf=E9</p>\r
+    /// --b--\r
+    /// ";
+    /// let options = Options::new().line_ending(LineEnding::Lf);
+    /// assert_eq!(Converter::new(options.clone()).convert_email(message), "Hello café");
+    /// let converter = Converter::new(options.prefer_plain_text(true));
+    /// assert_eq!(converter.convert_email(message), "*Hello* there");
+    /// ```
+    pub fn convert_email(&self, message: &[u8]) -> String {
+        self.part_text(&Part::parse(message), 0).unwrap_or_default()
+    }
+
+    /// The text of a part, or `None` if it has none.
+    fn part_text(&self, part: &Part<'_>, depth: usize) -> Option<String> {
+        if depth > MAX_DEPTH {
+            return None;
+        }
+        let subparts = || {
+            let boundary = part.content_type.param("boundary").unwrap_or_default();
+            split_multipart(part.body, boundary)
+                .into_iter()
+                .map(Part::parse)
+                .collect::<Vec<_>>()
+        };
+        match part.content_type.mime_type.as_str() {
+            "text/html" => Some(self.html_text(part)),
+            "text/plain" => Some(self.plain_text(part)),
+            "multipart/alternative" => {
+                // later parts are the better ones
+                let (plain, rich): (Vec<_>, Vec<_>) = subparts()
+                    .into_iter()
+                    .rev()
+                    .partition(|part| part.content_type.mime_type == "text/plain");
+                let (preferred, other) = if self.options.prefer_plain_text {
+                    (plain, rich)
+                } else {
+                    (rich, plain)
+                };
+                preferred
+                    .iter()
+                    .chain(&other)
+                    .find_map(|part| self.part_text(part, depth + 1))
+            }
+            "multipart/related" => {
+                let subparts = subparts();
+                let start = part.content_type.param("start");
+                let root = subparts
+                    .iter()
+                    .find(|part| start.is_some() && part.header("content-id") == start)
+                    .or_else(|| subparts.first())?;
+                self.part_text(root, depth + 1)
+            }
+            mime_type if mime_type.starts_with("multipart/") => {
+                let texts: Vec<_> = subparts()
+                    .iter()
+                    .filter(|part| !part.is_attachment())
+                    .filter_map(|part| self.part_text(part, depth + 1))
+                    .filter(|text| !text.trim().is_empty())
+                    .collect();
+                if texts.is_empty() {
+                    return None;
+                }
+                let separator = self.options.newline().repeat(self.options.block_lines());
+                let texts: Vec<_> = texts.iter().map(|text| text.trim_end()).collect();
+                Some(texts.join(&separator))
+            }
+            _ => None,
+        }
+    }
+
+    fn html_text(&self, part: &Part<'_>) -> String {
+        let body = part.decoded_body();
+        match part.charset() {
+            Some(encoding) => self.convert(&encoding.decode(&body)),
+            None => self.convert_bytes(&body),
+        }
+    }
+
+    /// The text of a `text/plain` part, with the configured line endings.
+    fn plain_text(&self, part: &Part<'_>) -> String {
+        let body = part.decoded_body();
+        let text = part.charset().unwrap_or(Encoding::Utf8).decode(&body);
+        let newline = self.options.newline();
+        let mut lines = text.lines().peekable();
+        let mut out = String::with_capacity(text.len());
+        while let Some(line) = lines.next() {
+            out.push_str(line.strip_suffix('\r').unwrap_or(line));
+            if lines.peek().is_some() {
+                out.push_str(newline);
+            }
+        }
+        out
+    }
+}
+
+/// A message or a part of a multipart message.
+struct Part<'a> {
+    /// The unfolded headers, with lowercase names.
+    headers: Vec<(String, String)>,
+    content_type: ContentType,
+    body: &'a [u8],
+}
+
+impl<'a> Part<'a> {
+    fn parse(raw: &'a [u8]) -> Self {
+        let mut headers: Vec<(String, String)> = Vec::new();
+        let mut rest = raw;
+        loop {
+            let (line, next) = match rest.iter().position(|&b| b == b'\n') {
+                Some(end) => (&rest[..end], &rest[end + 1..]),
+                None => (rest, &rest[rest.len()..]),
+            };
+            let line = String::from_utf8_lossy(line.strip_suffix(b"\r").unwrap_or(line));
+            if line.is_empty() {
+                rest = next;
+                break;
+            }
+            if line.starts_with([' ', '\t']) {
+                // a folded header continues on this line
+                if let Some((_, value)) = headers.last_mut() {
+                    value.push(' ');
+                    value.push_str(line.trim());
+                }
+            } else if let Some((name, value)) = line.split_once(':') {
+                headers.push((name.trim().to_ascii_lowercase(), value.trim().to_string()));
+            }
+            rest = next;
+            if rest.is_empty() {
+                break;
+            }
+        }
+
+        let content_type = headers
+            .iter()
+            .find(|(name, _)| name == "content-type")
+            .map_or_else(ContentType::default, |(_, value)| ContentType::parse(value));
+        Part {
+            headers,
+            content_type,
+            body: rest,
+        }
+    }
+
+    fn header(&self, name: &str) -> Option<&str> {
+        self.headers
+            .iter()
+            .find(|(header, _)| header == name)
+            .map(|(_, value)| value.as_str())
+    }
+
+    fn is_attachment(&self) -> bool {
+        self.header("content-disposition")
+            .is_some_and(|disposition| {
+                let kind = disposition.split(';').next().unwrap_or_default();
+                kind.trim().eq_ignore_ascii_case("attachment")
+            })
+    }
+
+    fn charset(&self) -> Option<Encoding> {
+        self.content_type
+            .param("charset")
+            .and_then(Encoding::for_label)
+    }
+
+    /// The body without its content transfer encoding.
+    fn decoded_body(&self) -> Cow<'a, [u8]> {
+        let encoding = self
+            .header("content-transfer-encoding")
+            .unwrap_or_default()
+            .to_ascii_lowercase();
+        match encoding.as_str() {
+            "base64" => Cow::Owned(decode_base64(self.body)),
+            "quoted-printable" => Cow::Owned(decode_quoted_printable(self.body)),
+            _ => Cow::Borrowed(self.body),
+        }
+    }
+}
+
+/// A `Content-Type` header like `text/html; charset="utf-8"`.
+struct ContentType {
+    /// The lowercase type and subtype.
+    mime_type: String,
+    /// The parameters, with lowercase names.
+    params: Vec<(String, String)>,
+}
+
+impl Default for ContentType {
+    fn default() -> Self {
+        ContentType {
+            mime_type: "text/plain".into(),
+            params: Vec::new(),
+        }
+    }
+}
+
+impl ContentType {
+    fn parse(value: &str) -> Self {
+        let (mime_type, mut rest) = value.split_once(';').unwrap_or((value, ""));
+        let mut params = Vec::new();
+        while let Some((name, value)) = rest.split_once('=') {
+            let name = name.trim().to_ascii_lowercase();
+            let value = value.trim_start();
+            let param = match value.strip_prefix('"') {
+                Some(quoted) => {
+                    // backslashes escape the next character
+                    let mut param = String::new();
+                    let mut chars = quoted.char_indices();
+                    rest = "";
+                    while let Some((i, c)) = chars.next() {
+                        match c {
+                            '"' => {
+                                rest = quoted[i + 1..].split_once(';').map_or("", |(_, rest)| rest);
+                                break;
+                            }
+                            '\\' => param.extend(chars.next().map(|(_, c)| c)),
+                            c => param.push(c),
+                        }
+                    }
+                    param
+                }
+                None => {
+                    let (param, next) = value.split_once(';').unwrap_or((value, ""));
+                    rest = next;
+                    param.trim().to_string()
+                }
+            };
+            params.push((name, param));
+        }
+        ContentType {
+            mime_type: mime_type.trim().to_ascii_lowercase(),
+            params,
+        }
+    }
+
+    fn param(&self, name: &str) -> Option<&str> {
+        self.params
+            .iter()
+            .find(|(param, _)| param == name)
+            .map(|(_, value)| value.as_str())
+    }
+}
+
+/// Splits the body of a multipart message into its parts. The text before the
+/// first and after the last boundary is left out.
+fn split_multipart<'a>(body: &'a [u8], boundary: &str) -> Vec<&'a [u8]> {
+    let mut parts = Vec::new();
+    if boundary.is_empty() {
+        return parts;
+    }
+    let delimiter = format!("--{}", boundary);
+    // where the part that is being split off starts
+    let mut start = None;
+    let mut line_start = 0;
+    while line_start < body.len() {
+        let line_end = body[line_start..]
+            .iter()
+            .position(|&b| b == b'\n')
+            .map_or(body.len(), |end| line_start + end);
+        let line = &body[line_start..line_end];
+        if let Some(rest) = line.strip_prefix(delimiter.as_bytes()) {
+            let closing = rest.starts_with(b"--");
+            if closing || rest.iter().all(u8::is_ascii_whitespace) {
+                if let Some(start) = start {
+                    // the line break before the boundary belongs to it
+                    let end = body[..line_start]
+                        .strip_suffix(b"\n")
+                        .map_or(line_start, |before| {
+                            before.strip_suffix(b"\r").unwrap_or(before).len()
+                        });
+                    parts.push(&body[start..end.max(start)]);
+                }
+                if closing {
+                    return parts;
+                }
+                start = Some(line_end + 1);
+            }
+        }
+        line_start = line_end + 1;
+    }
+    // the closing boundary is missing
+    if let Some(start) = start {
+        parts.push(&body[start.min(body.len())..]);
+    }
+    parts
+}
+
+/// Decodes base64, skipping line breaks and any other characters outside of
+/// the alphabet.
+fn decode_base64(input: &[u8]) -> Vec<u8> {
+    let mut out = Vec::with_capacity(input.len() / 4 * 3);
+    let mut buffer = 0u32;
+    let mut bits = 0;
+    for &byte in input {
+        let value = match byte {
+            b'A'..=b'Z' => byte - b'A',
+            b'a'..=b'z' => byte - b'a' + 26,
+            b'0'..=b'9' => byte - b'0' + 52,
+            b'+' => 62,
+            b'/' => 63,
+            b'=' => break,
+            _ => continue,
+        };
+        buffer = buffer << 6 | u32::from(value);
+        bits += 6;
+        if bits >= 8 {
+            bits -= 8;
+            out.push((buffer >> bits) as u8);
+            buffer &= (1 << bits) - 1;
+        }
+    }
+    out
+}
+
+/// Decodes quoted-printable text, keeping invalid escapes as they are.
+fn decode_quoted_printable(input: &[u8]) -> Vec<u8> {
+    let hex = |byte: Option<&u8>| byte.and_then(|&byte| char::from(byte).to_digit(16));
+    let mut out = Vec::with_capacity(input.len());
+    let mut i = 0;
+    while i < input.len() {
+        if input[i] == b'=' {
+            let rest = &input[i + 1..];
+            if let (Some(high), Some(low)) = (hex(rest.first()), hex(rest.get(1))) {
+                out.push((high << 4 | low) as u8);
+                i += 3;
+                continue;
+            }
+            // a soft line break, which may be preceded by whitespace
+            let spaces = rest
+                .iter()
+                .take_while(|&&b| b == b' ' || b == b'\t')
+                .count();
+            match &rest[spaces..] {
+                [b'\r', b'\n', ..] => {
+                    i += spaces + 3;
+                    continue;
+                }
+                [b'\n', ..] => {
+                    i += spaces + 2;
+                    continue;
+                }
+                [] => break,
+                _ => {}
+            }
+        }
+        out.push(input[i]);
+        i += 1;
+    }
+    out
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+    use crate::{LineEnding, Options};
+
+    fn convert(message: impl AsRef<[u8]>, prefer_plain_text: bool) -> String {
+        let options = Options::new()
+            .line_ending(LineEnding::Lf)
+            .prefer_plain_text(prefer_plain_text);
+        Converter::new(options).convert_email(message.as_ref())
+    }
+
+    #[test]
+    fn nested_multiparts() {
+        let message = "Subject: test\n\
+            Content-Type: multipart/mixed;\n boundary=outer\n\
+            \n\
+            preamble\n\
+            --outer\n\
+            Content-Type: multipart/alternative; boundary=\"inner; b\"\n\
+            \n\
+            --inner; b\n\
+            Content-Type: text/plain\n\
+            \n\
+            plain\n\
+            --inner; b\n\
+            Content-Type: multipart/related; boundary=rel; start=\"<root>\"\n\
+            \n\
+            --rel\n\
+            Content-Type: image/png\n\
+            \n\
+            PNG\n\
+            --rel\n\
+            Content-Type: text/html\n\
+            Content-ID: <root>\n\
+            \n\
+            <p>rich</p>\n\
+            --rel--\n\
+            --inner; b--\n\
+            --outer\n\
+            Content-Type: text/plain\n\
+            Content-Disposition: attachment; filename=notes.txt\n\
+            \n\
+            attached\n\
+            --outer\n\
+            Content-Type: text/html; charset=windows-1252\n\
+            Content-Transfer-Encoding: base64\n\
+            \n\
+            PHA+\n\
+            k2VuZJQ8L3A+\n\
+            --outer--\n\
+            epilogue\n";
+        assert_eq!(convert(message, false), "rich\n\n“end”");
+        assert_eq!(convert(message, true), "plain\n\n“end”");
+    }
+
+    #[test]
+    fn single_parts() {
+        assert_eq!(
+            convert("Subject: x\r\n\r\nline 1\r\nline 2\r\n", false),
+            "line 1\nline 2"
+        );
+        assert_eq!(
+            convert(
+                b"Content-Type: TEXT/HTML\n\n<meta charset=latin1>caf\xE9",
+                false
+            ),
+            "café"
+        );
+        assert_eq!(convert("Content-Type: image/png\n\nPNG", false), "");
+        assert_eq!(convert("", false), "");
+    }
+
+    #[test]
+    fn unterminated_multipart() {
+        let message = "Content-Type: multipart/mixed; boundary=b\n\n--b\n\na\n--b\n\nb";
+        assert_eq!(convert(message, false), "a\n\nb");
+    }
+
+    #[test]
+    fn content_type_params() {
+        let content_type =
+            ContentType::parse("Multipart/Mixed; BOUNDARY=\"a \\\"b\\\"; c\"; charset = utf-8");
+        assert_eq!(content_type.mime_type, "multipart/mixed");
+        assert_eq!(content_type.param("boundary"), Some("a \"b\"; c"));
+        assert_eq!(content_type.param("charset"), Some("utf-8"));
+    }
+
+    #[test]
+    fn transfer_encodings() {
+        assert_eq!(decode_base64(b"aGVs\r\nbG8=\r\n"), b"hello");
+        assert_eq!(decode_base64(b"YQ"), b"a");
+        assert_eq!(
+            decode_quoted_printable(b"caf=C3=A9 =3D soft=  \r\nbreak=\nend=zz="),
+            "café = softbreakend=zz".as_bytes()
+        );
+    }
+}
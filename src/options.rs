@@ -0,0 +1,362 @@
+/// The line ending inserted for line breaks in the output.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LineEnding {
+    /// `\r\n`, the default since it is what plain text emails use.
+    Crlf,
+    /// `\n`
+    Lf,
+    /// The line ending of the platform the code was compiled for: CRLF on
+    /// Windows, LF everywhere else.
+    Native,
+}
+
+impl LineEnding {
+    /// The characters inserted for a line break.
+    pub fn as_str(self) -> &'static str {
+        match self {
+            LineEnding::Crlf => "\r\n",
+            LineEnding::Lf => "\n",
+            LineEnding::Native if cfg!(windows) => "\r\n",
+            LineEnding::Native => "\n",
+        }
+    }
+}
+
+/// How `a` tags are rendered.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum LinkStyle {
+    /// The link text followed by the href in parentheses, e.g. `here (test)`.
+    /// If the text is the same as the href, it is only printed once.
+    ///
+    /// In [`OutputFormat::Markdown`] this is a regular Markdown link, e.g.
+    /// `[here](test)`.
+    Inline,
+    /// The link text followed by a number in brackets, e.g. `here[1]`. The
+    /// hrefs are listed at the end of the output, e.g. `[1]: test`. Links to the
+    /// same href share the same number.
+    ///
+    /// In [`OutputFormat::Markdown`] these are reference links, e.g.
+    /// `[here][1]`.
+    Footnote,
+    /// Only the link text, the href is dropped. This omits links entirely.
+    TextOnly,
+}
+
+/// How `img` tags are rendered.
+///
+/// Images without alt text, which are usually decorative, and tracking pixels
+/// with a width and height of at most one pixel are always left out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum ImageStyle {
+    /// The alt text, as if it was part of the surrounding text.
+    AltText,
+    /// The alt text marked as an image, e.g. `[image: Logo]`, or `image: Logo`
+    /// for [`OutputFormat::Markdown`], which writes images with a source as
+    /// `![Logo](logo.png)` either way.
+    Labeled,
+    /// Images are left out entirely.
+    Omit,
+}
+
+/// The kind of text that is produced.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum OutputFormat {
+    /// Plain text, with formatting reduced to line breaks.
+    PlainText,
+    /// Markdown, keeping headings, links, lists, emphasis, preformatted text and
+    /// block quotes. Text is escaped so it is not mistaken for Markdown syntax.
+    Markdown,
+}
+
+/// How block elements like paragraphs and headings are separated from the
+/// surrounding text.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub enum BlockSeparator {
+    /// An empty line between blocks.
+    BlankLine,
+    /// A single line break between blocks.
+    Newline,
+}
+
+/// Settings for a [`Converter`](crate::Converter).
+///
+/// The default settings are the ones used by [`html2text`](crate::html2text).
+/// Settings can be changed with the builder methods:
+///
+/// ```
+/// use nanohtml2text::{Converter, LineEnding, Options};
+///
+/// let options = Options::new().line_ending(LineEnding::Lf);
+/// let text = Converter::new(options).convert("<p>one</p><p>two</p>");
+/// assert_eq!(text, "one\n\ntwo");
+/// ```
+#[derive(Debug, Clone, PartialEq, Eq)]
+pub struct Options {
+    pub(crate) format: OutputFormat,
+    pub(crate) line_ending: LineEnding,
+    pub(crate) link_style: LinkStyle,
+    pub(crate) image_style: ImageStyle,
+    pub(crate) image_sources: bool,
+    pub(crate) block_separator: BlockSeparator,
+    pub(crate) silenced: Vec<String>,
+    pub(crate) wrap_width: Option<usize>,
+    pub(crate) bullet: String,
+    pub(crate) list_indent: usize,
+    pub(crate) table_borders: bool,
+    pub(crate) detect_layout_tables: bool,
+    pub(crate) base_url: Option<String>,
+    #[cfg(feature = "mime")]
+    pub(crate) prefer_plain_text: bool,
+}
+
+impl Default for Options {
+    fn default() -> Self {
+        Options {
+            format: OutputFormat::PlainText,
+            line_ending: LineEnding::Crlf,
+            link_style: LinkStyle::Inline,
+            image_style: ImageStyle::AltText,
+            image_sources: false,
+            block_separator: BlockSeparator::BlankLine,
+            silenced: vec!["head".into(), "script".into(), "style".into()],
+            wrap_width: None,
+            bullet: String::new(),
+            list_indent: 2,
+            table_borders: false,
+            detect_layout_tables: true,
+            base_url: None,
+            #[cfg(feature = "mime")]
+            prefer_plain_text: false,
+        }
+    }
+}
+
+impl Options {
+    /// Creates the default settings.
+    pub fn new() -> Self {
+        Self::default()
+    }
+
+    /// Sets the kind of text that is produced. Defaults to
+    /// [`OutputFormat::PlainText`].
+    ///
+    /// ```
+    /// use nanohtml2text::{Converter, LineEnding, Options, OutputFormat};
+    ///
+    /// let options = Options::new()
+    ///     .format(OutputFormat::Markdown)
+    ///     .line_ending(LineEnding::Lf);
+    /// let text = Converter::new(options).convert("<h1>Hi</h1><p>a <b>bold</b> move</p>");
+    /// assert_eq!(text, "# Hi\n\na **bold** move");
+    /// ```
+    pub fn format(mut self, format: OutputFormat) -> Self {
+        self.format = format;
+        self
+    }
+
+    /// Sets the line ending used for line breaks. Defaults to
+    /// [`LineEnding::Crlf`].
+    pub fn line_ending(mut self, line_ending: LineEnding) -> Self {
+        self.line_ending = line_ending;
+        self
+    }
+
+    /// Sets how links are rendered. Defaults to [`LinkStyle::Inline`].
+    pub fn link_style(mut self, link_style: LinkStyle) -> Self {
+        self.link_style = link_style;
+        self
+    }
+
+    /// Sets how images are rendered. Defaults to [`ImageStyle::AltText`].
+    ///
+    /// The alt text of an image inside a link becomes the text of the link.
+    ///
+    /// ```
+    /// use nanohtml2text::{Converter, ImageStyle, Options};
+    ///
+    /// let html = "<a href=\"https://example.com\"><img src=\"logo.png\" alt=\"Example\"></a>";
+    /// let options = Options::new().image_style(ImageStyle::Labeled);
+    /// let text = Converter::new(options).convert(html);
+    /// assert_eq!(text, "[image: Example] (https://example.com)");
+    /// ```
+    pub fn image_style(mut self, image_style: ImageStyle) -> Self {
+        self.image_style = image_style;
+        self
+    }
+
+    /// Writes the `src` of images after their alt text, like the href of a
+    /// link, e.g. `Logo (logo.png)`. In [`OutputFormat::Markdown`], images are
+    /// written as Markdown images instead, e.g. `![Logo](logo.png)`. Defaults
+    /// to `false`.
+    pub fn image_sources(mut self, show: bool) -> Self {
+        self.image_sources = show;
+        self
+    }
+
+    /// Sets how block elements are separated. Defaults to
+    /// [`BlockSeparator::BlankLine`].
+    pub fn block_separator(mut self, block_separator: BlockSeparator) -> Self {
+        self.block_separator = block_separator;
+        self
+    }
+
+    /// Adds an element that is discarded together with its content.
+    pub fn silence(mut self, element: &str) -> Self {
+        let element = element.to_lowercase();
+        if !self.silenced.contains(&element) {
+            self.silenced.push(element);
+        }
+        self
+    }
+
+    /// Replaces the list of elements that are discarded together with their
+    /// content. Defaults to `head`, `script` and `style`.
+    pub fn silenced_elements<I, S>(mut self, elements: I) -> Self
+    where
+        I: IntoIterator<Item = S>,
+        S: AsRef<str>,
+    {
+        self.silenced = elements
+            .into_iter()
+            .map(|element| element.as_ref().to_lowercase())
+            .collect();
+        self
+    }
+
+    /// Wraps lines that are wider than `width` columns on word boundaries.
+    /// Words that don't fit on a line by themselves, like long URLs, are not
+    /// split. Defaults to `None`, which doesn't wrap at all.
+    ///
+    /// ```
+    /// use nanohtml2text::{Converter, LineEnding, Options};
+    ///
+    /// let options = Options::new().line_ending(LineEnding::Lf).wrap_width(10);
+    /// let text = Converter::new(options).convert("<p>the quick brown fox</p>");
+    /// assert_eq!(text, "the quick\nbrown fox");
+    /// ```
+    pub fn wrap_width(mut self, width: impl Into<Option<usize>>) -> Self {
+        self.wrap_width = width.into().map(|width| width.max(1));
+        self
+    }
+
+    /// Sets the marker written in front of the items of unordered lists,
+    /// including any spacing after it, e.g. `"* "`. By default there is none,
+    /// and the items only start on a new line, except for
+    /// [`OutputFormat::Markdown`], which uses `"* "`.
+    ///
+    /// Items of ordered lists are numbered instead, following the `start`,
+    /// `reversed` and `type` attributes of the list and the `value` attribute of
+    /// the items.
+    ///
+    /// For [`OutputFormat::Markdown`], the bullet should be one of `* `, `- `
+    /// or `+ `.
+    pub fn bullet(mut self, bullet: impl Into<String>) -> Self {
+        self.bullet = bullet.into();
+        self
+    }
+
+    /// Sets by how many columns each level of nested lists is indented.
+    /// Defaults to 2. Lines of a list item after the first one are aligned
+    /// with the text after its marker.
+    ///
+    /// This is ignored for [`OutputFormat::Markdown`], where nested lists have
+    /// to be aligned with the text of the enclosing list item.
+    pub fn list_indent(mut self, width: usize) -> Self {
+        self.list_indent = width;
+        self
+    }
+
+    /// Draws ASCII borders around table cells. Defaults to `false`, which
+    /// separates the columns with spaces instead.
+    ///
+    /// Either way, tables are laid out as a grid with aligned columns and rows
+    /// made up of `th` cells are underlined. In [`OutputFormat::Markdown`],
+    /// tables are always written as pipe tables.
+    ///
+    /// ```
+    /// use nanohtml2text::{Converter, LineEnding, Options};
+    ///
+    /// let html = "<table><tr><th>Item</th><th>Price</th></tr>\
+    ///             <tr><td>Coffee</td><td>3.00</td></tr></table>";
+    /// let options = Options::new().line_ending(LineEnding::Lf);
+    /// assert_eq!(
+    ///     Converter::new(options.clone()).convert(html),
+    ///     "Item    Price\n------  -----\nCoffee  3.00\n\n"
+    /// );
+    /// assert_eq!(
+    ///     Converter::new(options.table_borders(true)).convert(html),
+    ///     "+--------+-------+\n\
+    ///      | Item   | Price |\n\
+    ///      +========+=======+\n\
+    ///      | Coffee | 3.00  |\n\
+    ///      +--------+-------+\n\n"
+    /// );
+    /// ```
+    pub fn table_borders(mut self, borders: bool) -> Self {
+        self.table_borders = borders;
+        self
+    }
+
+    /// Guesses which tables are only used for laying out the page and writes
+    /// their cells as ordinary blocks of text instead of a grid. Defaults to
+    /// `true`.
+    ///
+    /// Tables with `role="presentation"`, tables with a single column and
+    /// tables containing other tables or block elements like paragraphs are
+    /// considered layout tables.
+    pub fn detect_layout_tables(mut self, detect: bool) -> Self {
+        self.detect_layout_tables = detect;
+        self
+    }
+
+    /// Sets the URL of the document, which relative hrefs like `/wiki/yet` or
+    /// `../index.html` are resolved against. A `base` element in the document
+    /// is resolved against it and takes precedence. By default, relative hrefs
+    /// are left unchanged unless the document has a `base` element with an
+    /// absolute URL.
+    ///
+    /// ```
+    /// use nanohtml2text::{Converter, Options};
+    ///
+    /// let options = Options::new().base_url("https://example.com/docs/");
+    /// let text = Converter::new(options).convert("<a href=\"../about\">About</a>");
+    /// assert_eq!(text, "About (https://example.com/about)");
+    /// ```
+    pub fn base_url(mut self, url: impl Into<String>) -> Self {
+        self.base_url = Some(url.into());
+        self
+    }
+
+    /// When converting an email whose text is offered both as HTML and as
+    /// plain text, uses the plain text instead of converting the HTML.
+    /// Defaults to `false`. See [`Converter::convert_email`](crate::Converter::convert_email).
+    #[cfg(feature = "mime")]
+    pub fn prefer_plain_text(mut self, prefer: bool) -> Self {
+        self.prefer_plain_text = prefer;
+        self
+    }
+
+    pub(crate) fn is_silenced(&self, element: &str) -> bool {
+        self.silenced.iter().any(|silenced| silenced == element)
+    }
+
+    pub(crate) fn newline(&self) -> &'static str {
+        self.line_ending.as_str()
+    }
+
+    /// The marker in front of the items of unordered lists.
+    pub(crate) fn list_bullet(&self) -> &str {
+        match self.format {
+            OutputFormat::Markdown if self.bullet.is_empty() => "* ",
+            _ => &self.bullet,
+        }
+    }
+
+    /// The number of line breaks inserted around block elements.
+    pub(crate) fn block_lines(&self) -> usize {
+        match self.block_separator {
+            BlockSeparator::BlankLine => 2,
+            BlockSeparator::Newline => 1,
+        }
+    }
+}
@@ -0,0 +1,1365 @@
+use std::borrow::Cow;
+use std::collections::{HashMap, VecDeque};
+use std::fmt;
+
+use crate::attributes::{attribute_value, has_attribute, is_html_whitespace};
+use crate::escape::decode_entities;
+use crate::link::Link;
+use crate::list::{self, List};
+use crate::markdown;
+use crate::options::{ImageStyle, LinkStyle, Options, OutputFormat};
+use crate::table::{self, Grid, Table};
+use crate::url;
+use crate::width::display_width;
+use crate::wrap::wrap_line;
+
+/// Elements that never have a closing tag.
+const VOID_ELEMENTS: &[&str] = &[
+    "area", "base", "br", "col", "embed", "hr", "img", "input", "link", "meta", "source", "track",
+    "wbr",
+];
+
+/// How much of the input may be held back while waiting for more of it, e.g.
+/// to find the end of a tag or to look ahead to the end of a table. Beyond
+/// that, the input is handled as if it was complete, so that streaming
+/// conversions only need a bounded amount of memory.
+///
+/// Searching for the end of a tag and looking ahead never go further than
+/// this either, so that the time a conversion takes stays linear even if
+/// tags are never closed.
+const LOOKAHEAD_LIMIT: usize = 64 * 1024;
+
+/// Elements whose content is raw text, in which tags are not recognized.
+const RAW_TEXT_ELEMENTS: &[&str] = &[
+    "script", "style", "textarea", "title", "xmp", "iframe", "noembed", "noframes", "noscript",
+];
+
+/// How many levels of nested lists are indented, so that the indentation of
+/// deeply nested lists doesn't make the output grow quadratically.
+const MAX_LIST_DEPTH: usize = 16;
+
+/// How many levels of nested Markdown block quotes are written, for the same
+/// reason.
+const MAX_QUOTE_DEPTH: usize = 16;
+
+/// How many levels of nested tables are laid out as data tables. Each level
+/// lays out the text of the tables inside it again, so deeper tables are
+/// written like layout tables.
+const MAX_TABLE_DEPTH: usize = 16;
+
+/// How much input [`Renderer::render_into`] handles at a time before writing
+/// the finished lines, so that the whole output is never buffered.
+const WINDOW: usize = 4 * LOOKAHEAD_LIMIT;
+
+/// How much capacity the buffers kept between conversions may retain, so that
+/// one large conversion doesn't hold on to its memory.
+const MAX_SCRATCH_CAPACITY: usize = LOOKAHEAD_LIMIT;
+
+/// An `a` tag that has been opened but not closed yet.
+struct OpenLink {
+    href: Option<String>,
+    title: Option<String>,
+    /// The byte offset of the tag in the HTML.
+    offset: usize,
+    /// The text written since the tag was opened.
+    text: String,
+    /// For Markdown links, the length of `pending` before the opening bracket
+    /// was added to it.
+    markdown_bracket: Option<usize>,
+}
+
+/// Text that is being written, either the whole output or the content of a
+/// table cell.
+///
+/// Line breaks are not written immediately but counted, so that consecutive
+/// block elements don't produce more than one empty line and so that a line
+/// break at the start of the output can be left out entirely.
+struct Output {
+    /// All finished lines.
+    out: String,
+    /// Whether finished lines have already been taken from `out`.
+    taken: bool,
+    /// The line that is currently being written.
+    line: String,
+    /// Whether `line` contains preformatted text, which must not be wrapped.
+    line_is_preformatted: bool,
+    /// Line breaks that still have to be written before the next text.
+    breaks: usize,
+    /// Whether whitespace was collapsed into a space that still has to be
+    /// written before the next text.
+    space: bool,
+    /// Markup that starts with the next text: the marker of a list item, the
+    /// `#`s of a Markdown heading and inline markup like an opening `**`, in
+    /// the order they are written. It is only written once there is text, so
+    /// that empty elements don't leave any markup behind.
+    marker: String,
+    heading: String,
+    pending: String,
+    /// How many Markdown block quotes the current block is in. Each one adds
+    /// a `> ` in front of its lines, up to [`MAX_QUOTE_DEPTH`].
+    quote_depth: usize,
+    /// The prefix that was active when the current line was started.
+    line_prefix: String,
+    /// The prefix for the lines the current line is wrapped into. This differs
+    /// from `line_prefix` for list items, whose wrapped lines are aligned
+    /// with the text after the marker.
+    continuation_prefix: String,
+    /// Whether the next text starts a list item.
+    item_start: bool,
+    lists: Vec<List>,
+    wrap_width: Option<usize>,
+}
+
+/// The buffers of an [`Output`], which can be kept between conversions so
+/// that they don't have to be allocated again.
+#[derive(Debug, Default)]
+pub(crate) struct Scratch {
+    out: String,
+    line: String,
+    marker: String,
+    heading: String,
+    pending: String,
+    line_prefix: String,
+    continuation_prefix: String,
+}
+
+impl Output {
+    fn new(wrap_width: Option<usize>) -> Self {
+        Output::with_scratch(wrap_width, Scratch::default())
+    }
+
+    fn with_scratch(wrap_width: Option<usize>, scratch: Scratch) -> Self {
+        Output {
+            out: scratch.out,
+            taken: false,
+            line: scratch.line,
+            line_is_preformatted: false,
+            breaks: 0,
+            space: false,
+            marker: scratch.marker,
+            heading: scratch.heading,
+            pending: scratch.pending,
+            quote_depth: 0,
+            line_prefix: scratch.line_prefix,
+            continuation_prefix: scratch.continuation_prefix,
+            item_start: false,
+            lists: Vec::new(),
+            wrap_width,
+        }
+    }
+
+    /// Empties the buffers so that they can be used for the next conversion.
+    fn into_scratch(self) -> Scratch {
+        let mut scratch = Scratch {
+            out: self.out,
+            line: self.line,
+            marker: self.marker,
+            heading: self.heading,
+            pending: self.pending,
+            line_prefix: self.line_prefix,
+            continuation_prefix: self.continuation_prefix,
+        };
+        for buffer in [
+            &mut scratch.out,
+            &mut scratch.line,
+            &mut scratch.marker,
+            &mut scratch.heading,
+            &mut scratch.pending,
+            &mut scratch.line_prefix,
+            &mut scratch.continuation_prefix,
+        ] {
+            buffer.clear();
+            buffer.shrink_to(MAX_SCRATCH_CAPACITY);
+        }
+        scratch
+    }
+}
+
+/// A `table` element whose end hasn't been reached yet.
+struct OpenTable {
+    /// `None` for layout tables, whose cells are written as they come.
+    table: Option<Table>,
+    /// While a cell is open, the output that was active before it, which the
+    /// table will be written to.
+    outer: Option<Output>,
+    /// The `colspan` and whether the open cell is a header cell.
+    cell: (usize, bool),
+}
+
+/// Keeps track of the output while walking through the HTML.
+pub(crate) struct Renderer<'o> {
+    options: &'o Options,
+    output: Output,
+    tables: Vec<OpenTable>,
+    /// How many of the open tables are data tables.
+    data_tables: usize,
+    /// Open elements that may preserve whitespace. The flag is `false` for
+    /// elements that are only tracked because they have the same name as the
+    /// enclosing whitespace preserving element, so that the right closing tag
+    /// is matched.
+    preformatted: Vec<(String, bool)>,
+    /// A newline directly after the start of a preformatted element is ignored.
+    skip_newline: bool,
+    /// The fence of the open Markdown code block, empty if there is none.
+    fence: String,
+    /// How many `pre` elements inside the open Markdown code block are open.
+    nested_pres: usize,
+    /// Whether the input is inside a comment.
+    in_comment: bool,
+    /// The silenced element whose content is being skipped.
+    silenced: Option<String>,
+    /// The element with raw text inside the silenced element, like a `script`
+    /// in the `head`, whose content is skipped without looking for a `base`.
+    silenced_raw_text: Option<&'static str>,
+    link: Option<OpenLink>,
+    /// The byte offset of the tag that is being handled.
+    offset: usize,
+    /// How many bytes of the input previous calls to `feed` have handled.
+    fed: usize,
+    /// The number of items of `ol` lists that are yet to start, by the byte
+    /// offset of their start tag in the input, counted in advance together
+    /// with an enclosing reversed list.
+    item_counts: VecDeque<(usize, usize)>,
+    /// All links, if they are collected.
+    links: Option<Vec<Link>>,
+    /// The hrefs of links written as footnotes, in order of their numbers.
+    footnotes: Vec<String>,
+    /// The numbers of the footnotes by href.
+    footnote_numbers: HashMap<String, usize>,
+    /// The URL relative hrefs are resolved against.
+    base_url: Option<String>,
+    /// Whether a `base` element with an href has been found.
+    found_base: bool,
+}
+
+impl<'o> Renderer<'o> {
+    pub(crate) fn new(options: &'o Options) -> Self {
+        Renderer::with_scratch(options, Scratch::default())
+    }
+
+    /// Creates a renderer that reuses the buffers of a previous one.
+    pub(crate) fn with_scratch(options: &'o Options, scratch: Scratch) -> Self {
+        Renderer {
+            options,
+            output: Output::with_scratch(options.wrap_width, scratch),
+            tables: Vec::new(),
+            data_tables: 0,
+            preformatted: Vec::new(),
+            skip_newline: false,
+            fence: String::new(),
+            nested_pres: 0,
+            in_comment: false,
+            silenced: None,
+            silenced_raw_text: None,
+            link: None,
+            offset: 0,
+            fed: 0,
+            item_counts: VecDeque::new(),
+            links: None,
+            footnotes: Vec::new(),
+            footnote_numbers: HashMap::new(),
+            base_url: options.base_url.clone(),
+            found_base: false,
+        }
+    }
+
+    /// Converts the complete `html` and writes the text to `sink`. Returns the
+    /// buffers for the next conversion.
+    pub(crate) fn render_into<W: fmt::Write>(
+        mut self,
+        html: &str,
+        sink: &mut W,
+    ) -> (fmt::Result, Scratch) {
+        let mut start = 0;
+        while html.len() - start > WINDOW {
+            let mut end = start + WINDOW;
+            while !html.is_char_boundary(end) {
+                end -= 1;
+            }
+            start += self.feed(&html[start..end], false);
+            if let Err(error) = self.write_finished(sink) {
+                return (Err(error), self.output.into_scratch());
+            }
+        }
+        self.feed(&html[start..], true);
+        self.finish_into(sink)
+    }
+
+    /// Collects the links in `html` instead of rendering it.
+    pub(crate) fn links(mut self, html: &str) -> Vec<Link> {
+        self.links = Some(Vec::new());
+        self.feed(html, true);
+        self.close_link();
+        self.links.unwrap_or_default()
+    }
+
+    /// Handles as much of `html` as possible and returns how many bytes of it
+    /// were handled. Unless the input is `complete`, a tag or entity at the
+    /// end might be cut off, so it is left for the next call, which has to
+    /// start with the rest of `html`.
+    pub(crate) fn feed(&mut self, html: &str, complete: bool) -> usize {
+        let mut i = 0;
+        // the position of the next '>', so that text with lots of '<' but no
+        // '>' isn't searched again and again
+        let mut next_gt = 0;
+        while i < html.len() {
+            let complete = complete || html.len() - i > LOOKAHEAD_LIMIT;
+            if self.in_comment {
+                i += self.skip_comment(&html[i..], complete);
+                if self.in_comment {
+                    break;
+                }
+                continue;
+            }
+            if let Some(name) = self.silenced.take() {
+                i += self.skip_silenced(&html[i..], name, complete);
+                if self.silenced.is_some() {
+                    break;
+                }
+                continue;
+            }
+
+            match html[i..].find('<') {
+                None => {
+                    let end = if complete {
+                        html.len()
+                    } else {
+                        i + text_end(&html[i..])
+                    };
+                    if end > i {
+                        self.text(&html[i..end]);
+                    }
+                    i = end;
+                    break;
+                }
+                Some(text_segment) => {
+                    if text_segment > 0 {
+                        self.text(&html[i..i + text_segment]);
+                        i += text_segment;
+                    }
+                    if next_gt <= i {
+                        next_gt = html[i..].find('>').map_or(html.len(), |gt| i + gt);
+                    }
+                    if complete && next_gt == html.len() && !html[i + 1..].starts_with("!--") {
+                        // was not actually a tag, so reinsert the '<'
+                        self.text("<");
+                        i += 1;
+                        continue;
+                    }
+                    self.offset = i;
+                    match self.handle_tag(&html[i + 1..], complete) {
+                        Some(len) => i += 1 + len,
+                        None => break,
+                    }
+                }
+            }
+        }
+        self.fed += i;
+        i
+    }
+
+    /// Skips the content of a comment up to and including its end. Returns the
+    /// number of bytes skipped.
+    fn skip_comment(&mut self, html: &str, complete: bool) -> usize {
+        match html.find("-->") {
+            Some(end) => {
+                self.in_comment = false;
+                end + 3
+            }
+            None if complete => html.len(),
+            // the end might be cut off
+            None => html.trim_end_matches('-').len(),
+        }
+    }
+
+    /// Skips the content of a silenced element up to and including its end tag.
+    /// Returns the number of bytes skipped.
+    fn skip_silenced(&mut self, html: &str, name: String, complete: bool) -> usize {
+        let raw_text = RAW_TEXT_ELEMENTS.contains(&name.as_str());
+        let (content, skipped) = match end_tag(html, &name) {
+            Some(end) => end,
+            None => {
+                // the end tag or a base element might be cut off
+                let end = if complete {
+                    html.len()
+                } else {
+                    html.rfind('<').unwrap_or(html.len())
+                };
+                self.silenced = Some(name);
+                (end, end)
+            }
+        };
+        if raw_text {
+            return skipped;
+        }
+
+        // the base URL is usually set in the head, but not by the content of
+        // scripts or the like in it
+        let content = &html[..content];
+        let mut raw_text = self.silenced_raw_text.take();
+        let mut search = 0;
+        while !self.found_base {
+            if let Some(name) = raw_text {
+                match end_tag(&content[search..], name) {
+                    Some((_, end)) => search += end,
+                    None => break,
+                }
+                raw_text = None;
+            }
+            let i = match content[search..].find('<') {
+                Some(i) => search + i + 1,
+                None => break,
+            };
+            search = i;
+            let tag = &content[i..];
+            raw_text = RAW_TEXT_ELEMENTS.iter().copied().find(|&name| {
+                tag.get(..name.len())
+                    .is_some_and(|start| start.eq_ignore_ascii_case(name))
+                    && tag[name.len()..]
+                        .starts_with(|c: char| is_html_whitespace(c) || c == '/' || c == '>')
+            });
+            if tag
+                .get(..4)
+                .is_some_and(|name| name.eq_ignore_ascii_case("base"))
+                && tag[4..].starts_with(is_html_whitespace)
+            {
+                let attribs = lookahead(&tag[4..]);
+                let end = tag_end(attribs, true).unwrap_or(attribs.len());
+                self.set_base_url(Some(&attribs[..end]));
+                // continue after the tag, so that no text is scanned twice
+                search += 4 + end;
+            }
+        }
+        if self.silenced.is_some() {
+            self.silenced_raw_text = raw_text;
+        }
+        skipped
+    }
+
+    /// Function to parse and handle the individual tags.
+    /// Assumes that there was a '<' before the given string
+    ///
+    /// Returns the byte length to skip, or `None` if the tag is cut off at the
+    /// end of an incomplete input.
+    fn handle_tag(&mut self, s: &str, complete: bool) -> Option<usize> {
+        if !complete && s.len() < 3 {
+            // too short to even tell if this is a comment
+            return None;
+        }
+        if let Some(comment) = s.strip_prefix("!--") {
+            // HTML comment, which may be closed right away with `<!-->` or
+            // `<!--->`
+            if comment.starts_with('>') {
+                return Some(4);
+            }
+            if comment.starts_with("->") {
+                return Some(5);
+            }
+            if !complete && comment.len() < 2 {
+                return None;
+            }
+            self.in_comment = true;
+            return Some(3);
+        }
+        if !s.starts_with(|c: char| c.is_ascii_alphabetic() || matches!(c, '/' | '!' | '?')) {
+            // was not actually a tag, so reinsert the '<'
+            self.text("<");
+            return Some(0);
+        }
+
+        let truncated = s.len() > LOOKAHEAD_LIMIT;
+        let (s, complete) = if truncated {
+            (lookahead(s), true)
+        } else {
+            (s, complete)
+        };
+
+        let (tag, more) = match tag_end(s, complete) {
+            Some(end) if end > 0 => (&s[..end], &s[end + 1..]),
+            None if !complete => return None,
+            _ => {
+                // was not actually a tag, so reinsert the '<'
+                self.text("<");
+                return Some(0);
+            }
+        };
+
+        let (name, attribs) = if let Some((name, attribs)) = tag.split_once(char::is_whitespace) {
+            (name, Some(attribs))
+        } else {
+            (tag, None)
+        };
+        let name = if name.bytes().any(|b| b.is_ascii_uppercase()) {
+            Cow::Owned(name.to_ascii_lowercase())
+        } else {
+            Cow::Borrowed(name)
+        };
+
+        let markdown = self.options.format == OutputFormat::Markdown;
+        match name.as_ref() {
+            "a" => {
+                let href = attribs.and_then(|attribs| self.href(attribs));
+                let title = attribs.and_then(|attribs| attribute_value(attribs, "title"));
+                self.close_link();
+                let markdown_bracket = (markdown
+                    && href.is_some()
+                    && self.options.link_style != LinkStyle::TextOnly
+                    && !self.is_preformatted())
+                .then(|| {
+                    let len = self.output.pending.len();
+                    self.output.pending.push('[');
+                    len
+                });
+                self.link = Some(OpenLink {
+                    href,
+                    title,
+                    offset: self.offset,
+                    text: String::new(),
+                    markdown_bracket,
+                });
+            }
+            "/a" => self.close_link(),
+            "base" => self.set_base_url(attribs),
+            "img" => self.image(attribs.unwrap_or("")),
+            "br" | "br/" => self.line_break(),
+            "ul" => self.output.lists.push(List::unordered()),
+            "ol" => {
+                let attribute = |name| attribs.and_then(|attribs| attribute_value(attribs, name));
+                let reversed = attribs.is_some_and(|attribs| has_attribute(attribs, "reversed"));
+                let item_count = if reversed {
+                    self.item_count(tag.len() + 2, more, complete)?
+                } else {
+                    0
+                };
+                self.output.lists.push(List::ordered(
+                    attribute("start").as_deref(),
+                    reversed,
+                    attribute("type").as_deref(),
+                    || item_count,
+                ));
+            }
+            "/ul" | "/ol" => {
+                self.output.lists.pop();
+                self.ensure_breaks(1);
+            }
+            "li" => {
+                self.ensure_breaks(1);
+                let value = attribs.and_then(|attribs| attribute_value(attribs, "value"));
+                let bullet = self.options.list_bullet();
+                let marker = match self.output.lists.last_mut() {
+                    Some(list) => list.next_marker(value.as_deref(), bullet, markdown),
+                    None => bullet.to_string(),
+                };
+                // drop the markers of empty list items
+                self.output.marker = marker;
+                self.output.item_start = true;
+            }
+            "p" => self.block_break(),
+            "h1" | "h2" | "h3" | "h4" | "h5" | "h6" => {
+                self.block_break();
+                if markdown {
+                    let level = usize::from(name.as_bytes()[1] - b'0');
+                    self.output.heading = format!("{} ", "#".repeat(level));
+                }
+            }
+            "/h1" | "/h2" | "/h3" | "/h4" | "/h5" | "/h6" => {
+                self.block_break();
+                // drop the marker of an empty heading
+                self.output.heading.clear();
+            }
+            "pre" if markdown && !self.fence.is_empty() => {
+                // a code block can't contain another one
+                self.nested_pres += 1;
+            }
+            "pre" => {
+                if markdown {
+                    self.fence = match code_block_end(more) {
+                        Some(end) => markdown::fence(&decode_entities(&more[..end]), false),
+                        // the content might contain more backticks
+                        None if !complete => return None,
+                        None => markdown::fence(&decode_entities(more), truncated),
+                    };
+                }
+                self.block_break();
+                if markdown {
+                    let fence = self.fence.clone();
+                    self.write_preformatted(&fence);
+                    self.output.breaks += 1;
+                }
+            }
+            "/pre" if markdown && self.nested_pres > 0 => self.nested_pres -= 1,
+            "/pre" => {
+                self.close_code_block();
+                self.block_break();
+            }
+            "blockquote" | "/blockquote" if markdown => {
+                self.block_break();
+                if name == "blockquote" {
+                    self.output.quote_depth += 1;
+                } else {
+                    self.output.quote_depth = self.output.quote_depth.saturating_sub(1);
+                }
+            }
+            "table" => {
+                let presentation = attribs
+                    .and_then(|attribs| attribute_value(attribs, "role"))
+                    .is_some_and(|role| {
+                        matches!(
+                            role.trim().to_ascii_lowercase().as_str(),
+                            "presentation" | "none"
+                        )
+                    });
+                let layout = self.data_tables >= MAX_TABLE_DEPTH
+                    || self.options.detect_layout_tables
+                        && (presentation || table::is_layout_table(more, complete)?);
+                self.block_break();
+                if !layout {
+                    self.data_tables += 1;
+                }
+                self.tables.push(OpenTable {
+                    table: (!layout).then(Table::default),
+                    outer: None,
+                    cell: (1, false),
+                });
+            }
+            "/table" => self.end_table(),
+            "tr" | "/tr" if !self.tables.is_empty() => {
+                self.end_cell();
+                match &mut self.tables.last_mut().unwrap().table {
+                    Some(table) if name == "tr" => table.start_row(),
+                    Some(_) => {}
+                    None => self.ensure_breaks(1),
+                }
+            }
+            "td" | "th" if !self.tables.is_empty() => {
+                let colspan = attribs
+                    .and_then(|attribs| attribute_value(attribs, "colspan"))
+                    .and_then(|colspan| colspan.trim().parse().ok())
+                    .unwrap_or(1);
+                self.start_cell(colspan, name == "th");
+            }
+            "/td" | "/th" => self.end_cell(),
+            "caption" | "/caption" => self.block_break(),
+            "b" | "strong" if markdown => self.open_inline("**"),
+            "/b" | "/strong" if markdown => self.close_inline("**"),
+            "i" | "em" if markdown => self.open_inline("_"),
+            "/i" | "/em" if markdown => self.close_inline("_"),
+            name if self.options.is_silenced(name) => {
+                // the content is skipped up to the end tag
+                self.silenced = Some(name.to_string());
+                return Some(tag.len() + 1);
+            }
+            // other/unknown tags are just discarded
+            _ => {}
+        }
+
+        self.track_preformatted(&name, tag.ends_with('/'), attribs);
+
+        Some(tag.len() + 1)
+    }
+
+    /// Keeps track of whether whitespace has to be preserved, which is the
+    /// case inside `pre` and `textarea` as well as elements styled with
+    /// `white-space: pre`.
+    fn track_preformatted(&mut self, name: &str, self_closing: bool, attribs: Option<&str>) {
+        let in_same_element = |preformatted: &[(String, bool)], name: &str| {
+            preformatted
+                .last()
+                .is_some_and(|(element, _)| element == name)
+        };
+
+        if let Some(name) = name.strip_prefix('/') {
+            if in_same_element(&self.preformatted, name) {
+                self.preformatted.pop();
+            }
+        } else if !self_closing && !VOID_ELEMENTS.contains(&name) {
+            let preserves = matches!(name, "pre" | "textarea")
+                || attribs
+                    .and_then(|attribs| attribute_value(attribs, "style"))
+                    .is_some_and(|style| preserves_whitespace(&style));
+            if preserves || in_same_element(&self.preformatted, name) {
+                self.preformatted.push((name.to_string(), preserves));
+                self.skip_newline |= preserves;
+            }
+        }
+    }
+
+    /// Writes the alt text of an image.
+    fn image(&mut self, attribs: &str) {
+        let attribute = |name| attribute_value(attribs, name);
+        let alt = match attribute("alt") {
+            Some(alt) if !alt.trim().is_empty() => alt,
+            // decorative image
+            _ => return,
+        };
+        let is_pixel = |name| {
+            attribute(name).is_some_and(|size| {
+                let size = size.trim();
+                let size = size.strip_suffix("px").unwrap_or(size);
+                size.parse::<f32>().is_ok_and(|size| size <= 1.0)
+            })
+        };
+        if self.options.image_style == ImageStyle::Omit || is_pixel("width") && is_pixel("height") {
+            return;
+        }
+        let src = attribute("src")
+            .filter(|_| self.options.image_sources)
+            .map(|src| self.resolve_url(&src))
+            .filter(|src| !src.is_empty());
+
+        let markdown = self.options.format == OutputFormat::Markdown;
+        let labeled = self.options.image_style == ImageStyle::Labeled;
+        if markdown && src.is_some() {
+            // the syntax already marks it as an image
+            self.write("![");
+            self.decoded_text(&alt);
+        } else if labeled && markdown {
+            // brackets would have to be escaped
+            self.decoded_text("image: ");
+            self.decoded_text(&alt);
+        } else if labeled {
+            self.decoded_text("[image: ");
+            self.decoded_text(alt.trim());
+            self.decoded_text("]");
+        } else {
+            self.decoded_text(&alt);
+        }
+        match src {
+            Some(src) if markdown => {
+                self.push("](");
+                self.push(&markdown::escape_url(&src));
+                self.push(")");
+            }
+            Some(src) => {
+                self.output.space = true;
+                self.write("(");
+                self.push(&src);
+                self.push(")");
+            }
+            None => {}
+        }
+    }
+
+    /// The decoded and resolved href of an `a` element, if it links anywhere.
+    fn href(&self, attribs: &str) -> Option<String> {
+        attribute_value(attribs, "href")
+            .filter(|href| !href.trim_start().starts_with("javascript:"))
+            .map(|href| self.resolve_url(&href))
+    }
+
+    /// Handles a `base` element. Only the first one with an href counts.
+    fn set_base_url(&mut self, attribs: Option<&str>) {
+        if self.found_base {
+            return;
+        }
+        if let Some(href) = attribs.and_then(|attribs| attribute_value(attribs, "href")) {
+            self.base_url = Some(self.resolve_url(&href));
+            self.found_base = true;
+        }
+    }
+
+    fn resolve_url(&self, href: &str) -> String {
+        match &self.base_url {
+            Some(base) => url::resolve(base, href),
+            None => href.to_string(),
+        }
+    }
+
+    fn is_preformatted(&self) -> bool {
+        self.preformatted.iter().any(|(_, preserves)| *preserves)
+    }
+
+    fn text(&mut self, text: &str) {
+        self.decoded_text(&decode_entities(text));
+    }
+
+    /// Writes text whose entities have already been decoded.
+    fn decoded_text(&mut self, text: &str) {
+        if self.is_preformatted() {
+            self.preformatted_text(text);
+            return;
+        }
+
+        let markdown = self.options.format == OutputFormat::Markdown;
+        let text = if markdown {
+            markdown::escape_text(text)
+        } else {
+            Cow::Borrowed(text)
+        };
+        // collapse spaces
+        for (i, word) in text.split(is_html_whitespace).enumerate() {
+            if i > 0 {
+                self.output.space = true;
+            }
+            if word.is_empty() {
+                continue;
+            }
+            if markdown && (self.output.breaks > 0 || self.output.line.is_empty()) {
+                self.write(&markdown::escape_line_start(word));
+            } else {
+                self.write(word);
+            }
+        }
+    }
+
+    fn preformatted_text(&mut self, text: &str) {
+        let text = text.replace("\r\n", "\n").replace('\r', "\n");
+        let mut text = text.as_str();
+        if std::mem::take(&mut self.skip_newline) {
+            text = text.strip_prefix('\n').unwrap_or(text);
+        }
+
+        for (i, line) in text.split('\n').enumerate() {
+            if i > 0 {
+                // unlike other line breaks, these are never collapsed
+                self.output.breaks += 1;
+                self.output.space = false;
+            }
+            if !line.is_empty() {
+                self.write_preformatted(line);
+            }
+        }
+    }
+
+    fn write_preformatted(&mut self, s: &str) {
+        self.write(s);
+        self.output.line_is_preformatted = true;
+    }
+
+    /// Writes some text that does not contain any line breaks.
+    fn write(&mut self, s: &str) {
+        self.flush_breaks();
+        if std::mem::take(&mut self.output.space) && !self.output.line.is_empty() {
+            self.push(" ");
+        }
+        let output = &mut self.output;
+        let markup = [
+            std::mem::take(&mut output.marker),
+            std::mem::take(&mut output.heading),
+            std::mem::take(&mut output.pending),
+        ];
+        for markup in markup.iter().filter(|markup| !markup.is_empty()) {
+            self.push(markup);
+        }
+        self.push(s);
+        self.output.item_start = false;
+    }
+
+    fn push(&mut self, s: &str) {
+        if self.output.line.is_empty() {
+            let (indent, continuation_indent) = self.list_indentation();
+            let output = &mut self.output;
+            for (prefix, indent) in [
+                (&mut output.line_prefix, indent),
+                (&mut output.continuation_prefix, continuation_indent),
+            ] {
+                prefix.clear();
+                for _ in 0..output.quote_depth.min(MAX_QUOTE_DEPTH) {
+                    prefix.push_str("> ");
+                }
+                prefix.push_str(&" ".repeat(indent));
+            }
+        }
+        self.output.line.push_str(s);
+        if let Some(link) = &mut self.link {
+            link.text.push_str(s);
+        }
+    }
+
+    fn close_link(&mut self) {
+        let link = match self.link.take() {
+            Some(link) => link,
+            None => return,
+        };
+        if let Some(links) = &mut self.links {
+            links.push(Link {
+                text: link.text.trim().to_string(),
+                href: link.href,
+                title: link.title,
+                offset: link.offset,
+            });
+            return;
+        }
+        let href = match (link.href, self.options.link_style) {
+            (Some(_), LinkStyle::TextOnly) | (None, _) => return,
+            (Some(href), _) => href,
+        };
+        let footnotes = self.options.link_style == LinkStyle::Footnote;
+
+        let text = link.text.trim();
+        if let Some(bracket) = link.markdown_bracket {
+            if text.is_empty() {
+                // the opening bracket was never written
+                self.output.pending.truncate(bracket);
+                if markdown::is_autolink(&href) {
+                    self.write("<");
+                    self.push(&markdown::escape_url(&href));
+                    self.push(">");
+                } else {
+                    self.write("[");
+                    self.push(&markdown::escape_text(&href));
+                    self.push("](");
+                    self.push(&markdown::escape_url(&href));
+                    self.push(")");
+                }
+            } else if footnotes {
+                let n = self.footnote(href);
+                self.push(&format!("][{}]", n));
+            } else {
+                self.push("](");
+                self.push(&markdown::escape_url(&href));
+                self.push(")");
+            }
+        } else if text.is_empty() {
+            self.write(&href);
+        } else if footnotes && text != href {
+            let n = self.footnote(href);
+            self.push(&format!("[{}]", n));
+        } else if text != href {
+            self.output.space = true;
+            self.write("(");
+            self.push(&href);
+            self.push(")");
+        }
+    }
+
+    /// Returns the number of the footnote for `href`, adding it if there is none
+    /// yet.
+    fn footnote(&mut self, href: String) -> usize {
+        if let Some(&n) = self.footnote_numbers.get(&href) {
+            return n;
+        }
+        self.footnotes.push(href.clone());
+        self.footnote_numbers.insert(href, self.footnotes.len());
+        self.footnotes.len()
+    }
+
+    /// Writes the list of footnotes at the end of the output.
+    fn write_footnotes(&mut self) {
+        if self.footnotes.is_empty() {
+            return;
+        }
+        // the list doesn't belong to any element that is still open
+        self.output.lists.clear();
+        self.output.quote_depth = 0;
+        self.output.marker.clear();
+        self.output.heading.clear();
+        self.output.pending.clear();
+
+        self.block_break();
+        let markdown = self.options.format == OutputFormat::Markdown;
+        for (i, href) in std::mem::take(&mut self.footnotes).iter().enumerate() {
+            if i > 0 {
+                self.output.breaks += 1;
+            }
+            let href = if markdown {
+                markdown::escape_url(href)
+            } else {
+                href.into()
+            };
+            // written like preformatted text so that long URLs aren't wrapped
+            // onto their own line
+            self.write_preformatted(&format!("[{}]: {}", i + 1, href));
+        }
+    }
+
+    fn start_cell(&mut self, colspan: usize, header: bool) {
+        self.end_cell();
+        let table = self.tables.last_mut().unwrap();
+        if table.table.is_none() {
+            // the cells of layout tables are just blocks of text
+            self.ensure_breaks(1);
+            return;
+        }
+        table.cell = (colspan, header);
+        // cells are laid out later, so they aren't wrapped
+        table.outer = Some(std::mem::replace(&mut self.output, Output::new(None)));
+    }
+
+    fn end_cell(&mut self) {
+        let outer = match self.tables.last_mut().and_then(|table| table.outer.take()) {
+            Some(outer) => outer,
+            None => return,
+        };
+        let mut cell = std::mem::replace(&mut self.output, outer);
+        // trailing line breaks are not needed inside a cell
+        cell.breaks = 0;
+        let text = self.finish_output(cell);
+
+        let open = self.tables.last_mut().unwrap();
+        let (colspan, header) = open.cell;
+        if let Some(table) = &mut open.table {
+            table.add_cell(&text, self.options.newline(), colspan, header);
+        }
+    }
+
+    fn end_table(&mut self) {
+        self.end_cell();
+        let table = match self.tables.pop() {
+            Some(OpenTable {
+                table: Some(table), ..
+            }) => {
+                self.data_tables -= 1;
+                table
+            }
+            Some(_) => {
+                self.block_break();
+                return;
+            }
+            None => return,
+        };
+        let grid = match self.options.format {
+            OutputFormat::Markdown => Grid::Markdown,
+            OutputFormat::PlainText if self.options.table_borders => Grid::Bordered,
+            OutputFormat::PlainText => Grid::Plain,
+        };
+
+        self.block_break();
+        for (i, line) in table.render(grid).iter().enumerate() {
+            if i > 0 {
+                self.output.breaks += 1;
+            }
+            self.write_preformatted(line);
+        }
+        self.block_break();
+    }
+
+    /// Writes the closing fence of the open Markdown code block, if any.
+    fn close_code_block(&mut self) {
+        if self.fence.is_empty() {
+            return;
+        }
+        let fence = std::mem::take(&mut self.fence);
+        self.output.breaks = self.output.breaks.max(1);
+        self.write_preformatted(&fence);
+    }
+
+    /// Starts inline markup like emphasis, which is only written together with
+    /// the following text.
+    fn open_inline(&mut self, marker: &str) {
+        if !self.is_preformatted() {
+            self.output.pending.push_str(marker);
+        }
+    }
+
+    /// Ends inline markup. If it was never written, because there was no text
+    /// since it was started, it is dropped instead.
+    fn close_inline(&mut self, marker: &str) {
+        if self.is_preformatted() {
+            return;
+        }
+        if self.output.pending.ends_with(marker) {
+            self.output
+                .pending
+                .truncate(self.output.pending.len() - marker.len());
+        } else {
+            self.flush_breaks();
+            self.push(marker);
+        }
+    }
+
+    /// The number of items of the reversed list whose start tag is being
+    /// handled, given the HTML that follows the `tag_len` bytes of the tag.
+    /// Lists nested in a reversed list are counted along with it.
+    fn item_count(&mut self, tag_len: usize, more: &str, complete: bool) -> Option<usize> {
+        let start = self.fed + self.offset;
+        while let Some(&(offset, count)) = self.item_counts.front() {
+            if offset > start {
+                break;
+            }
+            self.item_counts.pop_front();
+            if offset == start {
+                return Some(count);
+            }
+        }
+
+        let counts = list::count_items(more, complete)?;
+        let more_start = start + tag_len;
+        self.item_counts.extend(
+            counts
+                .nested
+                .into_iter()
+                .map(|(offset, count)| (more_start + offset, count)),
+        );
+        Some(counts.count)
+    }
+
+    /// How far the current line and the lines it is wrapped into have to be
+    /// indented because they are inside a list.
+    ///
+    /// Nested lists are indented by the configured width per level, or in
+    /// Markdown by the width of the enclosing items' markers so that they are
+    /// recognized as nested. Text after the first line of an item is aligned
+    /// with the text after its marker. Lists nested deeper than
+    /// [`MAX_LIST_DEPTH`] are indented like the lists at that depth.
+    fn list_indentation(&self) -> (usize, usize) {
+        let (current, outer) = match self.output.lists.split_last() {
+            Some(lists) => lists,
+            None => return (0, 0),
+        };
+        let outer = &outer[..outer.len().min(MAX_LIST_DEPTH)];
+        let level = if self.options.format == OutputFormat::Markdown {
+            outer.iter().map(|list| list.hang).sum()
+        } else {
+            outer.len() * self.options.list_indent
+        };
+        let continuation = level + current.hang;
+        if self.output.item_start {
+            (level, continuation)
+        } else {
+            (continuation, continuation)
+        }
+    }
+
+    fn is_empty(&self) -> bool {
+        self.output.out.is_empty() && self.output.line.is_empty() && !self.output.taken
+    }
+
+    /// Inserts a single line break, unless the output is still empty or
+    /// already ends in a block break.
+    fn line_break(&mut self) {
+        if !self.is_empty() && self.output.breaks < self.options.block_lines() {
+            self.output.breaks += 1;
+        }
+    }
+
+    /// Makes sure that the following text is separated from the previous text
+    /// as configured by the [`BlockSeparator`](crate::BlockSeparator).
+    fn block_break(&mut self) {
+        self.ensure_breaks(self.options.block_lines());
+    }
+
+    /// Makes sure that the following text starts at least `n` lines below the
+    /// previous text, unless the output is still empty.
+    fn ensure_breaks(&mut self, n: usize) {
+        if !self.is_empty() {
+            self.output.breaks = self.output.breaks.max(n);
+        }
+    }
+
+    fn flush_breaks(&mut self) {
+        if self.output.breaks > 0 {
+            self.output.space = false;
+            if let Some(link) = &mut self.link {
+                // the line break separates the words of the link text
+                link.text.push(' ');
+            }
+        }
+        for _ in 0..std::mem::take(&mut self.output.breaks) {
+            self.end_line();
+            self.output.out.push_str(self.options.newline());
+        }
+    }
+
+    /// Moves the current line to the output, wrapping it if necessary.
+    fn end_line(&mut self) {
+        if self.output.line.is_empty() {
+            // empty lines still belong to the current block
+            let depth = self.output.quote_depth.min(MAX_QUOTE_DEPTH);
+            for i in 0..depth {
+                self.output
+                    .out
+                    .push_str(if i + 1 < depth { "> " } else { ">" });
+            }
+            return;
+        }
+
+        let line = if self.output.line_is_preformatted {
+            self.output.line.as_str()
+        } else {
+            self.output.line.trim_end_matches(' ')
+        };
+        let lines = match self.output.wrap_width {
+            Some(width) if !self.output.line_is_preformatted => {
+                let available = |prefix: &str| width.saturating_sub(display_width(prefix)).max(1);
+                wrap_line(
+                    line,
+                    available(&self.output.line_prefix),
+                    available(&self.output.continuation_prefix),
+                )
+            }
+            _ => vec![line],
+        };
+        let markdown = self.options.format == OutputFormat::Markdown;
+        for (i, line) in lines.into_iter().enumerate() {
+            if i == 0 {
+                self.output.out.push_str(&self.output.line_prefix);
+                self.output.out.push_str(line);
+                continue;
+            }
+            self.output.out.push_str(self.options.newline());
+            self.output.out.push_str(&self.output.continuation_prefix);
+            if markdown {
+                // wrapping may have moved text that starts a block to the line start
+                self.output.out.push_str(&markdown::escape_line_start(line));
+            } else {
+                self.output.out.push_str(line);
+            }
+        }
+        self.output.line.clear();
+        self.output.line_is_preformatted = false;
+    }
+
+    /// Writes the lines that are finished so far to `sink`, unless they are
+    /// part of a table that is still being laid out.
+    pub(crate) fn write_finished<W: fmt::Write>(&mut self, sink: &mut W) -> fmt::Result {
+        if !self.tables.is_empty() || self.output.out.is_empty() {
+            return Ok(());
+        }
+        self.output.taken = true;
+        let result = sink.write_str(&self.output.out);
+        self.output.out.clear();
+        result
+    }
+
+    /// Ends the input and writes the rest of the text to `sink`. Returns the
+    /// buffers for the next conversion.
+    pub(crate) fn finish_into<W: fmt::Write>(mut self, sink: &mut W) -> (fmt::Result, Scratch) {
+        self.close_link();
+        while !self.tables.is_empty() {
+            self.end_table();
+        }
+        self.close_code_block();
+        self.write_footnotes();
+        if self.options.format == OutputFormat::Markdown {
+            // the last block doesn't need to be ended explicitly
+            self.output.breaks = 0;
+        }
+        self.flush_breaks();
+        self.end_line();
+        let result = sink.write_str(&self.output.out);
+        (result, self.output.into_scratch())
+    }
+
+    /// Writes everything that is still pending in `output` and returns its text.
+    fn finish_output(&mut self, output: Output) -> String {
+        let outer = std::mem::replace(&mut self.output, output);
+        self.flush_breaks();
+        self.end_line();
+        std::mem::replace(&mut self.output, outer).out
+    }
+}
+
+/// Finds the end tag of the element `name` in `html`. Returns its start and
+/// end.
+fn end_tag(html: &str, name: &str) -> Option<(usize, usize)> {
+    for (i, _) in html.match_indices("</") {
+        let end_name = html[i + 2..].get(..name.len());
+        if !end_name.is_some_and(|end_name| end_name.eq_ignore_ascii_case(name)) {
+            continue;
+        }
+        let after = &html[i + 2 + name.len()..];
+        if after.starts_with(|c: char| is_html_whitespace(c) || c == '/' || c == '>') {
+            return after
+                .find('>')
+                .map(|end| (i, html.len() - after.len() + end + 1));
+        }
+    }
+    None
+}
+
+/// The length of `text` without an entity or line break that might be cut off
+/// at its end.
+fn text_end(text: &str) -> usize {
+    let text = text.strip_suffix('\r').unwrap_or(text);
+    match text.rfind('&') {
+        Some(i)
+            if text.len() - i < 40
+                && text[i + 1..]
+                    .bytes()
+                    .all(|b| b.is_ascii_alphanumeric() || b == b'#') =>
+        {
+            i
+        }
+        _ => text.len(),
+    }
+}
+
+/// The start of `s` that is searched for the end of a tag, at most
+/// [`LOOKAHEAD_LIMIT`] bytes long.
+fn lookahead(s: &str) -> &str {
+    let mut end = LOOKAHEAD_LIMIT.min(s.len());
+    while !s.is_char_boundary(end) {
+        end -= 1;
+    }
+    &s[..end]
+}
+
+/// Finds the `>` that ends a tag, given the text after its `<`. A `>` inside a
+/// quoted attribute value doesn't end the tag. Returns `None` if there is no
+/// end, or if it might be cut off in an input that isn't `complete`.
+pub(crate) fn tag_end(s: &str, complete: bool) -> Option<usize> {
+    let bytes = s.as_bytes();
+    let mut i = 0;
+    while i < bytes.len() {
+        match bytes[i] {
+            b'>' => return Some(i),
+            b'=' => {
+                i += 1;
+                while bytes
+                    .get(i)
+                    .is_some_and(|&b| is_html_whitespace(char::from(b)))
+                {
+                    i += 1;
+                }
+                if let Some(&quote @ (b'"' | b'\'')) = bytes.get(i) {
+                    match s[i + 1..].find(char::from(quote)) {
+                        Some(end) => i += end + 2,
+                        // an unterminated quote is most likely a typo, so don't
+                        // swallow the rest of the document
+                        None if complete => return s.find('>'),
+                        None => return None,
+                    }
+                }
+            }
+            _ => i += 1,
+        }
+    }
+    None
+}
+
+/// The start of the `</pre>` tag that ends a `pre` element, given the HTML that
+/// follows its start tag, taking nested `pre` elements into account.
+fn code_block_end(html: &str) -> Option<usize> {
+    let mut depth = 0;
+    for (i, name) in tags(html) {
+        match name.as_str() {
+            "pre" => depth += 1,
+            "/pre" if depth == 0 => return Some(i),
+            "/pre" => depth -= 1,
+            _ => {}
+        }
+    }
+    None
+}
+
+/// The lowercase names of all tags in `html`, including the `/` of end tags,
+/// for quickly looking ahead.
+pub(crate) fn tag_names(html: &str) -> impl Iterator<Item = String> + '_ {
+    tags(html).map(|(_, name)| name)
+}
+
+/// Like [`tag_names`], but also returns the byte offset of every tag's `<`.
+pub(crate) fn tags(html: &str) -> impl Iterator<Item = (usize, String)> + '_ {
+    html.match_indices('<').map(move |(i, _)| {
+        let tag = &html[i + 1..];
+        let end = tag
+            .char_indices()
+            .find(|&(i, c)| c.is_whitespace() || c == '>' || (c == '/' && i > 0))
+            .map_or(tag.len(), |(i, _)| i);
+        (i, tag[..end].to_ascii_lowercase())
+    })
+}
+
+/// Checks if a `style` attribute sets a `white-space` value that preserves
+/// line breaks and spaces.
+fn preserves_whitespace(style: &str) -> bool {
+    style
+        .split(';')
+        .filter_map(|declaration| declaration.split_once(':'))
+        .rfind(|(property, _)| property.trim().eq_ignore_ascii_case("white-space"))
+        .is_some_and(|(_, value)| {
+            let value = value.trim().to_ascii_lowercase();
+            matches!(value.as_str(), "pre" | "pre-wrap" | "break-spaces")
+        })
+}
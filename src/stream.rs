@@ -0,0 +1,186 @@
+use std::fmt;
+use std::io;
+
+use crate::options::Options;
+use crate::render::Renderer;
+
+/// Converts HTML that arrives in chunks, writing the text as soon as it is
+/// known. Created by [`Converter::stream`](crate::Converter::stream).
+///
+/// Chunks may end anywhere, even in the middle of a tag or an entity. Only
+/// little of the input and output is kept in memory, with the exception of
+/// tables, which are written once they are complete so that their columns can
+/// be aligned.
+///
+/// ```
+/// use nanohtml2text::{Converter, LineEnding, Options};
+///
+/// let converter = Converter::new(Options::new().line_ending(LineEnding::Lf));
+/// let mut stream = converter.stream(String::new());
+/// for chunk in ["<p>Tom &am", "p; Jerry</p><p clas", "s=\"x\">again</p>"] {
+///     stream.push(chunk).unwrap();
+/// }
+/// assert_eq!(stream.finish().unwrap(), "Tom & Jerry\n\nagain");
+/// ```
+pub struct StreamConverter<'o, W> {
+    renderer: Renderer<'o>,
+    /// The end of the input so far, which couldn't be handled yet because it
+    /// might be cut off.
+    input: String,
+    writer: W,
+}
+
+impl<'o, W: fmt::Write> StreamConverter<'o, W> {
+    pub(crate) fn new(options: &'o Options, writer: W) -> Self {
+        StreamConverter {
+            renderer: Renderer::new(options),
+            input: String::new(),
+            writer,
+        }
+    }
+
+    /// Converts the next chunk of HTML.
+    pub fn push(&mut self, html: &str) -> fmt::Result {
+        if self.input.is_empty() {
+            let handled = self.renderer.feed(html, false);
+            self.input.push_str(&html[handled..]);
+        } else {
+            self.input.push_str(html);
+            let handled = self.renderer.feed(&self.input, false);
+            self.input.drain(..handled);
+        }
+        self.renderer.write_finished(&mut self.writer)
+    }
+
+    /// Ends the input and writes the rest of the text. Returns the writer.
+    pub fn finish(self) -> Result<W, fmt::Error> {
+        let (writer, result) = self.finish_writer();
+        result.map(|()| writer)
+    }
+
+    fn finish_writer(mut self) -> (W, fmt::Result) {
+        self.renderer.feed(&self.input, true);
+        let (result, _) = self.renderer.finish_into(&mut self.writer);
+        (self.writer, result)
+    }
+}
+
+/// Converts UTF-8 encoded HTML from `reader` and writes the text to `writer`.
+/// Invalid UTF-8 is replaced with U+FFFD.
+pub(crate) fn convert_reader<R: io::Read, W: io::Write>(
+    options: &Options,
+    mut reader: R,
+    writer: W,
+) -> io::Result<()> {
+    let mut stream = StreamConverter::new(
+        options,
+        IoWriter {
+            inner: writer,
+            error: None,
+        },
+    );
+    let mut buffer = [0; 8192];
+    // the start of a character that is cut off at the end of a read
+    let mut partial = Vec::new();
+    loop {
+        let n = match reader.read(&mut buffer) {
+            Ok(0) => break,
+            Ok(n) => n,
+            Err(error) if error.kind() == io::ErrorKind::Interrupted => continue,
+            Err(error) => return Err(error),
+        };
+        partial.extend_from_slice(&buffer[..n]);
+        let end = partial.len() - partial_char_len(&partial);
+        let result = stream.push(&String::from_utf8_lossy(&partial[..end]));
+        stream.writer.check(result)?;
+        partial.drain(..end);
+    }
+    let result = stream.push(&String::from_utf8_lossy(&partial));
+    stream.writer.check(result)?;
+    let (mut writer, result) = stream.finish_writer();
+    writer.check(result)?;
+    writer.inner.flush()
+}
+
+/// The length of a UTF-8 sequence at the end of `bytes` that is cut off.
+fn partial_char_len(bytes: &[u8]) -> usize {
+    for len in 1..=bytes.len().min(3) {
+        let byte = bytes[bytes.len() - len];
+        let needed = match byte {
+            0xc0..=0xdf => 2,
+            0xe0..=0xef => 3,
+            0xf0..=0xf7 => 4,
+            // continuation byte, keep looking for the start
+            0x80..=0xbf => continue,
+            _ => return 0,
+        };
+        return if needed > len { len } else { 0 };
+    }
+    0
+}
+
+/// Adapts an [`io::Write`] to [`fmt::Write`], keeping the error.
+struct IoWriter<W> {
+    inner: W,
+    error: Option<io::Error>,
+}
+
+impl<W> IoWriter<W> {
+    /// Turns a formatting error back into the I/O error that caused it.
+    fn check(&mut self, result: fmt::Result) -> io::Result<()> {
+        match (self.error.take(), result) {
+            (Some(error), _) => Err(error),
+            (None, Ok(())) => Ok(()),
+            (None, Err(_)) => Err(io::Error::other("formatter error")),
+        }
+    }
+}
+
+impl<W: io::Write> fmt::Write for IoWriter<W> {
+    fn write_str(&mut self, s: &str) -> fmt::Result {
+        self.inner.write_all(s.as_bytes()).map_err(|error| {
+            self.error = Some(error);
+            fmt::Error
+        })
+    }
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn bounded_memory() {
+        struct Count(usize);
+        impl fmt::Write for Count {
+            fn write_str(&mut self, s: &str) -> fmt::Result {
+                self.0 += s.len();
+                Ok(())
+            }
+        }
+        let options = Options::new();
+        let mut stream = StreamConverter::new(&options, Count(0));
+        stream.push("<script>").unwrap();
+        for _ in 0..10_000 {
+            stream
+                .push("<p>some <b>text</b> &amp; more</p><!-- ")
+                .unwrap();
+            stream.push("comment --></script> &am").unwrap();
+            assert!(stream.input.len() < 10);
+            let mut finished = String::new();
+            stream.renderer.write_finished(&mut finished).unwrap();
+            assert!(finished.len() < 100);
+        }
+        assert!(stream.finish().unwrap().0 > 100_000);
+    }
+
+    #[test]
+    fn partial_chars() {
+        assert_eq!(partial_char_len(b"abc"), 0);
+        assert_eq!(partial_char_len("aé".as_bytes()), 0);
+        assert_eq!(partial_char_len(&"aé".as_bytes()[..2]), 1);
+        assert_eq!(partial_char_len(&"a日".as_bytes()[..3]), 2);
+        assert_eq!(partial_char_len(&"a🦀".as_bytes()[..4]), 3);
+        assert_eq!(partial_char_len(&[b'a', 0x80]), 0);
+    }
+}
@@ -0,0 +1,413 @@
+use crate::render::tag_names;
+use crate::width::display_width;
+
+/// Elements that make the content of a table cell more than a simple value.
+const BLOCK_ELEMENTS: &[&str] = &[
+    "address",
+    "article",
+    "aside",
+    "blockquote",
+    "div",
+    "dl",
+    "fieldset",
+    "figure",
+    "footer",
+    "form",
+    "h1",
+    "h2",
+    "h3",
+    "h4",
+    "h5",
+    "h6",
+    "header",
+    "hr",
+    "main",
+    "nav",
+    "ol",
+    "p",
+    "pre",
+    "section",
+    "ul",
+];
+
+/// Guesses whether a table is only used to lay out the page instead of
+/// containing tabular data, given the HTML that follows its start tag.
+///
+/// Tables that contain other tables or block elements like paragraphs, as
+/// well as tables with only a single column, are considered layout tables.
+///
+/// Returns `None` if that can't be decided because the end of the table isn't
+/// part of the HTML yet and more of it may still follow, unless `complete` is
+/// set.
+pub(crate) fn is_layout_table(html: &str, complete: bool) -> Option<bool> {
+    let mut columns = 0;
+    let mut max_columns = 0;
+    let mut in_cell = false;
+    for name in tag_names(html) {
+        match name.as_str() {
+            "table" => return Some(true),
+            "/table" => return Some(max_columns <= 1),
+            "tr" => columns = 0,
+            "td" | "th" => {
+                columns += 1;
+                max_columns = max_columns.max(columns);
+                in_cell = true;
+            }
+            name if in_cell && BLOCK_ELEMENTS.contains(&name) => return Some(true),
+            _ => {}
+        }
+    }
+    complete.then_some(max_columns <= 1)
+}
+
+/// The largest `colspan` that is respected, as in browsers.
+const MAX_COLSPAN: usize = 1000;
+
+/// The most columns a table is laid out with, so that rows with fewer cells,
+/// which are padded to the full width, can't make the output explode. The
+/// text of cells that don't fit anymore is added to the last cell of their
+/// row.
+const MAX_COLUMNS: usize = 100;
+
+/// How the cells of a table are laid out.
+#[derive(Debug, Clone, Copy, PartialEq, Eq)]
+pub(crate) enum Grid {
+    /// Columns separated by spaces, header rows underlined with dashes.
+    Plain,
+    /// ASCII borders around every cell.
+    Bordered,
+    /// A Markdown pipe table.
+    Markdown,
+}
+
+struct Cell {
+    lines: Vec<String>,
+    colspan: usize,
+    header: bool,
+}
+
+/// The cells of a table, collected until the whole table is known so that the
+/// column widths can be computed.
+#[derive(Default)]
+pub(crate) struct Table {
+    rows: Vec<Vec<Cell>>,
+    /// The number of columns the cells of the current row span.
+    row_columns: usize,
+}
+
+impl Table {
+    pub(crate) fn start_row(&mut self) {
+        self.rows.push(Vec::new());
+        self.row_columns = 0;
+    }
+
+    /// Adds a cell to the current row. `text` may span several lines.
+    pub(crate) fn add_cell(&mut self, text: &str, newline: &str, colspan: usize, header: bool) {
+        if self.rows.is_empty() {
+            self.start_row();
+        }
+        let mut lines: Vec<String> = text.split(newline).map(String::from).collect();
+        while lines.len() > 1 && lines.last().is_some_and(|line| line.trim().is_empty()) {
+            lines.pop();
+        }
+        let row = self.rows.last_mut().unwrap();
+        if self.row_columns >= MAX_COLUMNS {
+            let last = row.last_mut().unwrap().lines.last_mut().unwrap();
+            for line in lines.iter().map(|line| line.trim()) {
+                if !line.is_empty() {
+                    last.push(' ');
+                    last.push_str(line);
+                }
+            }
+            return;
+        }
+        let colspan = colspan.clamp(1, MAX_COLSPAN.min(MAX_COLUMNS - self.row_columns));
+        self.row_columns += colspan;
+        row.push(Cell {
+            lines,
+            colspan,
+            header,
+        });
+    }
+
+    /// Lays out the table, returning the lines of the result.
+    pub(crate) fn render(mut self, grid: Grid) -> Vec<String> {
+        self.rows.retain(|row| !row.is_empty());
+        if self.rows.is_empty() {
+            return Vec::new();
+        }
+        if grid == Grid::Markdown {
+            // Markdown table cells can't contain line breaks
+            for cell in self.rows.iter_mut().flatten() {
+                let text = cell.lines.join(" ").replace('|', "\\|");
+                cell.lines = vec![text];
+            }
+        }
+
+        let separator_width = match grid {
+            Grid::Plain => 2,
+            Grid::Bordered | Grid::Markdown => 3,
+        };
+        let mut widths = self.column_widths(separator_width);
+        if grid == Grid::Markdown {
+            // the delimiter row needs at least three dashes per column
+            for width in &mut widths {
+                *width = (*width).max(3);
+            }
+        }
+
+        let mut out = Vec::new();
+        let rule = |fill: char| rule(&widths, grid, fill);
+        if grid == Grid::Bordered {
+            out.push(rule('-'));
+        }
+        for (i, row) in self.rows.iter().enumerate() {
+            let row_height = row.iter().map(|cell| cell.lines.len()).max().unwrap_or(0);
+            for line in 0..row_height {
+                out.push(render_line(row, line, &widths, grid, separator_width));
+            }
+
+            let is_header = row.iter().all(|cell| cell.header);
+            let next_is_header = self
+                .rows
+                .get(i + 1)
+                .is_some_and(|row| row.iter().all(|cell| cell.header));
+            let is_last = i + 1 == self.rows.len();
+            match grid {
+                // Markdown tables always have exactly one header row
+                Grid::Markdown if i == 0 => out.push(rule('-')),
+                Grid::Markdown => {}
+                Grid::Plain if is_header && !next_is_header && !is_last => out.push(rule('-')),
+                Grid::Plain => {}
+                Grid::Bordered if is_last => out.push(rule('-')),
+                Grid::Bordered if is_header && !next_is_header => out.push(rule('=')),
+                Grid::Bordered => {}
+            }
+        }
+        out
+    }
+
+    /// Computes the width of every column. Cells spanning several columns that
+    /// don't fit into them widen the last of those columns.
+    fn column_widths(&self, separator_width: usize) -> Vec<usize> {
+        let columns = self
+            .rows
+            .iter()
+            .map(|row| row.iter().map(|cell| cell.colspan).sum())
+            .max()
+            .unwrap_or(0);
+        let mut widths = vec![1; columns];
+
+        let cell_width = |cell: &Cell| cell.lines.iter().map(|line| display_width(line)).max();
+        for row in &self.rows {
+            let mut column = 0;
+            for cell in row {
+                if cell.colspan == 1 {
+                    widths[column] = widths[column].max(cell_width(cell).unwrap_or(0));
+                }
+                column += cell.colspan;
+            }
+        }
+        for row in &self.rows {
+            let mut column = 0;
+            for cell in row.iter() {
+                if cell.colspan > 1 {
+                    let spanned = &widths[column..column + cell.colspan];
+                    let available =
+                        spanned.iter().sum::<usize>() + (cell.colspan - 1) * separator_width;
+                    let needed = cell_width(cell).unwrap_or(0);
+                    if needed > available {
+                        widths[column + cell.colspan - 1] += needed - available;
+                    }
+                }
+                column += cell.colspan;
+            }
+        }
+        widths
+    }
+}
+
+/// A horizontal line separating rows.
+fn rule(widths: &[usize], grid: Grid, fill: char) -> String {
+    let segments = widths.iter().map(|&width| match grid {
+        Grid::Plain => fill.to_string().repeat(width),
+        Grid::Bordered => fill.to_string().repeat(width + 2),
+        Grid::Markdown => fill.to_string().repeat(width),
+    });
+    match grid {
+        Grid::Plain => segments.collect::<Vec<_>>().join("  "),
+        Grid::Bordered => format!("+{}+", segments.collect::<Vec<_>>().join("+")),
+        Grid::Markdown => format!("| {} |", segments.collect::<Vec<_>>().join(" | ")),
+    }
+}
+
+/// Renders line number `line` of a row, padding every cell to its width.
+fn render_line(
+    row: &[Cell],
+    line: usize,
+    widths: &[usize],
+    grid: Grid,
+    separator_width: usize,
+) -> String {
+    let mut cells = Vec::new();
+    let mut column = 0;
+    for cell in row {
+        let spanned = &widths[column..column + cell.colspan];
+        let width = spanned.iter().sum::<usize>() + (cell.colspan - 1) * separator_width;
+        let text = cell.lines.get(line).map_or("", String::as_str);
+        cells.push(pad(text, width));
+        column += cell.colspan;
+    }
+    // rows with fewer cells than the table has columns
+    for &width in &widths[column..] {
+        cells.push(pad("", width));
+    }
+
+    match grid {
+        Grid::Plain => cells.join("  ").trim_end().to_string(),
+        Grid::Bordered | Grid::Markdown => format!("| {} |", cells.join(" | ")),
+    }
+}
+
+fn pad(text: &str, width: usize) -> String {
+    let padding = width.saturating_sub(display_width(text));
+    format!("{}{}", text, " ".repeat(padding))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn table(rows: &[&[(&str, usize, bool)]]) -> Table {
+        let mut table = Table::default();
+        for row in rows {
+            table.start_row();
+            for &(text, colspan, header) in row.iter() {
+                table.add_cell(text, "\n", colspan, header);
+            }
+        }
+        table
+    }
+
+    #[test]
+    fn layout_detection() {
+        assert_eq!(is_layout_table("<tr><td>a</td><td>b", false), None);
+        assert_eq!(is_layout_table("<tr><td>a</td><td>b", true), Some(false));
+        assert_eq!(
+            is_layout_table("<tr><td>a</td><td>b</td></tr></table>", false),
+            Some(false)
+        );
+        assert_eq!(
+            is_layout_table("<tr><th>a<th>b<tr><td><b>c</b><br>d<td>e</table><p>", false),
+            Some(false)
+        );
+        assert_eq!(
+            is_layout_table("<tr><td>a</td></tr><tr><td>b</td></tr></table>", false),
+            Some(true)
+        );
+        assert_eq!(
+            is_layout_table("<tr><td><p>a</p></td><td>b</td></tr></table>", false),
+            Some(true)
+        );
+        assert_eq!(
+            is_layout_table(
+                "<tr><td><table><tr><td>a<td>b</table></td><td>c</td></tr></table>",
+                false
+            ),
+            Some(true)
+        );
+    }
+
+    #[test]
+    fn plain_grid() {
+        let table = table(&[
+            &[("Item", 1, true), ("Price", 1, true)],
+            &[("Coffee", 1, false), ("3", 1, false)],
+            &[("Tea\nwith milk", 1, false)],
+        ]);
+        assert_eq!(
+            table.render(Grid::Plain),
+            [
+                "Item       Price",
+                "---------  -----",
+                "Coffee     3",
+                "Tea",
+                "with milk",
+            ]
+        );
+    }
+
+    #[test]
+    fn bordered_grid() {
+        let table = table(&[
+            &[("Item", 1, true), ("Price", 1, true)],
+            &[("Coffee", 1, false), ("3", 1, false)],
+        ]);
+        assert_eq!(
+            table.render(Grid::Bordered),
+            [
+                "+--------+-------+",
+                "| Item   | Price |",
+                "+========+=======+",
+                "| Coffee | 3     |",
+                "+--------+-------+",
+            ]
+        );
+    }
+
+    #[test]
+    fn colspan() {
+        let table = table(&[
+            &[("a", 1, false), ("b", 1, false)],
+            &[("a much longer cell", 2, false)],
+        ]);
+        assert_eq!(
+            table.render(Grid::Bordered),
+            [
+                "+---+----------------+",
+                "| a | b              |",
+                "| a much longer cell |",
+                "+---+----------------+",
+            ]
+        );
+    }
+
+    #[test]
+    fn colspan_limits() {
+        let wide = table(&[&[("a", 5000, false), ("b\nc", 1, false)]]);
+        assert_eq!(wide.column_widths(2).len(), MAX_COLUMNS);
+        assert_eq!(wide.rows[0].len(), 1);
+        assert_eq!(wide.rows[0][0].lines, ["a b c"]);
+
+        let mut many = Table::default();
+        for _ in 0..MAX_COLUMNS + 10 {
+            many.add_cell("x", "\n", 1, false);
+        }
+        assert_eq!(many.column_widths(2).len(), MAX_COLUMNS);
+
+        let zero = table(&[&[("a", 0, false), ("b", 1, false)]]);
+        assert_eq!(zero.column_widths(2), [1, 1]);
+    }
+
+    #[test]
+    fn wide_characters() {
+        let table = table(&[&[("日本", 1, false), ("x", 1, false)], &[("ab", 1, false)]]);
+        assert_eq!(table.render(Grid::Plain), ["日本  x", "ab"]);
+    }
+
+    #[test]
+    fn markdown() {
+        let table = table(&[
+            &[("a|b", 1, false), ("c", 1, false)],
+            &[("two\nlines", 1, false)],
+        ]);
+        assert_eq!(
+            table.render(Grid::Markdown),
+            [
+                "| a\\|b      | c   |",
+                "| --------- | --- |",
+                "| two lines |     |",
+            ]
+        );
+    }
+}
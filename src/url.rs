@@ -0,0 +1,165 @@
+/// The parts of an absolute URL that relative references are resolved
+/// against.
+struct Base<'a> {
+    scheme: &'a str,
+    /// Including the leading `//`, empty for URLs like `mailto:` ones.
+    authority: &'a str,
+    path: &'a str,
+    query: &'a str,
+}
+
+impl<'a> Base<'a> {
+    fn parse(url: &'a str) -> Option<Self> {
+        let scheme_len = scheme_len(url)?;
+        let scheme = &url[..scheme_len];
+        let rest = &url[scheme_len + 1..];
+        let rest = rest.split('#').next().unwrap_or(rest);
+
+        let authority_len = match rest.strip_prefix("//") {
+            Some(authority) => authority.find(['/', '?']).map_or(rest.len(), |i| i + 2),
+            None => 0,
+        };
+        let (authority, rest) = rest.split_at(authority_len);
+        let (path, query) = match rest.find('?') {
+            Some(i) => rest.split_at(i),
+            None => (rest, ""),
+        };
+        Some(Base {
+            scheme,
+            authority,
+            path,
+            query,
+        })
+    }
+}
+
+/// The length of the scheme of `url` without the colon, if it has one.
+fn scheme_len(url: &str) -> Option<usize> {
+    let end = url.find(':')?;
+    let scheme = &url[..end];
+    let valid = scheme.starts_with(|c: char| c.is_ascii_alphabetic())
+        && scheme
+            .chars()
+            .all(|c| c.is_ascii_alphanumeric() || matches!(c, '+' | '-' | '.'));
+    valid.then_some(end)
+}
+
+/// Resolves `href` against the absolute URL `base`, as described in
+/// [RFC 3986](https://www.rfc-editor.org/rfc/rfc3986#section-5.2). If `base`
+/// is not an absolute URL, `href` is returned unchanged.
+pub(crate) fn resolve(base: &str, href: &str) -> String {
+    let href = href.trim();
+    if scheme_len(href).is_some() {
+        return href.to_string();
+    }
+    let base = match Base::parse(base.trim()) {
+        Some(base) => base,
+        None => return href.to_string(),
+    };
+
+    if let Some(authority) = href.strip_prefix("//") {
+        return format!("{}://{}", base.scheme, authority);
+    }
+    if href.is_empty() || href.starts_with('#') {
+        return format!(
+            "{}:{}{}{}{}",
+            base.scheme, base.authority, base.path, base.query, href
+        );
+    }
+    if href.starts_with('?') {
+        return format!("{}:{}{}{}", base.scheme, base.authority, base.path, href);
+    }
+
+    let (path, suffix) = match href.find(['?', '#']) {
+        Some(i) => href.split_at(i),
+        None => (href, ""),
+    };
+    let path = if path.starts_with('/') {
+        remove_dot_segments(path)
+    } else if base.authority.is_empty() && base.path.is_empty() {
+        remove_dot_segments(&format!("/{}", path))
+    } else {
+        // replace everything after the last slash of the base path
+        let directory = base.path.rfind('/').map_or("/", |i| &base.path[..=i]);
+        remove_dot_segments(&format!("{}{}", directory, path))
+    };
+    format!("{}:{}{}{}", base.scheme, base.authority, path, suffix)
+}
+
+/// Removes `.` and `..` segments from an absolute path.
+fn remove_dot_segments(path: &str) -> String {
+    let mut segments: Vec<&str> = Vec::new();
+    let mut parts = path.split('/').skip(1).peekable();
+    while let Some(part) = parts.next() {
+        let last = parts.peek().is_none();
+        match part {
+            "." | ".." => {
+                if part == ".." {
+                    segments.pop();
+                }
+                // a trailing dot segment still refers to a directory
+                if last {
+                    segments.push("");
+                }
+            }
+            _ => segments.push(part),
+        }
+    }
+    format!("/{}", segments.join("/"))
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    #[test]
+    fn rfc_examples() {
+        let base = "http://a/b/c/d;p?q";
+        for &(href, expected) in &[
+            ("g:h", "g:h"),
+            ("g", "http://a/b/c/g"),
+            ("./g", "http://a/b/c/g"),
+            ("g/", "http://a/b/c/g/"),
+            ("/g", "http://a/g"),
+            ("//g", "http://g"),
+            ("?y", "http://a/b/c/d;p?y"),
+            ("g?y", "http://a/b/c/g?y"),
+            ("#s", "http://a/b/c/d;p?q#s"),
+            ("g#s", "http://a/b/c/g#s"),
+            ("g?y#s", "http://a/b/c/g?y#s"),
+            (";x", "http://a/b/c/;x"),
+            ("", "http://a/b/c/d;p?q"),
+            (".", "http://a/b/c/"),
+            ("./", "http://a/b/c/"),
+            ("..", "http://a/b/"),
+            ("../", "http://a/b/"),
+            ("../g", "http://a/b/g"),
+            ("../..", "http://a/"),
+            ("../../g", "http://a/g"),
+            ("../../../g", "http://a/g"),
+            ("/./g", "http://a/g"),
+            ("/../g", "http://a/g"),
+            ("g.", "http://a/b/c/g."),
+            ("..g", "http://a/b/c/..g"),
+            ("./../g", "http://a/b/g"),
+            ("g/./h", "http://a/b/c/g/h"),
+            ("g/../h", "http://a/b/c/h"),
+            ("g?y/../x", "http://a/b/c/g?y/../x"),
+        ] {
+            assert_eq!(resolve(base, href), expected, "{}", href);
+        }
+    }
+
+    #[test]
+    fn bases() {
+        assert_eq!(
+            resolve("https://en.wiktionary.org", "/wiki/yet#English"),
+            "https://en.wiktionary.org/wiki/yet#English"
+        );
+        assert_eq!(resolve("https://x.y", "a"), "https://x.y/a");
+        assert_eq!(resolve("https://x.y/a#top", "#b"), "https://x.y/a#b");
+        assert_eq!(resolve("https://x.y/a?q", "//cdn.y/b"), "https://cdn.y/b");
+        assert_eq!(resolve("/relative/base", "a"), "a");
+        assert_eq!(resolve("https://x.y/", " mailto:a@b.c "), "mailto:a@b.c");
+    }
+}
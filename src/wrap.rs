@@ -0,0 +1,134 @@
+use crate::width::{char_width, display_width};
+
+/// Wraps a single line so that every part is at most `width` columns wide,
+/// except for the first part which may be `first_width` columns wide.
+///
+/// Lines are only broken at spaces and between wide (e.g. CJK) characters, so
+/// words that are longer than `width`, like URLs, are never split and end up
+/// on a line of their own instead.
+pub(crate) fn wrap_line(line: &str, first_width: usize, width: usize) -> Vec<&str> {
+    if display_width(line) <= first_width {
+        return vec![line];
+    }
+
+    let mut lines = Vec::new();
+    // byte offset where the current line starts and its width so far
+    let mut current: Option<(usize, usize)> = None;
+    let mut end = 0;
+    for (start, piece_end, starts_word) in pieces(line) {
+        let piece_width = display_width(&line[start..piece_end]);
+        current = match current {
+            Some((line_start, column)) => {
+                // only the start of a word has to be separated by a space
+                let column = column + usize::from(starts_word) + piece_width;
+                let width = if lines.is_empty() { first_width } else { width };
+                if column > width {
+                    lines.push(&line[line_start..end]);
+                    Some((start, piece_width))
+                } else {
+                    Some((line_start, column))
+                }
+            }
+            None => Some((start, piece_width)),
+        };
+        end = piece_end;
+    }
+    if let Some((line_start, _)) = current {
+        lines.push(&line[line_start..end]);
+    }
+    lines
+}
+
+/// Splits a line into the parts that may be put on different lines, returning
+/// their start and end offsets and whether they start a new word. Wide
+/// characters can be broken around, everything else that is not separated by
+/// spaces is kept together.
+fn pieces(line: &str) -> Vec<(usize, usize, bool)> {
+    let mut pieces = Vec::new();
+    let mut current: Option<(usize, bool)> = None;
+    let mut starts_word = true;
+    let mut previous_wide = false;
+    for (i, c) in line.char_indices() {
+        if c == ' ' {
+            if let Some((start, word)) = current.take() {
+                pieces.push((start, i, word));
+            }
+            starts_word = true;
+            continue;
+        }
+
+        let width = char_width(c);
+        let break_before = match width {
+            // combining characters stay with what they are combined with
+            0 => false,
+            2 => true,
+            _ => previous_wide,
+        };
+        if width > 0 {
+            previous_wide = width == 2;
+        }
+        match current {
+            None => current = Some((i, std::mem::take(&mut starts_word))),
+            Some((start, word)) if break_before => {
+                pieces.push((start, i, word));
+                current = Some((i, false));
+            }
+            Some(_) => {}
+        }
+    }
+    if let Some((start, word)) = current {
+        pieces.push((start, line.len(), word));
+    }
+    pieces
+}
+
+#[cfg(test)]
+mod tests {
+    use super::*;
+
+    fn wrap(text: &str, width: usize, newline: &str) -> String {
+        text.split(newline)
+            .flat_map(|line| wrap_line(line, width, width))
+            .collect::<Vec<_>>()
+            .join(newline)
+    }
+
+    #[test]
+    fn short_lines_untouched() {
+        assert_eq!(wrap("a  b\nc", 10, "\n"), "a  b\nc");
+    }
+
+    #[test]
+    fn wraps_on_spaces() {
+        assert_eq!(
+            wrap("the quick brown fox jumps", 10, "\r\n"),
+            "the quick\r\nbrown fox\r\njumps"
+        );
+    }
+
+    #[test]
+    fn first_line_width() {
+        assert_eq!(wrap_line("aa bb cc dd", 8, 5), ["aa bb cc", "dd"]);
+        assert_eq!(wrap_line("aa bb cc dd", 2, 5), ["aa", "bb cc", "dd"]);
+    }
+
+    #[test]
+    fn long_words_not_split() {
+        assert_eq!(
+            wrap("see http://example.com/a/long/path now", 10, "\n"),
+            "see\nhttp://example.com/a/long/path\nnow"
+        );
+    }
+
+    #[test]
+    fn wide_characters() {
+        assert_eq!(wrap("日本語の文章です", 6, "\n"), "日本語\nの文章\nです");
+        assert_eq!(wrap("ab 日本", 4, "\n"), "ab\n日本");
+    }
+
+    #[test]
+    fn combining_characters() {
+        // "e" followed by a combining acute accent is one column wide
+        assert_eq!(wrap("e\u{301}e\u{301} ab", 5, "\n"), "e\u{301}e\u{301} ab");
+    }
+}